@@ -44,6 +44,7 @@ impl<T> GdCellBlocking<T> {
     /// Fails if an accessible mutable reference exists on the current thread.
     ///
     /// Blocks if another thread currently holds a mutable reference.
+    #[track_caller]
     pub fn borrow(&self) -> Result<RefGuardBlocking<'_, T>, Box<dyn Error>> {
         let mut tracker_guard = self.thread_tracker.lock().unwrap();
 
@@ -88,6 +89,7 @@ impl<T> GdCellBlocking<T> {
     ///
     /// Blocks if another thread currently holds a mutable reference, or if another thread holds immutable references but the current thread
     /// doesn't.
+    #[track_caller]
     pub fn borrow_mut(&self) -> Result<MutGuardBlocking<'_, T>, Box<dyn Error>> {
         let mut tracker_guard = self.thread_tracker.lock().unwrap();
 
@@ -5,6 +5,8 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use std::panic::Location;
+
 /// A type that tracks the state of borrows for a [`GdCell`].
 ///
 /// This state upholds these invariants:
@@ -18,7 +20,10 @@
 /// If a catastrophic error occurs, then the state will be poisoned. If the state is poisoned then that's
 /// almost certainly an implementation bug, and should never happen. But in an abundance of caution it is
 /// included to be safe.
-#[derive(Clone, PartialEq, Debug)]
+///
+/// With the `debug-borrows` feature, the source location of every outstanding borrow is recorded as well, and
+/// listed in the errors of failed borrows.
+#[derive(Clone, Debug)]
 pub struct BorrowState {
     /// The number of `&T` references that are tracked.
     shared_count: usize,
@@ -28,6 +33,9 @@ pub struct BorrowState {
     inaccessible_count: usize,
     /// `true` if the borrow state has reached an erroneous or unreliable state.
     poisoned: bool,
+    /// Where the currently tracked references were created, in creation order.
+    #[cfg(feature = "debug-borrows")]
+    tracked: Vec<TrackedBorrow>,
 }
 
 impl BorrowState {
@@ -38,6 +46,8 @@ impl BorrowState {
             mut_count: 0,
             inaccessible_count: 0,
             poisoned: false,
+            #[cfg(feature = "debug-borrows")]
+            tracked: Vec::new(),
         }
     }
 
@@ -91,7 +101,7 @@ impl BorrowState {
         self.ensure_not_poisoned()?;
 
         if self.has_accessible() {
-            return Err("cannot borrow while accessible mutable borrow exists".into());
+            return Err(self.conflict("cannot borrow while accessible mutable borrow exists"));
         }
 
         Ok(())
@@ -101,16 +111,60 @@ impl BorrowState {
         self.ensure_not_poisoned()?;
 
         if self.has_accessible() {
-            return Err("cannot borrow while accessible mutable borrow exists".into());
+            return Err(self.conflict("cannot borrow while accessible mutable borrow exists"));
         }
 
         if self.shared_count != 0 {
-            return Err("cannot borrow mutable while shared borrow exists".into());
+            return Err(self.conflict("cannot borrow mutable while shared borrow exists"));
         }
 
         Ok(())
     }
 
+    /// Error for a borrow that conflicts with existing ones. Lists the outstanding borrows, if tracked.
+    fn conflict(&self, message: &str) -> BorrowStateErr {
+        #[cfg(feature = "debug-borrows")]
+        if !self.tracked.is_empty() {
+            let mut message = format!("{message}; outstanding borrows:");
+            for borrow in self.tracked.iter() {
+                message.push_str(&format!("\n    {borrow}"));
+            }
+
+            return BorrowStateErr::Custom(message);
+        }
+
+        message.into()
+    }
+
+    #[cfg_attr(not(feature = "debug-borrows"), allow(unused_variables))]
+    fn track(&mut self, location: &'static Location<'static>, is_mut: bool) {
+        #[cfg(feature = "debug-borrows")]
+        self.tracked.push(TrackedBorrow { location, is_mut });
+    }
+
+    /// Stops tracking the shared reference created at `location`.
+    ///
+    /// Shared references can be released in any order, so the caller must remember where it was created.
+    #[cfg_attr(not(feature = "debug-borrows"), allow(unused_variables))]
+    pub(crate) fn untrack_shared(&mut self, location: &'static Location<'static>) {
+        #[cfg(feature = "debug-borrows")]
+        if let Some(index) = self
+            .tracked
+            .iter()
+            .rposition(|borrow| !borrow.is_mut && borrow.location == location)
+        {
+            self.tracked.remove(index);
+        }
+    }
+
+    /// Stops tracking the most recent mutable reference; those are always released in reverse order.
+    fn untrack_mut(&mut self) {
+        #[cfg(feature = "debug-borrows")]
+        if let Some(index) = self.tracked.iter().rposition(|borrow| borrow.is_mut) {
+            self.tracked.remove(index);
+        }
+    }
+
     /// Track a new shared reference.
     ///
     /// Returns the new total number of shared references.
@@ -118,6 +172,7 @@ impl BorrowState {
     /// This fails when:
     /// - There exists an accessible mutable reference.
     /// - There exist `usize::MAX` shared references.
+    #[track_caller]
     pub fn increment_shared(&mut self) -> Result<usize, BorrowStateErr> {
         self.ensure_not_poisoned()?;
 
@@ -128,6 +183,7 @@ impl BorrowState {
             .checked_add(1)
             .ok_or("could not increment shared count")?;
 
+        self.track(Location::caller(), false);
         Ok(self.shared_count)
     }
 
@@ -164,6 +220,7 @@ impl BorrowState {
     /// - There are `usize::MAX` tracked mutable references.
     ///
     /// Any amount of shared references will prevent [`Self::increment_inaccessible`] from succeeding.
+    #[track_caller]
     pub fn increment_mut(&mut self) -> Result<usize, BorrowStateErr> {
         self.ensure_not_poisoned()?;
 
@@ -174,6 +231,7 @@ impl BorrowState {
             .checked_add(1)
             .ok_or("could not increment mut count")?;
 
+        self.track(Location::caller(), true);
         Ok(self.mut_count)
     }
 
@@ -205,6 +263,7 @@ impl BorrowState {
         // We know `mut_count` isn't 0.
         self.mut_count -= 1;
 
+        self.untrack_mut();
         Ok(self.mut_count)
     }
 
@@ -267,6 +326,34 @@ impl Default for BorrowState {
     }
 }
 
+// Tracked locations are informational only; two states are equal if they allow the same operations.
+impl PartialEq for BorrowState {
+    fn eq(&self, other: &Self) -> bool {
+        self.shared_count == other.shared_count
+            && self.mut_count == other.mut_count
+            && self.inaccessible_count == other.inaccessible_count
+            && self.poisoned == other.poisoned
+    }
+}
+
+/// Source location of an outstanding borrow, recorded with the `debug-borrows` feature.
+#[cfg(feature = "debug-borrows")]
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct TrackedBorrow {
+    /// Where the borrow was created.
+    pub location: &'static Location<'static>,
+    /// Whether the borrow is mutable.
+    pub is_mut: bool,
+}
+
+#[cfg(feature = "debug-borrows")]
+impl std::fmt::Display for TrackedBorrow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let kind = if self.is_mut { "mutable" } else { "shared" };
+        write!(f, "{kind} borrow at {}", self.location)
+    }
+}
+
 #[derive(Clone, Eq, PartialEq, Debug)]
 pub enum BorrowStateErr {
     Poisoned(String),
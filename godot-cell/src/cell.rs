@@ -28,6 +28,7 @@ impl<T> GdCell<T> {
     /// Returns a new shared reference to the contents of the cell.
     ///
     /// Fails if an accessible mutable reference exists.
+    #[track_caller]
     pub fn borrow(&self) -> Result<RefGuard<'_, T>, Box<dyn Error>> {
         self.0.as_ref().borrow()
     }
@@ -35,6 +36,7 @@ impl<T> GdCell<T> {
     /// Returns a new mutable reference to the contents of the cell.
     ///
     /// Fails if an accessible mutable reference exists, or a shared reference exists.
+    #[track_caller]
     pub fn borrow_mut(&self) -> Result<MutGuard<'_, T>, Box<dyn Error>> {
         self.0.as_ref().borrow_mut()
     }
@@ -102,6 +104,7 @@ impl<T> GdCellInner<T> {
     /// Returns a new shared reference to the contents of the cell.
     ///
     /// Fails if an accessible mutable reference exists.
+    #[track_caller]
     pub fn borrow(self: Pin<&Self>) -> Result<RefGuard<'_, T>, Box<dyn Error>> {
         let mut state = self.state.lock().unwrap();
         state.borrow_state.increment_shared()?;
@@ -114,6 +117,7 @@ impl<T> GdCellInner<T> {
     /// Returns a new mutable reference to the contents of the cell.
     ///
     /// Fails if an accessible mutable reference exists, or a shared reference exists.
+    #[track_caller]
     pub fn borrow_mut(self: Pin<&Self>) -> Result<MutGuard<'_, T>, Box<dyn Error>> {
         let mut state = self.state.lock().unwrap();
         state.borrow_state.increment_mut()?;
@@ -293,6 +297,31 @@ mod test {
         std::mem::drop(guard1);
     }
 
+    #[test]
+    #[cfg(feature = "debug-borrows")]
+    fn conflict_lists_outstanding_borrows() {
+        let cell = GdCell::new(0);
+        let (guard1, line1) = (cell.borrow().unwrap(), line!());
+        let (guard2, line2) = (cell.borrow().unwrap(), line!());
+        drop(guard1);
+
+        let err = cell.borrow_mut().unwrap_err().to_string();
+        assert!(!err.contains(&format!("{}:{line1}:", file!())), "{err}");
+        assert!(
+            err.contains(&format!("shared borrow at {}:{line2}:", file!())),
+            "{err}"
+        );
+
+        drop(guard2);
+        let (_guard3, line3) = (cell.borrow_mut().unwrap(), line!());
+
+        let err = cell.borrow().unwrap_err().to_string();
+        assert!(
+            err.contains(&format!("mutable borrow at {}:{line3}:", file!())),
+            "{err}"
+        );
+    }
+
     #[test]
     fn allow_inaccessible_mut_mut() {
         const VAL: i32 = 23456;
@@ -6,6 +6,7 @@
  */
 
 use std::ops::{Deref, DerefMut};
+use std::panic::Location;
 use std::ptr::NonNull;
 use std::sync::{Mutex, MutexGuard};
 
@@ -23,6 +24,9 @@ pub struct RefGuard<'a, T> {
 
     /// A pointer to the borrowed value.
     value: NonNull<T>,
+
+    /// Where the borrow was created, to untrack it on drop.
+    location: &'static Location<'static>,
 }
 
 impl<'a, T> RefGuard<'a, T> {
@@ -40,8 +44,13 @@ impl<'a, T> RefGuard<'a, T> {
     ///
     /// These conditions ensure that it is safe to call [`as_ref()`](NonNull::as_ref) on `value` for as long
     /// as the returned guard exists.
+    #[track_caller]
     pub(crate) unsafe fn new(state: &'a Mutex<CellState<T>>, value: NonNull<T>) -> Self {
-        Self { state, value }
+        Self {
+            state,
+            value,
+            location: Location::caller(),
+        }
     }
 }
 
@@ -56,12 +65,10 @@ impl<T> Deref for RefGuard<'_, T> {
 
 impl<T> Drop for RefGuard<'_, T> {
     fn drop(&mut self) {
-        self.state
-            .lock()
-            .unwrap()
-            .borrow_state
-            .decrement_shared()
-            .unwrap();
+        let mut state = self.state.lock().unwrap();
+
+        state.borrow_state.decrement_shared().unwrap();
+        state.borrow_state.untrack_shared(self.location);
     }
 }
 
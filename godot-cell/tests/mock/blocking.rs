@@ -159,12 +159,39 @@ fn non_blocking_reborrow() {
     });
 
     let panic_a = thread_a.join().err();
-
-    assert_eq!(panic_a.unwrap().downcast_ref::<String>().unwrap(), "called `Result::unwrap()` on an `Err` value: Custom(\"cannot borrow mutable while shared borrow exists\")");
+    assert_mut_while_shared_panic(panic_a.unwrap().downcast_ref::<String>().unwrap());
 
     let panic_b = thread_b.join().err();
+    assert_mut_while_shared_panic(panic_b.unwrap().downcast_ref::<String>().unwrap());
+}
 
-    assert_eq!(panic_b.unwrap().downcast_ref::<String>().unwrap(), "called `Result::unwrap()` on an `Err` value: Custom(\"cannot borrow mutable while shared borrow exists\")");
+/// Checks the panic message of `non_blocking_reborrow()`.
+fn assert_mut_while_shared_panic(message: &str) {
+    #[cfg(not(feature = "debug-borrows"))]
+    assert_eq!(message, "called `Result::unwrap()` on an `Err` value: Custom(\"cannot borrow mutable while shared borrow exists\")");
+
+    // With `debug-borrows`, the message additionally lists the outstanding shared borrows, which are taken in `setup_mock!` above.
+    // Depending on timing, the other thread's borrow is listed as well.
+    #[cfg(feature = "debug-borrows")]
+    {
+        let borrows = message
+            .strip_prefix("called `Result::unwrap()` on an `Err` value: Custom(\"cannot borrow mutable while shared borrow exists; outstanding borrows:")
+            .and_then(|rest| rest.strip_suffix("\")"))
+            .unwrap_or_else(|| panic!("unexpected message: {message}"));
+
+        // Each borrow is listed on its own (escaped) line, followed by line and column.
+        let entry_prefix = format!("\\n    shared borrow at {}:", file!());
+        let locations = borrows
+            .strip_prefix(&entry_prefix)
+            .unwrap_or_else(|| panic!("unexpected message: {message}"));
+
+        for location in locations.split(&entry_prefix) {
+            assert!(
+                location.split(':').all(|part| part.parse::<u32>().is_ok()),
+                "unexpected location in message: {message}"
+            );
+        }
+    }
 }
 
 /// Mutable borrow on main thread with shared borrow on others.
@@ -54,6 +54,7 @@ fn to_hardcoded_rust_ident(full_ty: &GodotTy) -> Option<&str> {
         ("bool", None) => "bool",
         ("String", None) => "GString",
         ("Array", None) => "VariantArray",
+        ("Dictionary", None) => "VarDictionary",
 
         // Types needed for native structures mapping
         ("uint8_t", None) => "u8",
@@ -296,7 +297,7 @@ fn to_rust_expr_inner(expr: &str, ty: &RustTy, is_inner: bool) -> TokenStream {
         "false" => return quote! { false },
         "[]" | "{}" if is_inner => return quote! {},
         "[]" => return quote! { Array::new() }, // VariantArray or Array<T>
-        "{}" => return quote! { Dictionary::new() },
+        "{}" => return quote! { VarDictionary::new() },
         "null" => {
             return match ty {
                 RustTy::BuiltinIdent { ty: ident, .. } if ident == "Variant" => {
@@ -580,7 +581,7 @@ fn gdscript_to_rust_expr() {
         // Special literals
         ("true",                                           None,               quote! { true }),
         ("false",                                          None,               quote! { false }),
-        ("{}",                                             None,               quote! { Dictionary::new() }),
+        ("{}",                                             None,               quote! { VarDictionary::new() }),
         ("[]",                                             None,               quote! { Array::new() }),
 
         ("null",                                           ty_variant,         quote! { Variant::nil() }),
@@ -1014,23 +1014,11 @@ impl<T: ArrayElement> Array<T> {
         let self_ty = self.element_type();
         let target_ty = ElementType::of::<T>();
 
-        // Exact match: check successful.
-        if self_ty == target_ty {
+        // Exact match, or script class (runtime) matching its native base class (compile-time).
+        if self_ty.is_compatible_with(&target_ty) {
             return Ok(self);
         }
 
-        // Check if script class (runtime) matches its native base class (compile-time).
-        // This allows an Array[Enemy] from GDScript to be used as Array<Gd<RefCounted>> in Rust.
-        if let (ElementType::ScriptClass(_), ElementType::Class(expected_class)) =
-            (&self_ty, &target_ty)
-        {
-            if let Some(actual_base_class) = self_ty.class_name() {
-                if actual_base_class == *expected_class {
-                    return Ok(self);
-                }
-            }
-        }
-
         Err(FromGodotError::BadArrayType {
             expected: target_ty,
             actual: self_ty,
@@ -1159,7 +1147,9 @@ unsafe impl<T: ArrayElement> GodotFfi for Array<T> {
 }
 
 // Only implement for untyped arrays; typed arrays cannot be nested in Godot.
-impl ArrayElement for VariantArray {}
+impl ArrayElement for VariantArray {
+    type ArgKind = meta::TypedElementArg;
+}
 
 impl<T: ArrayElement> GodotConvert for Array<T> {
     type Via = Self;
@@ -1270,6 +1260,15 @@ where
             PropertyHintInfo::export_array_element::<T>()
         }
     }
+
+    #[doc(hidden)]
+    fn element_variant_type() -> Option<VariantType> {
+        // Untyped arrays and arrays of objects have no per-element hints.
+        match element_variant_type::<T>() {
+            VariantType::NIL | VariantType::OBJECT => None,
+            elem_type => Some(elem_type),
+        }
+    }
 }
 
 impl<T: ArrayElement> BuiltinExport for Array<T> {}
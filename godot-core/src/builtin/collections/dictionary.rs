@@ -13,24 +13,65 @@ use godot_ffi as sys;
 use sys::types::OpaqueDictionary;
 use sys::{ffi_methods, interface_fn, GodotFfi};
 
-use crate::builtin::{inner, Variant, VariantArray};
-use crate::meta::{ElementType, ExtVariantType, FromGodot, ToGodot};
+use crate::builtin::{inner, Variant, VariantArray, VariantType};
+use crate::meta;
+use crate::meta::error::{ConvertError, FromGodotError, FromVariantError};
+use crate::meta::{
+    element_variant_type, ArrayElement, ElementArg, ElementType, ExtVariantType, FromGodot,
+    GodotConvert, GodotFfiVariant, GodotType, PropertyHintInfo, RefArg, ToGodot,
+};
+use crate::registry::property::{BuiltinExport, Export, Var};
 
 /// Godot's `Dictionary` type.
 ///
 /// Ordered associative hash-table, mapping keys to values.
 ///
-/// The keys and values of the dictionary are all `Variant`s, so they can be of different types.
-/// Variants are designed to be generally cheap to clone. Typed dictionaries are planned in a future godot-rust version.
-///
 /// Check out the [book](https://godot-rust.github.io/book/godot-api/builtins.html#arrays-and-dictionaries) for a tutorial on dictionaries.
 ///
-/// # Dictionary example
+/// # Typed dictionaries
+///
+/// Godot's `Dictionary` can be either typed or untyped.
+///
+/// An untyped dictionary can contain any kind of [`Variant`] as key or value, even different types in the same dictionary.
+/// We represent this in Rust as `VarDictionary`, which is just a type alias for `Dictionary<Variant, Variant>`.
+///
+/// Since Godot 4.4, dictionaries can also be typed: keys and values are still stored as `Variant` under the hood, but Godot
+/// performs runtime checks so that no entries of the wrong type are inserted. We represent this as `Dictionary<K, V>`, where
+/// both `K` and `V` must implement [`ArrayElement`]. It's possible to type only one side, e.g. `Dictionary<GString, Variant>`.
+///
+/// Before Godot 4.4, the engine has no notion of typed dictionaries. `Dictionary<K, V>` can still be used, but typing is then only
+/// enforced on the Rust side, by validating all entries when converting from Godot.
+///
+/// The same caveats as for [typed arrays](struct.Array.html#element-type-safety) apply to key and value types.
+///
+/// # Typed dictionary example
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// // Create typed Dictionary<GString, i64> and add entries.
+/// let mut dict = Dictionary::<GString, i64>::new();
+/// dict.set("apples", 12);
+/// dict.set("pears", 7);
+///
+/// // Access values.
+/// let value: i64 = dict.at("apples"); // 12
+/// let maybe: Option<i64> = dict.get("plums"); // None
+///
+/// // Iterate over (GString, i64) pairs.
+/// for (fruit, count) in dict.iter_shared() {
+///     println!("{fruit} => {count}");
+/// }
+///
+/// // Overwrite with set(); use insert() to get the previous value.
+/// let prev = dict.insert("pears", 9); // prev == Some(7)
+/// ```
+///
+/// # Untyped dictionary example
 ///
 /// ```no_run
 /// # use godot::prelude::*;
 /// // Create empty dictionary and add key-values pairs.
-/// let mut dict = Dictionary::new();
+/// let mut dict = VarDictionary::new();
 /// dict.set("str", "Hello");
 /// dict.set("num", 23);
 ///
@@ -79,8 +120,10 @@ use crate::meta::{ElementType, ExtVariantType, FromGodot, ToGodot};
 /// # Godot docs
 ///
 /// [`Dictionary` (stable)](https://docs.godotengine.org/en/stable/classes/class_dictionary.html)
-pub struct Dictionary {
+pub struct Dictionary<K: ArrayElement, V: ArrayElement> {
+    // Safety Invariant: The types of all keys and values in `opaque` match the types `K` and `V`.
     opaque: OpaqueDictionary,
+    _phantom: PhantomData<(K, V)>,
 
     /// Lazily computed and cached element type information for the key type.
     cached_key_type: OnceCell<ElementType>,
@@ -89,10 +132,15 @@ pub struct Dictionary {
     cached_value_type: OnceCell<ElementType>,
 }
 
-impl Dictionary {
+/// A Godot `Dictionary` without an assigned key and value type.
+pub type VarDictionary = Dictionary<Variant, Variant>;
+
+impl<K: ArrayElement, V: ArrayElement> Dictionary<K, V> {
     fn from_opaque(opaque: OpaqueDictionary) -> Self {
+        // Note: type is not yet checked at this point, because dictionary has not yet been initialized!
         Self {
             opaque,
+            _phantom: PhantomData,
             cached_key_type: OnceCell::new(),
             cached_value_type: OnceCell::new(),
         }
@@ -110,12 +158,10 @@ impl Dictionary {
     /// # Panics
     ///
     /// If there is no value for the given key. Note that this is distinct from a `NIL` value, which is returned as `Variant::nil()`.
-    pub fn at<K: ToGodot>(&self, key: K) -> Variant {
-        // Code duplication with get(), to avoid third clone (since K: ToGodot takes ownership).
-
-        let key = key.to_variant();
-        if self.contains_key(key.clone()) {
-            self.get_or_nil(key)
+    pub fn at(&self, key: impl ElementArg<K>) -> V {
+        let key = key.into_element_variant();
+        if self.as_inner().has(&key) {
+            V::from_variant(&self.as_inner().get(&key, &Variant::nil()))
         } else {
             panic!("key {key:?} missing in dictionary: {self:?}")
         }
@@ -123,18 +169,16 @@ impl Dictionary {
 
     /// Returns the value for the given key, or `None`.
     ///
-    /// Note that `NIL` values are returned as `Some(Variant::nil())`, while absent values are returned as `None`.
+    /// Note that `NIL` values are returned as `Some(Variant::nil())` in untyped dictionaries, while absent values are returned as `None`.
     /// If you want to treat both as `NIL`, use [`get_or_nil()`][Self::get_or_nil].
     ///
     /// When you are certain that a key is present, use [`at()`][`Self::at`] instead.
     ///
     /// This can be combined with Rust's `Option` methods, e.g. `dict.get(key).unwrap_or(default)`.
-    pub fn get<K: ToGodot>(&self, key: K) -> Option<Variant> {
-        // If implementation is changed, make sure to update at().
-
-        let key = key.to_variant();
-        if self.contains_key(key.clone()) {
-            Some(self.get_or_nil(key))
+    pub fn get(&self, key: impl ElementArg<K>) -> Option<V> {
+        let key = key.into_element_variant();
+        if self.as_inner().has(&key) {
+            Some(V::from_variant(&self.as_inner().get(&key, &Variant::nil())))
         } else {
             None
         }
@@ -148,8 +192,9 @@ impl Dictionary {
     /// When you are certain that a key is present, use [`at()`][`Self::at`] instead.
     ///
     /// _Godot equivalent: `dict.get(key, null)`_
-    pub fn get_or_nil<K: ToGodot>(&self, key: K) -> Variant {
-        self.as_inner().get(&key.to_variant(), &Variant::nil())
+    pub fn get_or_nil(&self, key: impl ElementArg<K>) -> Variant {
+        self.as_inner()
+            .get(&key.into_element_variant(), &Variant::nil())
     }
 
     /// Gets a value and ensures the key is set, inserting default if key is absent.
@@ -162,26 +207,26 @@ impl Dictionary {
     ///
     /// _Godot equivalent: `get_or_add`_
     #[doc(alias = "get_or_add")]
-    pub fn get_or_insert<K: ToGodot, V: ToGodot>(&mut self, key: K, default: V) -> Variant {
+    pub fn get_or_insert(&mut self, key: impl ElementArg<K>, default: impl ElementArg<V>) -> V {
         self.debug_ensure_mutable();
 
-        let key_variant = key.to_variant();
-        let default_variant = default.to_variant();
+        let key_variant = key.into_element_variant();
+        let default_variant = default.into_element_variant();
 
         // Godot 4.3+: delegate to native get_or_add().
         #[cfg(since_api = "4.3")]
         {
-            self.as_inner().get_or_add(&key_variant, &default_variant)
+            V::from_variant(&self.as_inner().get_or_add(&key_variant, &default_variant))
         }
 
         // Polyfill for Godot versions before 4.3.
         #[cfg(before_api = "4.3")]
         {
-            if let Some(existing_value) = self.get(key_variant.clone()) {
-                existing_value
+            if self.as_inner().has(&key_variant) {
+                V::from_variant(&self.as_inner().get(&key_variant, &Variant::nil()))
             } else {
-                self.set(key_variant, default_variant.clone());
-                default_variant
+                self.set_variant(&key_variant, default_variant.clone());
+                V::from_variant(&default_variant)
             }
         }
     }
@@ -190,9 +235,8 @@ impl Dictionary {
     ///
     /// _Godot equivalent: `has`_
     #[doc(alias = "has")]
-    pub fn contains_key<K: ToGodot>(&self, key: K) -> bool {
-        let key = key.to_variant();
-        self.as_inner().has(&key)
+    pub fn contains_key(&self, key: impl ElementArg<K>) -> bool {
+        self.as_inner().has(&key.into_element_variant())
     }
 
     /// Returns `true` if the dictionary contains all the given keys.
@@ -225,11 +269,11 @@ impl Dictionary {
     ///
     /// _Godot equivalent: `find_key`_
     #[doc(alias = "find_key")]
-    pub fn find_key_by_value<V: ToGodot>(&self, value: V) -> Option<Variant> {
-        let key = self.as_inner().find_key(&value.to_variant());
+    pub fn find_key_by_value(&self, value: impl ElementArg<V>) -> Option<K> {
+        let key = self.as_inner().find_key(&value.into_element_variant());
 
-        if !key.is_nil() || self.contains_key(key.clone()) {
-            Some(key)
+        if !key.is_nil() || self.as_inner().has(&key) {
+            Some(K::from_variant(&key))
         } else {
             None
         }
@@ -247,28 +291,23 @@ impl Dictionary {
     /// If you are interested in the previous value, use [`insert()`][Self::insert] instead.
     ///
     /// _Godot equivalent: `dict[key] = value`_
-    pub fn set<K: ToGodot, V: ToGodot>(&mut self, key: K, value: V) {
+    pub fn set(&mut self, key: impl ElementArg<K>, value: impl ElementArg<V>) {
         self.debug_ensure_mutable();
 
-        let key = key.to_variant();
-
-        // SAFETY: `self.get_ptr_mut(key)` always returns a valid pointer to a value in the dictionary; either pre-existing or newly inserted.
-        unsafe {
-            value.to_variant().move_into_var_ptr(self.get_ptr_mut(key));
-        }
+        self.set_variant(&key.into_element_variant(), value.into_element_variant());
     }
 
     /// Insert a value at the given key, returning the previous value for that key (if available).
     ///
     /// If you don't need the previous value, use [`set()`][Self::set] instead.
     #[must_use]
-    pub fn insert<K: ToGodot, V: ToGodot>(&mut self, key: K, value: V) -> Option<Variant> {
+    pub fn insert(&mut self, key: impl ElementArg<K>, value: impl ElementArg<V>) -> Option<V> {
         self.debug_ensure_mutable();
 
-        let key = key.to_variant();
-        let old_value = self.get(key.clone());
-        self.set(key, value);
-        old_value
+        let key = key.into_element_variant();
+        let old_value = self.get_variant(&key);
+        self.set_variant(&key, value.into_element_variant());
+        old_value.map(|v| V::from_variant(&v))
     }
 
     /// Removes a key from the map, and returns the value associated with
@@ -276,13 +315,13 @@ impl Dictionary {
     ///
     /// _Godot equivalent: `erase`_
     #[doc(alias = "erase")]
-    pub fn remove<K: ToGodot>(&mut self, key: K) -> Option<Variant> {
+    pub fn remove(&mut self, key: impl ElementArg<K>) -> Option<V> {
         self.debug_ensure_mutable();
 
-        let key = key.to_variant();
-        let old_value = self.get(key.clone());
+        let key = key.into_element_variant();
+        let old_value = self.get_variant(&key);
         self.as_inner().erase(&key);
-        old_value
+        old_value.map(|v| V::from_variant(&v))
     }
 
     /// Returns a 32-bit integer hash value representing the dictionary and its contents.
@@ -316,7 +355,7 @@ impl Dictionary {
     pub fn extend_dictionary(&mut self, other: &Self, overwrite: bool) {
         self.debug_ensure_mutable();
 
-        self.as_inner().merge(other, overwrite)
+        self.as_inner().merge(other.as_var_dictionary(), overwrite)
     }
 
     /// Deep copy, duplicating nested collections.
@@ -324,12 +363,16 @@ impl Dictionary {
     /// All nested arrays and dictionaries are duplicated and will not be shared with the original dictionary.
     /// Note that any `Object`-derived elements will still be shallow copied.
     ///
-    /// To create a shallow copy, use [`Self::duplicate_shallow()`] instead.  
+    /// To create a shallow copy, use [`Self::duplicate_shallow()`] instead.
     /// To create a new reference to the same dictionary data, use [`clone()`][Clone::clone].
     ///
     /// _Godot equivalent: `dict.duplicate(true)`_
     pub fn duplicate_deep(&self) -> Self {
-        self.as_inner().duplicate(true).with_cache(self)
+        let duplicate: VarDictionary = self.as_inner().duplicate(true);
+
+        // SAFETY: duplicate() returns a typed dictionary with the same key and value types as the original dictionary.
+        let duplicate = unsafe { duplicate.assume_type::<K, V>() };
+        duplicate.with_cache(self)
     }
 
     /// Shallow copy, copying elements but sharing nested collections.
@@ -337,37 +380,41 @@ impl Dictionary {
     /// All dictionary keys and values are copied, but any reference types (such as `Array`, `Dictionary` and `Gd<T>` objects)
     /// will still refer to the same value.
     ///
-    /// To create a deep copy, use [`Self::duplicate_deep()`] instead.  
+    /// To create a deep copy, use [`Self::duplicate_deep()`] instead.
     /// To create a new reference to the same dictionary data, use [`clone()`][Clone::clone].
     ///
     /// _Godot equivalent: `dict.duplicate(false)`_
     pub fn duplicate_shallow(&self) -> Self {
-        self.as_inner().duplicate(false).with_cache(self)
+        let duplicate: VarDictionary = self.as_inner().duplicate(false);
+
+        // SAFETY: duplicate() returns a typed dictionary with the same key and value types as the original dictionary.
+        let duplicate = unsafe { duplicate.assume_type::<K, V>() };
+        duplicate.with_cache(self)
     }
 
     /// Returns an iterator over the key-value pairs of the `Dictionary`.
     ///
-    /// The pairs are each of type `(Variant, Variant)`. Each pair references the original `Dictionary`, but instead of a `&`-reference
+    /// The pairs are each of type `(K, V)`. Each pair references the original `Dictionary`, but instead of a `&`-reference
     /// to key-value pairs as you might expect, the iterator returns a (cheap, shallow) copy of each key-value pair.
     ///
     /// Note that it's possible to modify the `Dictionary` through another reference while iterating over it. This will not result in
     /// unsoundness or crashes, but will cause the iterator to behave in an unspecified way.
     ///
-    /// Use `dict.iter_shared().typed::<K, V>()` to iterate over `(K, V)` pairs instead.
-    pub fn iter_shared(&self) -> Iter<'_> {
+    /// For untyped dictionaries, use `dict.iter_shared().typed::<K, V>()` to iterate over `(K, V)` pairs instead of `(Variant, Variant)`.
+    pub fn iter_shared(&self) -> Iter<'_, K, V> {
         Iter::new(self)
     }
 
     /// Returns an iterator over the keys in a `Dictionary`.
     ///
-    /// The keys are each of type `Variant`. Each key references the original `Dictionary`, but instead of a `&`-reference to keys pairs
+    /// The keys are each of type `K`. Each key references the original `Dictionary`, but instead of a `&`-reference to keys pairs
     /// as you might expect, the iterator returns a (cheap, shallow) copy of each key pair.
     ///
     /// Note that it's possible to modify the `Dictionary` through another reference while iterating over it. This will not result in
     /// unsoundness or crashes, but will cause the iterator to behave in an unspecified way.
     ///
-    /// Use `dict.keys_shared().typed::<K>()` to iterate over `K` keys instead.
-    pub fn keys_shared(&self) -> Keys<'_> {
+    /// For untyped dictionaries, use `dict.keys_shared().typed::<K>()` to iterate over `K` keys instead of `Variant`.
+    pub fn keys_shared(&self) -> Keys<'_, K> {
         Keys::new(self)
     }
 
@@ -415,8 +462,6 @@ impl Dictionary {
 
     /// Returns the runtime element type information for keys in this dictionary.
     ///
-    /// Provides information about Godot typed dictionaries, even though godot-rust currently doesn't implement generics for those.
-    ///
     /// The result is generally cached, so feel free to call this method repeatedly.
     ///
     /// # Panics (Debug)
@@ -435,8 +480,6 @@ impl Dictionary {
 
     /// Returns the runtime element type information for values in this dictionary.
     ///
-    /// Provides information about Godot typed dictionaries, even though godot-rust currently doesn't implement generics for those.
-    ///
     /// The result is generally cached, so feel free to call this method repeatedly.
     ///
     /// # Panics (Debug)
@@ -453,22 +496,191 @@ impl Dictionary {
         )
     }
 
+    /// Note that writes through the inner dictionary are only OK if they match the types `K` and `V`.
     #[doc(hidden)]
     pub fn as_inner(&self) -> inner::InnerDictionary<'_> {
-        inner::InnerDictionary::from_outer(self)
+        // The memory layout of `Dictionary<K, V>` does not depend on `K` and `V`.
+        inner::InnerDictionary::from_outer_typed(self)
+    }
+
+    /// Returns the value stored under `key` as a `Variant`, or `None` if the key is absent.
+    fn get_variant(&self, key: &Variant) -> Option<Variant> {
+        if self.as_inner().has(key) {
+            Some(self.as_inner().get(key, &Variant::nil()))
+        } else {
+            None
+        }
+    }
+
+    /// Stores `value` under `key`, without any conversions.
+    ///
+    /// Callers must ensure that key and value match the types `K` and `V`.
+    fn set_variant(&mut self, key: &Variant, value: Variant) {
+        // SAFETY: `self.get_ptr_mut(key)` always returns a valid pointer to a value in the dictionary; either pre-existing or newly inserted.
+        unsafe {
+            value.move_into_var_ptr(self.get_ptr_mut(key));
+        }
     }
 
     /// Get the pointer corresponding to the given key in the dictionary.
     ///
     /// If there exists no value at the given key, a `NIL` variant will be inserted for that key.
-    fn get_ptr_mut<K: ToGodot>(&mut self, key: K) -> sys::GDExtensionVariantPtr {
-        let key = key.to_variant();
-
+    fn get_ptr_mut(&mut self, key: &Variant) -> sys::GDExtensionVariantPtr {
         // Never a null pointer, since entry either existed already or was inserted above.
         // SAFETY: accessing an unknown key _mutably_ creates that entry in the dictionary, with value `NIL`.
         unsafe { interface_fn!(dictionary_operator_index)(self.sys_mut(), key.var_sys()) }
     }
 
+    /// Views this dictionary as untyped, for read access and for passing to Godot APIs.
+    fn as_var_dictionary(&self) -> &VarDictionary {
+        // SAFETY: every key and value is internally represented as Variant; see also assume_type().
+        unsafe { self.assume_type_ref::<Variant, Variant>() }
+    }
+
+    /// Changes the generic types on this dictionary, without changing its contents. Needed for API functions that return an
+    /// untyped dictionary even though we know its types, and for API functions that take an untyped dictionary.
+    ///
+    /// # Safety
+    ///
+    /// - Any keys and values written to the dictionary must match the runtime types of the dictionary.
+    /// - Any keys and values read from the dictionary must be convertible to the types `K2` and `V2`.
+    ///
+    /// If the safety invariant of `Dictionary` is intact, which it must be for any publicly accessible dictionaries, then `K2`/`V2`
+    /// must match the runtime types of the dictionary.
+    unsafe fn assume_type<K2: ArrayElement, V2: ArrayElement>(self) -> Dictionary<K2, V2> {
+        // The memory layout of `Dictionary<K, V>` does not depend on `K` and `V`.
+        std::mem::transmute::<Dictionary<K, V>, Dictionary<K2, V2>>(self)
+    }
+
+    /// # Safety
+    /// See [`assume_type`](Self::assume_type).
+    unsafe fn assume_type_ref<K2: ArrayElement, V2: ArrayElement>(&self) -> &Dictionary<K2, V2> {
+        // The memory layout of `Dictionary<K, V>` does not depend on `K` and `V`.
+        std::mem::transmute::<&Dictionary<K, V>, &Dictionary<K2, V2>>(self)
+    }
+
+    /// Checks that the inner dictionary has the correct types set on it for storing keys of type `K` and values of type `V`.
+    #[cfg(since_api = "4.4")]
+    fn with_checked_type(self) -> Result<Self, ConvertError> {
+        let actual_key = self.key_element_type();
+        let actual_value = self.value_element_type();
+        let expected_key = ElementType::of::<K>();
+        let expected_value = ElementType::of::<V>();
+
+        if actual_key.is_compatible_with(&expected_key)
+            && actual_value.is_compatible_with(&expected_value)
+        {
+            return Ok(self);
+        }
+
+        Err(FromGodotError::BadDictionaryType {
+            expected_key,
+            expected_value,
+            actual_key,
+            actual_value,
+        }
+        .into_error(self))
+    }
+
+    /// Checks that all keys and values in the dictionary can be converted to `K` and `V`.
+    ///
+    /// Before Godot 4.4, dictionaries carry no runtime type information, so each entry needs to be validated.
+    #[cfg(before_api = "4.4")]
+    fn with_checked_type(self) -> Result<Self, ConvertError> {
+        if Self::has_variant_kv() {
+            return Ok(self);
+        }
+
+        let all_convertible = self
+            .as_var_dictionary()
+            .iter_shared()
+            .all(|(key, value)| key.try_to::<K>().is_ok() && value.try_to::<V>().is_ok());
+
+        if all_convertible {
+            return Ok(self);
+        }
+
+        Err(FromGodotError::BadDictionaryType {
+            expected_key: ElementType::of::<K>(),
+            expected_value: ElementType::of::<V>(),
+            actual_key: ElementType::Untyped,
+            actual_value: ElementType::Untyped,
+        }
+        .into_error(self))
+    }
+
+    /// Sets the key and value types of the inner dictionary.
+    ///
+    /// # Safety
+    /// Must only be called once, directly after creation.
+    unsafe fn init_inner_type(&mut self) {
+        debug_assert!(self.is_empty());
+        debug_assert!(
+            self.cached_key_type.get().is_none() && self.cached_value_type.get().is_none(),
+            "init_inner_type() called twice"
+        );
+
+        // Godot 4.4+: typed dictionaries are enforced by the engine.
+        #[cfg(since_api = "4.4")]
+        {
+            use crate::builtin::StringName;
+
+            // Immediately set cache to static types.
+            let key_ty = ElementType::of::<K>();
+            let value_ty = ElementType::of::<V>();
+            let _ = self.cached_key_type.set(key_ty);
+            let _ = self.cached_value_type.set(value_ty);
+
+            if key_ty.is_typed() || value_ty.is_typed() {
+                let script = Variant::nil();
+
+                // Class names must be empty if the variant type is not OBJECT. Both must remain valid for the FFI call.
+                let key_class_name = key_ty
+                    .class_name()
+                    .map(|class_name| class_name.to_string_name())
+                    .unwrap_or_default();
+                let value_class_name = value_ty
+                    .class_name()
+                    .map(|class_name| class_name.to_string_name())
+                    .unwrap_or_default();
+
+                // SAFETY: Valid pointers are passed in.
+                // Relevant for correctness, not safety: the dictionary is a newly created, empty, untyped dictionary.
+                unsafe {
+                    interface_fn!(dictionary_set_typed)(
+                        self.sys_mut(),
+                        key_ty.variant_type().sys(),
+                        key_class_name.string_sys(),
+                        script.var_sys(),
+                        value_ty.variant_type().sys(),
+                        value_class_name.string_sys(),
+                        script.var_sys(),
+                    );
+                }
+            }
+        }
+    }
+
+    /// Returns a clone of the dictionary without checking the resulting type.
+    ///
+    /// # Safety
+    /// Should be used only in scenarios where the caller can guarantee that the resulting dictionary will have the correct types,
+    /// or when an incorrect Rust type is acceptable (passing raw dictionaries to Godot FFI).
+    unsafe fn clone_unchecked(&self) -> Self {
+        let result = Self::new_with_uninit(|self_ptr| {
+            let ctor = sys::builtin_fn!(dictionary_construct_copy);
+            let args = [self.sys()];
+            ctor(self_ptr, args.as_ptr());
+        });
+        result.with_cache(self)
+    }
+
+    /// Whether this dictionary is untyped and holds `Variant` keys and values (compile-time check).
+    fn has_variant_kv() -> bool {
+        element_variant_type::<K>() == VariantType::NIL
+            && element_variant_type::<V>() == VariantType::NIL
+    }
+
     /// Execute a function that creates a new Dictionary, transferring cached element types if available.
     ///
     /// This is a convenience helper for methods that create new Dictionary instances and want to preserve
@@ -481,6 +693,19 @@ impl Dictionary {
     }
 }
 
+impl VarDictionary {
+    /// # Safety
+    /// - Variant must have type `VariantType::DICTIONARY`.
+    /// - Subsequent operations on this dictionary must not rely on the key/value types of the dictionary.
+    pub(crate) unsafe fn from_variant_unchecked(variant: &Variant) -> Self {
+        // See also ffi_from_variant().
+        Self::new_with_uninit(|self_ptr| {
+            let dictionary_from_variant = sys::builtin_fn!(dictionary_from_variant);
+            dictionary_from_variant(self_ptr, sys::SysPtr::force_mut(variant.var_sys()));
+        })
+    }
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Traits
 
@@ -493,35 +718,95 @@ impl Dictionary {
 //   Dictionaries are properly initialized through a `from_sys` call, but the ref-count should be
 //   incremented as that is the callee's responsibility. Which we do by calling
 //   `std::mem::forget(dictionary.clone())`.
-unsafe impl GodotFfi for Dictionary {
+unsafe impl<K: ArrayElement, V: ArrayElement> GodotFfi for Dictionary<K, V> {
     const VARIANT_TYPE: ExtVariantType = ExtVariantType::Concrete(sys::VariantType::DICTIONARY);
 
     ffi_methods! { type sys::GDExtensionTypePtr = *mut Opaque; .. }
 }
 
-crate::meta::impl_godot_as_self!(Dictionary: ByRef);
+// Only implement for untyped dictionaries; typed dictionaries cannot be nested in Godot.
+impl ArrayElement for VarDictionary {
+    type ArgKind = meta::TypedElementArg;
+}
+
+impl<K: ArrayElement, V: ArrayElement> GodotConvert for Dictionary<K, V> {
+    type Via = Self;
+}
+
+impl<K: ArrayElement, V: ArrayElement> ToGodot for Dictionary<K, V> {
+    type Pass = meta::ByRef;
+
+    fn to_godot(&self) -> &Self::Via {
+        self
+    }
+
+    fn to_godot_owned(&self) -> Self::Via
+    where
+        Self::Via: Clone,
+    {
+        // Overridden, because default clone() validates that before/after element types are equal, which doesn't matter when we pass to FFI.
+        unsafe { self.clone_unchecked() }
+    }
+}
+
+impl<K: ArrayElement, V: ArrayElement> FromGodot for Dictionary<K, V> {
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        Ok(via)
+    }
+}
+
+impl<K: ArrayElement, V: ArrayElement> Default for Dictionary<K, V> {
+    #[inline]
+    fn default() -> Self {
+        let mut dictionary = unsafe {
+            Self::new_with_uninit(|self_ptr| {
+                let ctor = sys::builtin_fn!(dictionary_construct_default);
+                ctor(self_ptr, std::ptr::null_mut())
+            })
+        };
+
+        // SAFETY: We just created this dictionary, and haven't called `init_inner_type` before.
+        unsafe { dictionary.init_inner_type() };
+        dictionary
+    }
+}
+
+impl<K: ArrayElement, V: ArrayElement> Drop for Dictionary<K, V> {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe {
+            let dictionary_destroy = sys::builtin_fn!(dictionary_destroy);
+            dictionary_destroy(self.sys_mut());
+        }
+    }
+}
 
-impl_builtin_traits! {
-    for Dictionary {
-        Default => dictionary_construct_default;
-        Drop => dictionary_destroy;
-        PartialEq => dictionary_operator_equal;
-        // No < operator for dictionaries.
-        // Hash could be added, but without Eq it's not that useful.
+impl<K: ArrayElement, V: ArrayElement> PartialEq for Dictionary<K, V> {
+    // No < operator for dictionaries.
+    // Hash could be added, but without Eq it's not that useful.
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        unsafe {
+            let mut result = false;
+            sys::builtin_call! {
+                dictionary_operator_equal(self.sys(), other.sys(), result.sys_mut())
+            }
+            result
+        }
     }
 }
 
-impl fmt::Debug for Dictionary {
+impl<K: ArrayElement, V: ArrayElement> fmt::Debug for Dictionary<K, V> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{:?}", self.to_variant().stringify())
     }
 }
 
-impl fmt::Display for Dictionary {
+impl<K: ArrayElement, V: ArrayElement> fmt::Display for Dictionary<K, V> {
     /// Formats `Dictionary` to match Godot's string representation.
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{{ ")?;
-        for (count, (key, value)) in self.iter_shared().enumerate() {
+        for (count, (key, value)) in self.as_var_dictionary().iter_shared().enumerate() {
             if count != 0 {
                 write!(f, ", ")?;
             }
@@ -536,27 +821,131 @@ impl fmt::Display for Dictionary {
 ///
 /// To create a (mostly) independent copy instead, see [`Dictionary::duplicate_shallow()`] and
 /// [`Dictionary::duplicate_deep()`].
-impl Clone for Dictionary {
+impl<K: ArrayElement, V: ArrayElement> Clone for Dictionary<K, V> {
     fn clone(&self) -> Self {
         // SAFETY: `self` is a valid dictionary, since we have a reference that keeps it alive.
-        let result = unsafe {
+        // Type-check follows below.
+        let copy = unsafe { self.clone_unchecked() };
+
+        // Double-check copy's runtime type in Debug mode. Before Godot 4.4, there is no runtime type to check against, and validating
+        // each element would make every clone O(n).
+        if cfg!(debug_assertions) && cfg!(since_api = "4.4") {
+            copy.with_checked_type()
+                .expect("copied dictionary should have same type as original dictionary")
+        } else {
+            copy
+        }
+    }
+}
+
+impl<K: ArrayElement, V: ArrayElement> Var for Dictionary<K, V> {
+    fn get_property(&self) -> Self::Via {
+        self.to_godot_owned()
+    }
+
+    fn set_property(&mut self, value: Self::Via) {
+        *self = FromGodot::from_godot(value)
+    }
+
+    fn var_hint() -> PropertyHintInfo {
+        // For dictionary #[var], the hint string is "String;int", "StringName;Node" etc. for typed dictionaries, and "" for untyped ones.
+        #[cfg(since_api = "4.4")]
+        if !Self::has_variant_kv() {
+            return PropertyHintInfo::var_dictionary_element::<K, V>();
+        }
+
+        PropertyHintInfo::none()
+    }
+}
+
+impl<K, V> Export for Dictionary<K, V>
+where
+    K: ArrayElement + Export,
+    V: ArrayElement + Export,
+{
+    fn export_hint() -> PropertyHintInfo {
+        // If K == V == Variant, then we return "Dictionary" builtin type hint.
+        // Typed dictionaries are only recognized by the editor since Godot 4.4.
+        #[cfg(since_api = "4.4")]
+        if !Self::has_variant_kv() {
+            return PropertyHintInfo::export_dictionary_element::<K, V>();
+        }
+
+        PropertyHintInfo::type_name::<VarDictionary>()
+    }
+}
+
+impl<K: ArrayElement, V: ArrayElement> BuiltinExport for Dictionary<K, V> {}
+
+impl<K: ArrayElement, V: ArrayElement> GodotType for Dictionary<K, V> {
+    type Ffi = Self;
+
+    type ToFfi<'f>
+        = RefArg<'f, Dictionary<K, V>>
+    where
+        Self: 'f;
+
+    fn to_ffi(&self) -> Self::ToFfi<'_> {
+        RefArg::new(self)
+    }
+
+    fn into_ffi(self) -> Self::Ffi {
+        self
+    }
+
+    fn try_from_ffi(ffi: Self::Ffi) -> Result<Self, ConvertError> {
+        Ok(ffi)
+    }
+
+    fn godot_type_name() -> String {
+        "Dictionary".to_string()
+    }
+
+    fn property_hint_info() -> PropertyHintInfo {
+        // Typed dictionaries use type hint; untyped ones (and all dictionaries before Godot 4.4) have no hints.
+        <Self as Var>::var_hint()
+    }
+}
+
+impl<K: ArrayElement, V: ArrayElement> GodotFfiVariant for Dictionary<K, V> {
+    fn ffi_to_variant(&self) -> Variant {
+        unsafe {
+            Variant::new_with_var_uninit(|variant_ptr| {
+                let dictionary_to_variant = sys::builtin_fn!(dictionary_to_variant);
+                dictionary_to_variant(variant_ptr, sys::SysPtr::force_mut(self.sys()));
+            })
+        }
+    }
+
+    fn ffi_from_variant(variant: &Variant) -> Result<Self, ConvertError> {
+        // First check if the variant is a dictionary. The dictionary conversion shouldn't be called otherwise.
+        if variant.get_type() != Self::VARIANT_TYPE.variant_as_nil() {
+            return Err(FromVariantError::BadType {
+                expected: Self::VARIANT_TYPE.variant_as_nil(),
+                actual: variant.get_type(),
+            }
+            .into_error(variant.clone()));
+        }
+
+        let dictionary = unsafe {
             Self::new_with_uninit(|self_ptr| {
-                let ctor = sys::builtin_fn!(dictionary_construct_copy);
-                let args = [self.sys()];
-                ctor(self_ptr, args.as_ptr());
+                let dictionary_from_variant = sys::builtin_fn!(dictionary_from_variant);
+                dictionary_from_variant(self_ptr, sys::SysPtr::force_mut(variant.var_sys()));
             })
         };
-        result.with_cache(self)
+
+        // Then, check the runtime types of the dictionary.
+        dictionary.with_checked_type()
     }
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Conversion traits
 
-/// Creates a dictionary from the given iterator `I` over a `(&K, &V)` key-value pair.
+/// Creates an untyped dictionary from the given iterator `I` over a `(&K, &V)` key-value pair.
 ///
 /// Each key and value are converted to a `Variant`.
-impl<'a, 'b, K, V, I> From<I> for Dictionary
+impl<'a, 'b, K, V, I> From<I> for VarDictionary
 where
     I: IntoIterator<Item = (&'a K, &'b V)>,
     K: ToGodot + 'a,
@@ -574,17 +963,21 @@ where
 ///
 /// Inserts all key-value pairs from the iterator into the dictionary. Previous values for keys appearing
 /// in `iter` will be overwritten.
-impl<K: ToGodot, V: ToGodot> Extend<(K, V)> for Dictionary {
+impl<K: ArrayElement, V: ArrayElement> Extend<(K, V)> for Dictionary<K, V> {
     fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        self.debug_ensure_mutable();
+
         for (k, v) in iter.into_iter() {
-            self.set(k.to_variant(), v.to_variant())
+            // Generic K and V cannot be passed as `impl ElementArg`; convert directly.
+            self.set_variant(&k.to_variant(), v.to_variant());
         }
     }
 }
 
-impl<K: ToGodot, V: ToGodot> FromIterator<(K, V)> for Dictionary {
+/// Creates a `Dictionary` from an iterator over key-value pairs.
+impl<K: ArrayElement, V: ArrayElement> FromIterator<(K, V)> for Dictionary<K, V> {
     fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
-        let mut dict = Dictionary::new();
+        let mut dict = Self::new();
         dict.extend(iter);
         dict
     }
@@ -595,13 +988,13 @@ impl<K: ToGodot, V: ToGodot> FromIterator<(K, V)> for Dictionary {
 /// Internal helper for different iterator impls -- not an iterator itself
 struct DictionaryIter<'a> {
     last_key: Option<Variant>,
-    dictionary: &'a Dictionary,
+    dictionary: &'a VarDictionary,
     is_first: bool,
     next_idx: usize,
 }
 
 impl<'a> DictionaryIter<'a> {
-    fn new(dictionary: &'a Dictionary) -> Self {
+    fn new(dictionary: &'a VarDictionary) -> Self {
         Self {
             last_key: None,
             dictionary,
@@ -628,7 +1021,7 @@ impl<'a> DictionaryIter<'a> {
 
     fn next_key_value(&mut self) -> Option<(Variant, Variant)> {
         let key = self.next_key()?;
-        if !self.dictionary.contains_key(key.clone()) {
+        if !self.dictionary.as_inner().has(&key) {
             return None;
         }
 
@@ -644,7 +1037,7 @@ impl<'a> DictionaryIter<'a> {
         (remaining, Some(remaining))
     }
 
-    fn call_init(dictionary: &Dictionary) -> Option<Variant> {
+    fn call_init(dictionary: &VarDictionary) -> Option<Variant> {
         let variant: Variant = Variant::nil();
         let iter_fn = |dictionary, next_value: sys::GDExtensionVariantPtr, valid| unsafe {
             interface_fn!(variant_iter_init)(dictionary, sys::SysPtr::as_uninit(next_value), valid)
@@ -653,7 +1046,7 @@ impl<'a> DictionaryIter<'a> {
         Self::ffi_iterate(iter_fn, dictionary, variant)
     }
 
-    fn call_next(dictionary: &Dictionary, last_key: Variant) -> Option<Variant> {
+    fn call_next(dictionary: &VarDictionary, last_key: Variant) -> Option<Variant> {
         let iter_fn = |dictionary, next_value, valid| unsafe {
             interface_fn!(variant_iter_next)(dictionary, next_value, valid)
         };
@@ -671,7 +1064,7 @@ impl<'a> DictionaryIter<'a> {
             sys::GDExtensionVariantPtr,
             *mut sys::GDExtensionBool,
         ) -> sys::GDExtensionBool,
-        dictionary: &Dictionary,
+        dictionary: &VarDictionary,
         mut next_value: Variant,
     ) -> Option<Variant> {
         let dictionary = dictionary.to_variant();
@@ -705,29 +1098,35 @@ impl<'a> DictionaryIter<'a> {
 /// Iterator over key-value pairs in a [`Dictionary`].
 ///
 /// See [`Dictionary::iter_shared()`] for more information about iteration over dictionaries.
-pub struct Iter<'a> {
+pub struct Iter<'a, K: ArrayElement, V: ArrayElement> {
     iter: DictionaryIter<'a>,
+    _kv: PhantomData<(K, V)>,
 }
 
-impl<'a> Iter<'a> {
-    fn new(dictionary: &'a Dictionary) -> Self {
+impl<'a, K: ArrayElement, V: ArrayElement> Iter<'a, K, V> {
+    fn new(dictionary: &'a Dictionary<K, V>) -> Self {
         Self {
-            iter: DictionaryIter::new(dictionary),
+            iter: DictionaryIter::new(dictionary.as_var_dictionary()),
+            _kv: PhantomData,
         }
     }
 
-    /// Creates an iterator that converts each `(Variant, Variant)` key-value pair into a `(K, V)` key-value
+    /// Creates an iterator that converts each key-value pair into a `(K2, V2)` key-value
     /// pair, panicking upon conversion failure.
-    pub fn typed<K: FromGodot, V: FromGodot>(self) -> TypedIter<'a, K, V> {
-        TypedIter::from_untyped(self)
+    ///
+    /// Mostly useful for untyped dictionaries, where the iterator otherwise yields `(Variant, Variant)`.
+    pub fn typed<K2: FromGodot, V2: FromGodot>(self) -> TypedIter<'a, K2, V2> {
+        TypedIter::from_untyped(self.iter)
     }
 }
 
-impl Iterator for Iter<'_> {
-    type Item = (Variant, Variant);
+impl<K: ArrayElement, V: ArrayElement> Iterator for Iter<'_, K, V> {
+    type Item = (K, V);
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.iter.next_key_value()
+        self.iter
+            .next_key_value()
+            .map(|(key, value)| (K::from_variant(&key), V::from_variant(&value)))
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
@@ -740,21 +1139,25 @@ impl Iterator for Iter<'_> {
 /// Iterator over keys in a [`Dictionary`].
 ///
 /// See [`Dictionary::keys_shared()`] for more information about iteration over dictionaries.
-pub struct Keys<'a> {
+pub struct Keys<'a, K: ArrayElement> {
     iter: DictionaryIter<'a>,
+    _k: PhantomData<K>,
 }
 
-impl<'a> Keys<'a> {
-    fn new(dictionary: &'a Dictionary) -> Self {
+impl<'a, K: ArrayElement> Keys<'a, K> {
+    fn new<V: ArrayElement>(dictionary: &'a Dictionary<K, V>) -> Self {
         Self {
-            iter: DictionaryIter::new(dictionary),
+            iter: DictionaryIter::new(dictionary.as_var_dictionary()),
+            _k: PhantomData,
         }
     }
 
-    /// Creates an iterator that will convert each `Variant` key into a key of type `K`,
+    /// Creates an iterator that will convert each key into a key of type `K2`,
     /// panicking upon failure to convert.
-    pub fn typed<K: FromGodot>(self) -> TypedKeys<'a, K> {
-        TypedKeys::from_untyped(self)
+    ///
+    /// Mostly useful for untyped dictionaries, where the iterator otherwise yields `Variant`.
+    pub fn typed<K2: FromGodot>(self) -> TypedKeys<'a, K2> {
+        TypedKeys::from_untyped(self.iter)
     }
 
     /// Returns an array of the keys.
@@ -765,11 +1168,11 @@ impl<'a> Keys<'a> {
     }
 }
 
-impl Iterator for Keys<'_> {
-    type Item = Variant;
+impl<K: ArrayElement> Iterator for Keys<'_, K> {
+    type Item = K;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.iter.next_key()
+        self.iter.next_key().map(|key| K::from_variant(&key))
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
@@ -789,9 +1192,9 @@ pub struct TypedIter<'a, K, V> {
 }
 
 impl<'a, K, V> TypedIter<'a, K, V> {
-    fn from_untyped(value: Iter<'a>) -> Self {
+    fn from_untyped(iter: DictionaryIter<'a>) -> Self {
         Self {
-            iter: value.iter,
+            iter,
             _k: PhantomData,
             _v: PhantomData,
         }
@@ -823,9 +1226,9 @@ pub struct TypedKeys<'a, K> {
 }
 
 impl<'a, K> TypedKeys<'a, K> {
-    fn from_untyped(value: Keys<'a>) -> Self {
+    fn from_untyped(iter: DictionaryIter<'a>) -> Self {
         Self {
-            iter: value.iter,
+            iter,
             _k: PhantomData,
         }
     }
@@ -856,10 +1259,10 @@ fn u8_to_bool(u: u8) -> bool {
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
-/// Constructs [`Dictionary`] literals, close to Godot's own syntax.
+/// Constructs [`VarDictionary`] literals, close to Godot's own syntax.
 ///
 /// Any value can be used as a key, but to use an expression you need to surround it
-/// in `()` or `{}`.
+/// in `()` or `{}`. Keys and values are converted to [`Variant`].
 ///
 /// # Example
 /// ```no_run
@@ -881,7 +1284,7 @@ fn u8_to_bool(u: u8) -> bool {
 macro_rules! vdict {
     ($($key:tt: $value:expr),* $(,)?) => {
         {
-            let mut d = $crate::builtin::Dictionary::new();
+            let mut d = $crate::builtin::VarDictionary::new();
             $(
                 // `cargo check` complains that `(1 + 2): true` has unused parens, even though it's not
                 // possible to omit the parens.
@@ -902,3 +1305,84 @@ macro_rules! dict {
         )
     };
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "serde")]
+mod serialize {
+    use std::marker::PhantomData;
+
+    use serde::de::{MapAccess, Visitor};
+    use serde::ser::SerializeMap;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::*;
+
+    /// Serialized as a map, like `HashMap<K, V>`.
+    ///
+    /// Formats that require string keys (such as JSON) only support dictionaries whose keys serialize as strings, e.g. `GString`.
+    /// Dictionaries with arbitrary keys can be serialized through [`Variant`], which stores them as a list of key-value pairs.
+    #[cfg_attr(published_docs, doc(cfg(feature = "serde")))]
+    impl<K, V> Serialize for Dictionary<K, V>
+    where
+        K: ArrayElement + Serialize,
+        V: ArrayElement + Serialize,
+    {
+        #[inline]
+        fn serialize<S>(
+            &self,
+            serializer: S,
+        ) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where
+            S: Serializer,
+        {
+            let mut map = serializer.serialize_map(Some(self.len()))?;
+            for (key, value) in self.iter_shared() {
+                map.serialize_entry(&key, &value)?;
+            }
+            map.end()
+        }
+    }
+
+    #[cfg_attr(published_docs, doc(cfg(feature = "serde")))]
+    impl<'de, K, V> Deserialize<'de> for Dictionary<K, V>
+    where
+        K: ArrayElement + Deserialize<'de>,
+        V: ArrayElement + Deserialize<'de>,
+    {
+        #[inline]
+        fn deserialize<D>(deserializer: D) -> Result<Self, <D as Deserializer<'de>>::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            struct DictionaryVisitor<K, V>(PhantomData<(K, V)>);
+            impl<'de, K, V> Visitor<'de> for DictionaryVisitor<K, V>
+            where
+                K: ArrayElement + Deserialize<'de>,
+                V: ArrayElement + Deserialize<'de>,
+            {
+                type Value = Dictionary<K, V>;
+
+                fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                    formatter.write_str(std::any::type_name::<Self::Value>())
+                }
+
+                fn visit_map<A>(
+                    self,
+                    mut map: A,
+                ) -> Result<Self::Value, <A as MapAccess<'de>>::Error>
+                where
+                    A: MapAccess<'de>,
+                {
+                    let mut entries = map.size_hint().map_or_else(Vec::new, Vec::with_capacity);
+                    while let Some(entry) = map.next_entry::<K, V>()? {
+                        entries.push(entry);
+                    }
+                    Ok(entries.into_iter().collect())
+                }
+            }
+
+            deserializer.deserialize_map(DictionaryVisitor::<K, V>(PhantomData))
+        }
+    }
+}
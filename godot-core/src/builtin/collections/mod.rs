@@ -14,7 +14,7 @@ mod packed_array_element;
 // Re-export in godot::builtin.
 pub(crate) mod containers {
     pub use super::array::{Array, VariantArray};
-    pub use super::dictionary::Dictionary;
+    pub use super::dictionary::{Dictionary, VarDictionary};
     pub use super::packed_array::*;
 }
 
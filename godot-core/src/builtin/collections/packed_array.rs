@@ -463,7 +463,9 @@ impl<T: PackedArrayElement> FromGodot for PackedArray<T> {
     }
 }
 
-impl<T: PackedArrayElement> meta::ArrayElement for PackedArray<T> {}
+impl<T: PackedArrayElement> meta::ArrayElement for PackedArray<T> {
+    type ArgKind = meta::TypedElementArg;
+}
 
 impl<T: PackedArrayElement> meta::GodotType for PackedArray<T> {
     type Ffi = Self;
@@ -562,6 +564,11 @@ impl<T: PackedArrayElement> Export for PackedArray<T> {
             meta::PropertyHintInfo::type_name::<PackedArray<T>>()
         }
     }
+
+    #[doc(hidden)]
+    fn element_variant_type() -> Option<VariantType> {
+        Some(<T as meta::GodotType>::Ffi::VARIANT_TYPE.variant_as_nil())
+    }
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
@@ -1055,3 +1062,77 @@ fn populated_or_err(array: PackedByteArray) -> Result<PackedByteArray, ()> {
         Ok(array)
     }
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "serde")]
+mod serialize {
+    use std::marker::PhantomData;
+
+    use serde::de::{SeqAccess, Visitor};
+    use serde::ser::SerializeSeq;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::*;
+
+    #[cfg_attr(published_docs, doc(cfg(feature = "serde")))]
+    impl<T> Serialize for PackedArray<T>
+    where
+        T: PackedArrayElement + Serialize,
+    {
+        #[inline]
+        fn serialize<S>(
+            &self,
+            serializer: S,
+        ) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+        where
+            S: Serializer,
+        {
+            let mut sequence = serializer.serialize_seq(Some(self.len()))?;
+            for e in self.as_slice() {
+                sequence.serialize_element(e)?
+            }
+            sequence.end()
+        }
+    }
+
+    #[cfg_attr(published_docs, doc(cfg(feature = "serde")))]
+    impl<'de, T> Deserialize<'de> for PackedArray<T>
+    where
+        T: PackedArrayElement + Deserialize<'de>,
+    {
+        #[inline]
+        fn deserialize<D>(deserializer: D) -> Result<Self, <D as Deserializer<'de>>::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            struct PackedArrayVisitor<T>(PhantomData<T>);
+            impl<'de, T> Visitor<'de> for PackedArrayVisitor<T>
+            where
+                T: PackedArrayElement + Deserialize<'de>,
+            {
+                type Value = PackedArray<T>;
+
+                fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                    formatter.write_str(std::any::type_name::<Self::Value>())
+                }
+
+                fn visit_seq<A>(
+                    self,
+                    mut seq: A,
+                ) -> Result<Self::Value, <A as SeqAccess<'de>>::Error>
+                where
+                    A: SeqAccess<'de>,
+                {
+                    let mut vec = seq.size_hint().map_or_else(Vec::new, Vec::with_capacity);
+                    while let Some(val) = seq.next_element::<T>()? {
+                        vec.push(val);
+                    }
+                    Ok(Self::Value::from(vec))
+                }
+            }
+
+            deserializer.deserialize_seq(PackedArrayVisitor::<T>(PhantomData))
+        }
+    }
+}
@@ -86,7 +86,9 @@ macro_rules! impl_ffi_variant {
             impl_ffi_variant!(@godot_type_name $T $(, $GodotTy)?);
         }
 
-        impl ArrayElement for $T {}
+        impl ArrayElement for $T {
+            type ArgKind = crate::meta::TypedElementArg;
+        }
     };
 
     (@godot_type_name $T:ty) => {
@@ -152,7 +154,6 @@ mod impls {
     impl_ffi_variant!(ref GString, string_to_variant, string_from_variant; String);
     impl_ffi_variant!(ref StringName, string_name_to_variant, string_name_from_variant);
     impl_ffi_variant!(ref NodePath, node_path_to_variant, node_path_from_variant);
-    impl_ffi_variant!(ref Dictionary, dictionary_to_variant, dictionary_from_variant);
     impl_ffi_variant!(ref Signal, signal_to_variant, signal_from_variant);
     impl_ffi_variant!(ref Callable, callable_to_variant, callable_from_variant);
 }
@@ -176,6 +177,22 @@ impl<T: ArrayElement> IntoDynamicSend for Array<T> {
     }
 }
 
+impl<K: ArrayElement, V: ArrayElement> Sealed for ThreadConfined<Dictionary<K, V>> {}
+
+unsafe impl<K: ArrayElement, V: ArrayElement> DynamicSend for ThreadConfined<Dictionary<K, V>> {
+    type Inner = Dictionary<K, V>;
+    fn extract_if_safe(self) -> Option<Self::Inner> {
+        self.extract()
+    }
+}
+
+impl<K: ArrayElement, V: ArrayElement> IntoDynamicSend for Dictionary<K, V> {
+    type Target = ThreadConfined<Dictionary<K, V>>;
+    fn into_dynamic_send(self) -> Self::Target {
+        ThreadConfined::new(self)
+    }
+}
+
 impl_dynamic_send!(
     Send;
     bool, u8, u16, u32, u64, i8, i16, i32, i64, f32, f64
@@ -194,7 +211,7 @@ impl_dynamic_send!(
 
 impl_dynamic_send!(
     !Send;
-    Variant, NodePath, GString, Dictionary, Callable, Signal,
+    Variant, NodePath, GString, Callable, Signal,
     PackedByteArray, PackedInt32Array, PackedInt64Array, PackedFloat32Array, PackedFloat64Array, PackedStringArray,
     PackedVector2Array, PackedVector3Array, PackedColorArray
 );
@@ -263,7 +280,7 @@ const _: () = {
     const OBJECT: VariantType = variant_type::<Gd<Object>>();
     const CALLABLE: VariantType = variant_type::<Callable>();
     const SIGNAL: VariantType = variant_type::<Signal>();
-    const DICTIONARY: VariantType = variant_type::<Dictionary>();
+    const DICTIONARY: VariantType = variant_type::<VarDictionary>();
     const ARRAY: VariantType = variant_type::<VariantArray>();
     const PACKED_BYTE_ARRAY: VariantType = variant_type::<PackedByteArray>();
     const PACKED_INT32_ARRAY: VariantType = variant_type::<PackedInt32Array>();
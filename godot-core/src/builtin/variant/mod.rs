@@ -11,7 +11,7 @@ use godot_ffi as sys;
 use sys::{ffi_methods, interface_fn, GodotFfi};
 
 use crate::builtin::{
-    GString, StringName, VariantArray, VariantDispatch, VariantOperator, VariantType,
+    GString, StringName, VarDictionary, VariantArray, VariantDispatch, VariantOperator, VariantType,
 };
 use crate::classes;
 use crate::meta::error::{ConvertError, FromVariantError};
@@ -21,6 +21,8 @@ use crate::meta::{
 };
 
 mod impls;
+#[cfg(feature = "serde")]
+mod serialize;
 
 /// Godot variant type, able to store a variety of different types.
 ///
@@ -515,7 +517,9 @@ impl Variant {
     }
 }
 
-impl ArrayElement for Variant {}
+impl ArrayElement for Variant {
+    type ArgKind = crate::meta::UntypedElementArg;
+}
 
 // SAFETY:
 // `from_opaque` properly initializes a dereferenced pointer to an `OpaqueVariant`.
@@ -584,6 +588,13 @@ impl fmt::Debug for Variant {
                 array.fmt(f)
             }
 
+            // Same for dictionaries, which may be typed since Godot 4.4.
+            VariantType::DICTIONARY => {
+                // SAFETY: type is checked, and only operation is print (out data flow, no covariant in access).
+                let dictionary = unsafe { VarDictionary::from_variant_unchecked(self) };
+                dictionary.fmt(f)
+            }
+
             // Converting to objects before printing causes their refcount to increment, leading to an Observer effect
             // where `Debug` actually changes the object statistics. As such, fetch information without instantiating Gd<T>.
             VariantType::OBJECT => classes::debug_string_variant(self, f, "VariantGd"),
@@ -0,0 +1,274 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Serde support for `Variant`.
+//!
+//! A `Variant` is serialized in a self-describing, externally tagged form: `"Nil"` for nil, and `{ "<type>": value }` otherwise,
+//! where `<type>` is the Godot type name (as in GDScript's `type_string()`). Examples in JSON:
+//! - `{"int":42}`
+//! - `{"Vector2":{"x":1.0,"y":2.0}}`
+//! - `{"Array":[{"bool":true},"Nil"]}`
+//!
+//! Dictionaries are stored as a list of `[key, value]` pairs, so that keys of any type can be represented, even in formats that
+//! only support string keys.
+//!
+//! Objects, callables, signals and RIDs cannot be serialized; attempting to do so results in an error.
+
+use std::fmt;
+
+use serde::de::{EnumAccess, Unexpected, VariantAccess, Visitor};
+use serde::ser::SerializeSeq;
+use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::builtin::*;
+use crate::meta::ToGodot;
+
+const NAME: &str = "Variant";
+
+/// Godot type names of variant types that cannot be (de)serialized.
+const UNSUPPORTED_TAGS: &[&str] = &["RID", "Object", "Callable", "Signal"];
+
+macro_rules! impl_variant_serde {
+    (
+        $( $(#[$attr:meta])* $variant_type:ident => $tag:literal: $Ty:ty, )*
+    ) => {
+        /// All supported variant types with their tags.
+        ///
+        /// The position in this list is used as the variant index by non-self-describing formats. New entries must be appended.
+        const TAGS: &[(VariantType, &str)] = &[
+            (VariantType::NIL, "Nil"),
+            (VariantType::ARRAY, "Array"),
+            (VariantType::DICTIONARY, "Dictionary"),
+            $( $(#[$attr])* (VariantType::$variant_type, $tag), )*
+        ];
+
+        /// Same as [`TAGS`], but only the names (required by `Deserializer::deserialize_enum()`).
+        const TAG_NAMES: &[&str] = &[
+            "Nil",
+            "Array",
+            "Dictionary",
+            $( $(#[$attr])* $tag, )*
+        ];
+
+        fn serialize_value<S>(
+            variant: &Variant,
+            index: u32,
+            tag: &'static str,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            match variant.get_type() {
+                $(
+                    $(#[$attr])*
+                    VariantType::$variant_type => {
+                        serializer.serialize_newtype_variant(NAME, index, tag, &variant.to::<$Ty>())
+                    }
+                )*
+                other => unreachable!("variant type {other:?} is not a value type"),
+            }
+        }
+
+        fn deserialize_value<'de, A>(variant_type: VariantType, access: A) -> Result<Variant, A::Error>
+        where
+            A: VariantAccess<'de>,
+        {
+            match variant_type {
+                $(
+                    $(#[$attr])*
+                    VariantType::$variant_type => access.newtype_variant::<$Ty>().map(|value| value.to_variant()),
+                )*
+                other => unreachable!("variant type {other:?} is not a value type"),
+            }
+        }
+    };
+}
+
+impl_variant_serde! {
+    BOOL => "bool": bool,
+    INT => "int": i64,
+    FLOAT => "float": f64,
+    STRING => "String": GString,
+    VECTOR2 => "Vector2": Vector2,
+    VECTOR2I => "Vector2i": Vector2i,
+    RECT2 => "Rect2": Rect2,
+    RECT2I => "Rect2i": Rect2i,
+    VECTOR3 => "Vector3": Vector3,
+    VECTOR3I => "Vector3i": Vector3i,
+    TRANSFORM2D => "Transform2D": Transform2D,
+    VECTOR4 => "Vector4": Vector4,
+    VECTOR4I => "Vector4i": Vector4i,
+    PLANE => "Plane": Plane,
+    QUATERNION => "Quaternion": Quaternion,
+    AABB => "AABB": Aabb,
+    BASIS => "Basis": Basis,
+    TRANSFORM3D => "Transform3D": Transform3D,
+    PROJECTION => "Projection": Projection,
+    COLOR => "Color": Color,
+    STRING_NAME => "StringName": StringName,
+    NODE_PATH => "NodePath": NodePath,
+    PACKED_BYTE_ARRAY => "PackedByteArray": PackedByteArray,
+    PACKED_INT32_ARRAY => "PackedInt32Array": PackedInt32Array,
+    PACKED_INT64_ARRAY => "PackedInt64Array": PackedInt64Array,
+    PACKED_FLOAT32_ARRAY => "PackedFloat32Array": PackedFloat32Array,
+    PACKED_FLOAT64_ARRAY => "PackedFloat64Array": PackedFloat64Array,
+    PACKED_STRING_ARRAY => "PackedStringArray": PackedStringArray,
+    PACKED_VECTOR2_ARRAY => "PackedVector2Array": PackedVector2Array,
+    PACKED_VECTOR3_ARRAY => "PackedVector3Array": PackedVector3Array,
+    PACKED_COLOR_ARRAY => "PackedColorArray": PackedColorArray,
+    #[cfg(since_api = "4.3")]
+    PACKED_VECTOR4_ARRAY => "PackedVector4Array": PackedVector4Array,
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Serialization
+
+#[cfg_attr(published_docs, doc(cfg(feature = "serde")))]
+impl Serialize for Variant {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let variant_type = self.get_type();
+        let Some(index) = TAGS.iter().position(|(ty, _)| *ty == variant_type) else {
+            return Err(ser::Error::custom(format_args!(
+                "cannot serialize Variant of type {variant_type:?}: objects, callables, signals and RIDs are not supported"
+            )));
+        };
+
+        let (_, tag) = TAGS[index];
+        let index = index as u32;
+
+        match variant_type {
+            VariantType::NIL => serializer.serialize_unit_variant(NAME, index, tag),
+            VariantType::ARRAY => {
+                // SAFETY: type is checked, and the array is only read. Typed arrays are serialized like untyped ones.
+                let array = unsafe { VariantArray::from_variant_unchecked(self) };
+                serializer.serialize_newtype_variant(NAME, index, tag, &array)
+            }
+            VariantType::DICTIONARY => {
+                // SAFETY: type is checked, and the dictionary is only read. Typed dictionaries are serialized like untyped ones.
+                let dictionary = unsafe { VarDictionary::from_variant_unchecked(self) };
+                serializer.serialize_newtype_variant(
+                    NAME,
+                    index,
+                    tag,
+                    &DictionaryEntries(&dictionary),
+                )
+            }
+            _ => serialize_value(self, index, tag, serializer),
+        }
+    }
+}
+
+/// Serializes a dictionary as a sequence of `(key, value)` pairs.
+struct DictionaryEntries<'a>(&'a VarDictionary);
+
+impl Serialize for DictionaryEntries<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut sequence = serializer.serialize_seq(Some(self.0.len()))?;
+        for entry in self.0.iter_shared() {
+            sequence.serialize_element(&entry)?;
+        }
+        sequence.end()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Deserialization
+
+#[cfg_attr(published_docs, doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for Variant {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_enum(NAME, TAG_NAMES, VariantVisitor)
+    }
+}
+
+struct VariantVisitor;
+
+impl<'de> Visitor<'de> for VariantVisitor {
+    type Value = Variant;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a Variant in tagged form")
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        let (Tag(variant_type), access) = data.variant::<Tag>()?;
+
+        match variant_type {
+            VariantType::NIL => access.unit_variant().map(|()| Variant::nil()),
+            VariantType::ARRAY => access
+                .newtype_variant::<VariantArray>()
+                .map(|array| array.to_variant()),
+            VariantType::DICTIONARY => access
+                .newtype_variant::<Vec<(Variant, Variant)>>()
+                .map(|entries| entries.into_iter().collect::<VarDictionary>().to_variant()),
+            _ => deserialize_value(variant_type, access),
+        }
+    }
+}
+
+/// Identifies the variant type during deserialization, either by name or by index.
+struct Tag(VariantType);
+
+impl<'de> Deserialize<'de> for Tag {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_identifier(TagVisitor)
+    }
+}
+
+struct TagVisitor;
+
+impl Visitor<'_> for TagVisitor {
+    type Value = Tag;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a Variant type name")
+    }
+
+    fn visit_u64<E>(self, index: u64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        usize::try_from(index)
+            .ok()
+            .and_then(|index| TAGS.get(index))
+            .map(|(variant_type, _)| Tag(*variant_type))
+            .ok_or_else(|| E::invalid_value(Unexpected::Unsigned(index), &self))
+    }
+
+    fn visit_str<E>(self, tag: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        if let Some((variant_type, _)) = TAGS.iter().find(|(_, name)| *name == tag) {
+            return Ok(Tag(*variant_type));
+        }
+
+        if UNSUPPORTED_TAGS.contains(&tag) {
+            return Err(E::custom(format_args!(
+                "cannot deserialize Variant of type {tag}: objects, callables, signals and RIDs are not supported"
+            )));
+        }
+
+        Err(E::unknown_variant(tag, TAG_NAMES))
+    }
+}
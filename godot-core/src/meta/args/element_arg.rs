@@ -0,0 +1,55 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::Variant;
+use crate::meta::{ArrayElement, AsArg, ToGodot};
+
+/// Argument for keys and values of a [`Dictionary<K, V>`](crate::builtin::Dictionary).
+///
+/// Depending on the element type, different arguments are accepted:
+/// - For **typed** elements, this behaves like [`AsArg<T>`]: e.g. `i64` for `Dictionary<i64, _>`, or `&GString` and `&str` for
+///   `Dictionary<GString, _>`.
+/// - For **untyped** elements (`Variant`), any type implementing [`ToGodot`] is accepted, as well as `&Variant`. This allows writing
+///   `dict.set("key", 42)` on a [`VarDictionary`](crate::builtin::VarDictionary).
+///
+/// The second type parameter is an implementation detail and should be left at its default.
+pub trait ElementArg<T: ArrayElement, Kind = <T as ArrayElement>::ArgKind>: Sized {
+    #[doc(hidden)]
+    fn into_element_variant(self) -> Variant;
+}
+
+/// Marker for [`ArrayElement::ArgKind`] of statically typed elements.
+#[doc(hidden)]
+pub enum TypedElementArg {}
+
+/// Marker for [`ArrayElement::ArgKind`] of `Variant` elements.
+#[doc(hidden)]
+pub enum UntypedElementArg {}
+
+impl<T, A> ElementArg<T, TypedElementArg> for A
+where
+    T: ArrayElement<ArgKind = TypedElementArg>,
+    A: AsArg<T>,
+{
+    fn into_element_variant(self) -> Variant {
+        let arg = self.into_arg();
+        arg.cow_as_ref().to_variant()
+    }
+}
+
+impl<A: ToGodot> ElementArg<Variant, UntypedElementArg> for A {
+    fn into_element_variant(self) -> Variant {
+        self.to_variant()
+    }
+}
+
+// Separate impl, since `&Variant` does not implement `ToGodot`.
+impl ElementArg<Variant, UntypedElementArg> for &Variant {
+    fn into_element_variant(self) -> Variant {
+        self.clone()
+    }
+}
@@ -7,6 +7,7 @@
 
 mod as_arg;
 mod cow_arg;
+mod element_arg;
 mod object_arg;
 mod ref_arg;
 
@@ -25,6 +26,9 @@ pub use as_arg::{
 pub use cow_arg::CowArg;
 #[cfg(not(feature = "trace"))]
 pub(crate) use cow_arg::CowArg;
+pub use element_arg::ElementArg;
+#[doc(hidden)]
+pub use element_arg::{TypedElementArg, UntypedElementArg};
 #[allow(unused)] // TODO(v0.4): replace contents with newer changes
 pub use object_arg::ObjectArg;
 pub use ref_arg::RefArg;
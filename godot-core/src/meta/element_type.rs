@@ -81,6 +81,24 @@ impl ElementType {
         }
     }
 
+    /// Whether a container with this runtime element type can be used as a container of compile-time element type `target`.
+    ///
+    /// Besides exact matches, this allows script classes (runtime) to be used through their native base class (compile-time).
+    /// E.g. an `Array[Enemy]` from GDScript can be used as `Array<Gd<RefCounted>>` in Rust.
+    pub(crate) fn is_compatible_with(&self, target: &ElementType) -> bool {
+        if self == target {
+            return true;
+        }
+
+        if let (ElementType::ScriptClass(_), ElementType::Class(expected_class)) = (self, target) {
+            if let Some(actual_base_class) = self.class_name() {
+                return actual_base_class == *expected_class;
+            }
+        }
+
+        false
+    }
+
     /// Transfer cached element type from source to destination, preserving type info.
     ///
     /// Used by clone-like operations like `duplicate()`, `slice()`, etc. where we want to preserve cached type information to avoid
@@ -0,0 +1,53 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::error::Error;
+use std::fmt;
+
+/// Error when binding a user instance, because it is already bound in an incompatible way.
+///
+/// Returned by [`Gd::try_bind()`][crate::obj::Gd::try_bind] and [`Gd::try_bind_mut()`][crate::obj::Gd::try_bind_mut].
+///
+/// Such conflicts typically happen through re-entrancy, for example when a signal emitted inside a `bind_mut()` scope invokes a
+/// callback that binds the same object again. With the Cargo feature `debug-borrows`, the error message lists the source locations
+/// of all outstanding borrows, which makes it easy to find the conflicting guard.
+#[derive(Debug)]
+pub struct BorrowError {
+    type_name: &'static str,
+    is_mut: bool,
+    details: String,
+}
+
+impl BorrowError {
+    pub(crate) fn new<T>(is_mut: bool, cause: Box<dyn Error>) -> Self {
+        Self {
+            type_name: std::any::type_name::<T>(),
+            is_mut,
+            details: cause.to_string(),
+        }
+    }
+
+    /// Whether an exclusive borrow (`bind_mut`) was attempted.
+    pub fn is_mut(&self) -> bool {
+        self.is_mut
+    }
+}
+
+impl fmt::Display for BorrowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let method = if self.is_mut { "bind_mut" } else { "bind" };
+
+        write!(
+            f,
+            "Gd<T>::{method}() failed, already bound; T = {ty}.\n  Details: {details}.",
+            ty = self.type_name,
+            details = self.details,
+        )
+    }
+}
+
+impl Error for BorrowError {}
@@ -185,6 +185,14 @@ pub(crate) enum FromGodotError {
         actual: ElementType,
     },
 
+    /// Destination `Dictionary<K, V>` has different key or value type than source's runtime type.
+    BadDictionaryType {
+        expected_key: ElementType,
+        expected_value: ElementType,
+        actual_key: ElementType,
+        actual_value: ElementType,
+    },
+
     /// Special case of `BadArrayType` where a custom int type such as `i8` cannot hold a dynamic `i64` value.
     #[cfg(debug_assertions)]
     BadArrayTypeInt {
@@ -195,6 +203,21 @@ pub(crate) enum FromGodotError {
     /// InvalidEnum is also used by bitfields.
     InvalidEnum,
 
+    /// Dictionary-backed type (e.g. `#[godot(dictionary)]`) is missing a required key.
+    MissingDictionaryKey { key: &'static str },
+
+    /// Value stored under a dictionary key cannot be converted to the expected type.
+    BadDictionaryValue { key: &'static str, cause: String },
+
+    /// Array-backed type (e.g. `#[godot(array)]`) is too short to hold a required element.
+    MissingArrayElement { index: usize },
+
+    /// Array element cannot be converted to the expected type.
+    BadArrayElement { index: usize, cause: String },
+
+    /// Tag of a `#[derive(GodotConvert)]` enum does not match any of its variants.
+    UnknownEnumTag { enum_name: &'static str },
+
     /// Cannot map object to `dyn Trait` because none of the known concrete classes implements it.
     UnimplementedDynTrait {
         trait_name: String,
@@ -231,6 +254,17 @@ impl fmt::Display for FromGodotError {
 
                 write!(f, "expected array of type {exp_class}, got {act_class}")
             }
+            Self::BadDictionaryType {
+                expected_key,
+                expected_value,
+                actual_key,
+                actual_value,
+            } => {
+                write!(
+                    f,
+                    "expected dictionary of type {expected_key:?} => {expected_value:?}, got {actual_key:?} => {actual_value:?}"
+                )
+            }
             #[cfg(debug_assertions)]
             Self::BadArrayTypeInt {
                 expected_int_type,
@@ -242,6 +276,21 @@ impl fmt::Display for FromGodotError {
                 )
             }
             Self::InvalidEnum => write!(f, "invalid engine enum value"),
+            Self::MissingDictionaryKey { key } => {
+                write!(f, "dictionary is missing required key \"{key}\"")
+            }
+            Self::BadDictionaryValue { key, cause } => {
+                write!(f, "invalid value for dictionary key \"{key}\" ({cause})")
+            }
+            Self::MissingArrayElement { index } => {
+                write!(f, "array is missing required element at index {index}")
+            }
+            Self::BadArrayElement { index, cause } => {
+                write!(f, "invalid array element at index {index} ({cause})")
+            }
+            Self::UnknownEnumTag { enum_name } => {
+                write!(f, "tag does not match any variant of enum `{enum_name}`")
+            }
             Self::ZeroInstanceId => write!(f, "`InstanceId` cannot be 0"),
             Self::UnimplementedDynTrait {
                 trait_name,
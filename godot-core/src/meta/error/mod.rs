@@ -7,11 +7,13 @@
 
 //! Errors in the gdext library.
 
+mod borrow_error;
 mod call_error;
 mod convert_error;
 mod io_error;
 mod string_error;
 
+pub use borrow_error::*;
 pub use call_error::*;
 pub use convert_error::*;
 pub use io_error::*;
@@ -207,6 +207,8 @@ macro_rules! impl_godot_scalar {
 
         // For integer types, we can validate the conversion.
         impl ArrayElement for $T {
+            type ArgKind = meta::TypedElementArg;
+
             fn debug_validate_elements(array: &Array<Self>) -> Result<(), ConvertError> {
                 array.debug_validate_int_elements()
             }
@@ -236,7 +238,9 @@ macro_rules! impl_godot_scalar {
         }
 
         // For f32, conversion from f64 is lossy but will always succeed. Thus no debug validation needed.
-        impl ArrayElement for $T {}
+        impl ArrayElement for $T {
+            type ArgKind = meta::TypedElementArg;
+        }
 
         impl_godot_scalar!(@shared_traits; $T);
     };
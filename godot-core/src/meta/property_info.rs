@@ -125,6 +125,19 @@ impl PropertyInfo {
         }
     }
 
+    /// Create a new `PropertyInfo` representing a category in Godot.
+    ///
+    /// Categories are shown as headers in the Inspector, similar to the ones of each class in the inheritance chain.
+    pub fn new_category(category_name: &str) -> Self {
+        Self {
+            variant_type: VariantType::NIL,
+            class_name: ClassName::none(),
+            property_name: category_name.into(),
+            hint_info: PropertyHintInfo::none(),
+            usage: PropertyUsageFlags::CATEGORY,
+        }
+    }
+
     /// Create a new `PropertyInfo` representing a subgroup in Godot.
     ///
     /// See [`EditorInspector`](https://docs.godotengine.org/en/latest/classes/class_editorinspector.html#class-editorinspector) in Godot for
@@ -316,6 +329,33 @@ impl PropertyHintInfo {
         }
     }
 
+    /// Use for `#[var]` properties -- [`PROPERTY_HINT_DICTIONARY_TYPE`](PropertyHint::DICTIONARY_TYPE) with `"Key;Value"` type names as hint string.
+    #[cfg(since_api = "4.4")]
+    pub fn var_dictionary_element<K: ArrayElement, V: ArrayElement>() -> Self {
+        Self {
+            hint: PropertyHint::DICTIONARY_TYPE,
+            hint_string: GString::from(format!(
+                "{};{}",
+                element_godot_type_name::<K>(),
+                element_godot_type_name::<V>()
+            )),
+        }
+    }
+
+    /// Use for `#[export]` properties -- [`PROPERTY_HINT_TYPE_STRING`](PropertyHint::TYPE_STRING) with the **key and value** type strings,
+    /// separated by `;`, as hint string.
+    #[cfg(since_api = "4.4")]
+    pub fn export_dictionary_element<K: ArrayElement, V: ArrayElement>() -> Self {
+        Self {
+            hint: PropertyHint::TYPE_STRING,
+            hint_string: GString::from(format!(
+                "{};{}",
+                K::element_type_string(),
+                V::element_type_string()
+            )),
+        }
+    }
+
     /// Use for `#[export]` properties -- [`PROPERTY_HINT_TYPE_STRING`](PropertyHint::TYPE_STRING) with the **element** type string as hint string.
     pub fn export_packed_array_element<T: PackedArrayElement>() -> Self {
         Self {
@@ -195,6 +195,12 @@ pub trait ArrayElement: ToGodot + FromGodot + sealed::Sealed + 'static {
     // Note: several indirections in `ArrayElement` and the global `element_*` functions go through `GodotConvert::Via`,
     // to not require Self: `GodotType`. What matters is how array elements map to Godot on the FFI level (`GodotType` trait).
 
+    /// Selects which arguments [`ElementArg`](crate::meta::ElementArg) accepts for this element type.
+    ///
+    /// `UntypedElementArg` for `Variant`, `TypedElementArg` for all other types.
+    #[doc(hidden)]
+    type ArgKind;
+
     /// Returns the representation of this type as a type string, e.g. `"4:"` for string, or `"24:34/MyClass"` for objects.
     ///
     /// (`4` and `24` are variant type ords; `34` is `PropertyHint::NODE_TYPE` ord).
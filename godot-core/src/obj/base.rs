@@ -84,6 +84,11 @@ pub struct Base<T: GodotClass> {
     /// Rc allows to "copy-construct" the base from an existing one, while still affecting the user-instance through the original `Base<T>`.
     #[cfg(debug_assertions)]
     init_state: Rc<Cell<InitState>>,
+
+    /// Whether the base object may only be accessed on the main thread, although the user instance can be shared with other threads.
+    ///
+    /// Set for `#[class(threadsafe)]` classes, see [`ThreadSafeClass`][crate::obj::ThreadSafeClass].
+    is_thread_confined: bool,
 }
 
 impl<T: GodotClass> Base<T> {
@@ -103,6 +108,7 @@ impl<T: GodotClass> Base<T> {
             obj: ManuallyDrop::new(obj),
             #[cfg(debug_assertions)]
             init_state: Rc::clone(&base.init_state),
+            is_thread_confined: base.is_thread_confined,
         }
     }
 
@@ -146,6 +152,7 @@ impl<T: GodotClass> Base<T> {
         Self {
             obj: ManuallyDrop::new(obj),
             init_state: Rc::new(Cell::new(init_state)),
+            is_thread_confined: false,
         }
     }
 
@@ -153,6 +160,26 @@ impl<T: GodotClass> Base<T> {
     fn from_obj(obj: Gd<T>) -> Self {
         Self {
             obj: ManuallyDrop::new(obj),
+            is_thread_confined: false,
+        }
+    }
+
+    /// Restricts access to the base object to the main thread, for instances of `#[class(threadsafe)]` classes.
+    ///
+    /// Must be called before other `Base<T>` copies are made from this one.
+    pub(crate) fn confine_to_main_thread(&mut self) {
+        self.is_thread_confined = true;
+    }
+
+    /// Panics if the base object is accessed outside the main thread, while it is confined to it.
+    ///
+    /// The user instance of a thread-safe class can be reached from other threads, but engine APIs must not.
+    fn ensure_thread_access(&self) {
+        if self.is_thread_confined && !sys::is_main_thread() {
+            panic!(
+                "Base<{}>: base object of #[class(threadsafe)] class can only be accessed on the main thread",
+                T::class_name()
+            );
         }
     }
 
@@ -163,6 +190,7 @@ impl<T: GodotClass> Base<T> {
     #[doc(hidden)]
     #[deprecated = "Private API. Use `Base::to_init_gd()` or `WithBaseField::to_gd()` instead."] // TODO(v0.4): remove.
     pub fn to_gd(&self) -> Gd<T> {
+        self.ensure_thread_access();
         (*self.obj).clone()
     }
 
@@ -186,6 +214,8 @@ impl<T: GodotClass> Base<T> {
     /// # Panics (Debug)
     /// If called outside an initialization function, or for ref-counted objects on a non-main thread.
     pub fn to_init_gd(&self) -> Gd<T> {
+        self.ensure_thread_access();
+
         #[cfg(debug_assertions)] // debug_assert! still checks existence of symbols.
         assert!(
             self.is_initializing(),
@@ -276,6 +306,8 @@ impl<T: GodotClass> Base<T> {
     /// Returns a [`Gd`] referencing the base object, assuming the derived object is fully constructed.
     #[doc(hidden)]
     pub fn __fully_constructed_gd(&self) -> Gd<T> {
+        self.ensure_thread_access();
+
         #[cfg(debug_assertions)] // debug_assert! still checks existence of symbols.
         assert!(
             !self.is_initializing(),
@@ -288,6 +320,8 @@ impl<T: GodotClass> Base<T> {
     /// Returns a [`Gd`] referencing the base object, for use in script contexts only.
     #[doc(hidden)]
     pub fn __script_gd(&self) -> Gd<T> {
+        self.ensure_thread_access();
+
         // Used internally by `SiMut::base()` and `SiMut::base_mut()` for script re-entrancy.
         // Could maybe add debug validation to ensure script context in the future.
         (*self.obj).clone()
@@ -296,6 +330,7 @@ impl<T: GodotClass> Base<T> {
     // Currently only used in outbound virtual calls (for scripts); search for: base_field(self).obj_sys().
     #[doc(hidden)]
     pub fn obj_sys(&self) -> sys::GDExtensionObjectPtr {
+        self.ensure_thread_access();
         self.obj.obj_sys()
     }
 
@@ -327,6 +362,8 @@ impl<T: GodotClass> Base<T> {
     /// Returns a [`Gd`] referencing the base object, assuming the derived object is fully constructed.
     #[doc(hidden)]
     pub fn __constructed_gd(&self) -> Gd<T> {
+        self.ensure_thread_access();
+
         #[cfg(debug_assertions)] // debug_assert! still checks existence of symbols.
         assert!(
             !self.is_initializing(),
@@ -344,6 +381,8 @@ impl<T: GodotClass> Base<T> {
     /// # Safety
     /// Caller must ensure that the underlying object remains valid for the entire lifetime of the returned `PassiveGd`.
     pub(crate) unsafe fn constructed_passive(&self) -> PassiveGd<T> {
+        self.ensure_thread_access();
+
         #[cfg(debug_assertions)] // debug_assert! still checks existence of symbols.
         assert!(
             !self.is_initializing(),
@@ -357,12 +396,19 @@ impl<T: GodotClass> Base<T> {
 
 impl<T: GodotClass> Debug for Base<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        // Debug output is also used for logging (e.g. reference counting), so don't panic, but avoid calling into the engine.
+        if self.is_thread_confined && !sys::is_main_thread() {
+            let id = self.obj.instance_id_unchecked();
+            return write!(f, "Base {{ id: {id}, class: {} }}", T::class_name());
+        }
+
         classes::debug_string(&self.obj, f, "Base")
     }
 }
 
 impl<T: GodotClass> Display for Base<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        self.ensure_thread_access();
         classes::display_string(&self.obj, f)
     }
 }
@@ -6,6 +6,8 @@
  */
 
 use std::ops::DerefMut;
+#[cfg(feature = "experimental-threads")]
+use std::sync::Mutex;
 
 use godot_ffi::is_main_thread;
 
@@ -66,3 +68,28 @@ where
         callable.call_deferred(&[]);
     }
 }
+
+/// Defers a `Send` closure to run on the main thread during idle time.
+///
+/// Unlike [`WithDeferredCall::apply_deferred()`], this may be called from any thread. Godot's message queue is thread-safe, and the
+/// closure is executed once the main thread flushes it.
+#[cfg(feature = "experimental-threads")]
+pub(crate) fn call_deferred_sync<F>(rust_function: F)
+where
+    F: FnOnce() + Send + 'static,
+{
+    // from_sync_fn() needs FnMut + Sync; the Mutex provides both for a FnOnce + Send closure.
+    let rust_function = Mutex::new(Some(rust_function));
+
+    let callable = Callable::from_sync_fn("run_on_main_thread", move |_| {
+        let rust_function = rust_function
+            .lock()
+            .unwrap()
+            .take()
+            .expect("deferred callable invoked more than once");
+
+        rust_function();
+        Ok(Variant::nil())
+    });
+    callable.call_deferred(&[]);
+}
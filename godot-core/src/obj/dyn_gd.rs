@@ -600,6 +600,8 @@ where
     T: GodotClass,
     D: ?Sized + 'static,
 {
+    type ArgKind = meta::TypedElementArg;
+
     fn element_type_string() -> String {
         let hint_string = get_dyn_property_hint_string::<T, D>();
         object_export_element_type_string::<T>(hint_string)
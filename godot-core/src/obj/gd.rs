@@ -12,7 +12,7 @@ use godot_ffi as sys;
 use sys::{static_assert_eq_size_align, SysPtr as _};
 
 use crate::builtin::{Callable, GString, NodePath, StringName, Variant};
-use crate::meta::error::{ConvertError, FromFfiError};
+use crate::meta::error::{BorrowError, ConvertError, FromFfiError};
 use crate::meta::{
     ArrayElement, AsArg, CallContext, ClassName, CowArg, FromGodot, GodotConvert, GodotType,
     PropertyHintInfo, RefArg, ToGodot,
@@ -73,6 +73,7 @@ use crate::{classes, meta, out};
 /// * [`Gd::from_object(rust_obj)`][Gd::from_object] for existing Rust objects without a `Base<T>` field that are moved _into_ the smart pointer.
 /// * [`Gd::from_instance_id(id)`][Gd::from_instance_id] and [`Gd::try_from_instance_id(id)`][Gd::try_from_instance_id]
 ///   to obtain a pointer to an object which is already alive in the engine.
+/// * [`WeakGd::upgrade()`][crate::obj::WeakGd::upgrade] for weak handles created with [`Gd::downgrade()`], which do not keep the object alive.
 ///
 /// # Bind guards
 ///
@@ -182,7 +183,11 @@ where
     /// * If another `Gd` smart pointer pointing to the same Rust instance has a live `GdMut` guard bound.
     /// * If there is an ongoing function call from GDScript to Rust, which currently holds a `&mut T`
     ///   reference to the user instance. This can happen through re-entrancy (Rust -> GDScript -> Rust call).
+    ///
+    /// To find the conflicting guard, enable the Cargo feature `debug-borrows`. Use [`try_bind()`][Self::try_bind] to handle
+    /// the conflict instead of panicking.
     // Note: possible names: write/read, hold/hold_mut, r/w, r/rw, ...
+    #[track_caller]
     pub fn bind(&self) -> GdRef<'_, T> {
         self.raw.bind()
     }
@@ -200,9 +205,41 @@ where
     /// * If another `Gd` smart pointer pointing to the same Rust instance has a live `GdRef` or `GdMut` guard bound.
     /// * If there is an ongoing function call from GDScript to Rust, which currently holds a `&T` or `&mut T`
     ///   reference to the user instance. This can happen through re-entrancy (Rust -> GDScript -> Rust call).
+    ///
+    /// To find the conflicting guard, enable the Cargo feature `debug-borrows`. Use [`try_bind_mut()`][Self::try_bind_mut] to
+    /// handle the conflict instead of panicking.
+    #[track_caller]
     pub fn bind_mut(&mut self) -> GdMut<'_, T> {
         self.raw.bind_mut()
     }
+
+    /// Fallible version of [`bind()`][Self::bind].
+    ///
+    /// Returns a [`BorrowError`] instead of panicking if the instance is currently bound exclusively, e.g. by a `GdMut` guard or
+    /// by an ongoing `&mut self` method call further up the call stack.
+    ///
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// # #[derive(GodotClass)] #[class(init, base=Node)] struct Player { health: i32 }
+    /// fn report_health(player: &Gd<Player>) {
+    ///     match player.try_bind() {
+    ///         Ok(p) => godot_print!("Health: {}", p.health),
+    ///         Err(e) => godot_warn!("Player busy: {e}"),
+    ///     }
+    /// }
+    /// ```
+    #[track_caller]
+    pub fn try_bind(&self) -> Result<GdRef<'_, T>, BorrowError> {
+        self.raw.try_bind()
+    }
+
+    /// Fallible version of [`bind_mut()`][Self::bind_mut].
+    ///
+    /// Returns a [`BorrowError`] instead of panicking if the instance is currently bound, be it shared or exclusively.
+    #[track_caller]
+    pub fn try_bind_mut(&mut self) -> Result<GdMut<'_, T>, BorrowError> {
+        self.raw.try_bind_mut()
+    }
 }
 
 /// _The methods in this impl block are available for any `T`._ <br><br>
@@ -526,10 +563,20 @@ impl<T: GodotClass> Gd<T> {
     /// Create default instance for all types that have `GodotDefault`.
     ///
     /// Deliberately more loose than `Gd::default()`, does not require ref-counted memory strategy for user types.
+    ///
+    /// Fails to compile for `#[class(abstract)]` classes; those are only instantiated by Godot, as part of a subclass. This is a
+    /// post-monomorphization error, guarded by a `compile_fail` doctest in the `#[derive(GodotClass)]` docs.
     pub(crate) fn default_instance() -> Self
     where
         T: cap::GodotDefault,
     {
+        const {
+            assert!(
+                !T::__IS_ABSTRACT,
+                "classes declared with #[class(abstract)] cannot be instantiated; use a (GDScript) subclass instead"
+            );
+        }
+
         unsafe {
             // Default value (and compat one) for `p_notify_postinitialize` is true in Godot.
             #[cfg(since_api = "4.4")]
@@ -687,6 +734,8 @@ where
     /// - When the referred-to object has already been destroyed.
     /// - When this is invoked on an upcast `Gd<Object>` that dynamically points to a reference-counted type (i.e. operation not supported).
     /// - When the object is bound by an ongoing `bind()` or `bind_mut()` call (through a separate `Gd` pointer).
+    ///
+    /// If the object belongs to a [`NodePool`][crate::tools::NodePool], it is released back into the pool instead of being destroyed.
     pub fn free(self) {
         // Note: this method is NOT invoked when the free() call happens dynamically (e.g. through GDScript or reflection).
         // As such, do not use it for operations and validations to perform upon destruction.
@@ -742,6 +791,11 @@ where
             );
         }
 
+        // Nodes owned by a NodePool are returned to it instead of being destroyed. Not during unwind, as the reset hook runs user code.
+        if !is_panic_unwind && crate::tools::release_if_pooled(self.instance_id()) {
+            return;
+        }
+
         // SAFETY: object alive as checked.
         // This destroys the Storage instance, no need to run destructor again.
         unsafe {
@@ -967,6 +1021,8 @@ impl<T: GodotClass> GodotType for Gd<T> {
 }
 
 impl<T: GodotClass> ArrayElement for Gd<T> {
+    type ArgKind = meta::TypedElementArg;
+
     fn element_type_string() -> String {
         // See also impl Export for Gd<T>.
         object_export_element_type_string::<T>(T::class_name())
@@ -974,6 +1030,8 @@ impl<T: GodotClass> ArrayElement for Gd<T> {
 }
 
 impl<T: GodotClass> ArrayElement for Option<Gd<T>> {
+    type ArgKind = meta::TypedElementArg;
+
     fn element_type_string() -> String {
         Gd::<T>::element_type_string()
     }
@@ -22,11 +22,16 @@ mod on_editor;
 mod on_ready;
 mod passive_gd;
 mod raw_gd;
+#[cfg(feature = "experimental-threads")]
+mod thread_safe_gd;
 mod traits;
+mod weak_gd;
 
 pub(crate) mod rtti;
 
 pub use base::*;
+#[cfg(feature = "experimental-threads")]
+pub(crate) use call_deferred::call_deferred_sync;
 pub use call_deferred::WithDeferredCall;
 pub use dyn_gd::DynGd;
 pub use gd::*;
@@ -36,7 +41,10 @@ pub use on_editor::*;
 pub use on_ready::*;
 pub(crate) use passive_gd::PassiveGd;
 pub use raw_gd::*;
+#[cfg(feature = "experimental-threads")]
+pub use thread_safe_gd::ThreadSafeGd;
 pub use traits::*;
+pub use weak_gd::WeakGd;
 
 pub mod bounds;
 pub mod script;
@@ -5,6 +5,7 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use crate::builtin::VariantType;
 use crate::meta::{FromGodot, GodotConvert, GodotType, PropertyHintInfo};
 use crate::registry::property::{BuiltinExport, Export, Var};
 
@@ -380,6 +381,11 @@ where
     fn export_hint() -> PropertyHintInfo {
         T::export_hint()
     }
+
+    #[doc(hidden)]
+    fn element_variant_type() -> Option<VariantType> {
+        T::element_variant_type()
+    }
 }
 
 impl<T> BuiltinExport for OnEditor<T> {}
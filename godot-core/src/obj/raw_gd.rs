@@ -11,7 +11,7 @@ use godot_ffi as sys;
 use sys::{interface_fn, ExtVariantType, GodotFfi, GodotNullableFfi, PtrcallType};
 
 use crate::builtin::{Variant, VariantType};
-use crate::meta::error::{ConvertError, FromVariantError};
+use crate::meta::error::{BorrowError, ConvertError, FromVariantError};
 use crate::meta::{
     CallContext, ClassName, FromGodot, GodotConvert, GodotFfiVariant, GodotType, RefArg, ToGodot,
 };
@@ -424,6 +424,7 @@ where
     ///
     /// See [`crate::obj::Gd::bind()`] for a more in depth explanation.
     // Note: possible names: write/read, hold/hold_mut, r/w, r/rw, ...
+    #[track_caller]
     pub(crate) fn bind(&self) -> GdRef<'_, T> {
         self.check_rtti("bind");
         GdRef::from_guard(self.storage().unwrap().get())
@@ -432,11 +433,26 @@ where
     /// Hands out a guard for an exclusive borrow, through which the user instance can be read and written.
     ///
     /// See [`crate::obj::Gd::bind_mut()`] for a more in depth explanation.
+    #[track_caller]
     pub(crate) fn bind_mut(&mut self) -> GdMut<'_, T> {
         self.check_rtti("bind_mut");
         GdMut::from_guard(self.storage().unwrap().get_mut())
     }
 
+    /// Like [`bind()`](Self::bind), but returns an error if the instance is already bound exclusively.
+    #[track_caller]
+    pub(crate) fn try_bind(&self) -> Result<GdRef<'_, T>, BorrowError> {
+        self.check_rtti("try_bind");
+        self.storage().unwrap().try_get().map(GdRef::from_guard)
+    }
+
+    /// Like [`bind_mut()`](Self::bind_mut), but returns an error if the instance is already bound.
+    #[track_caller]
+    pub(crate) fn try_bind_mut(&mut self) -> Result<GdMut<'_, T>, BorrowError> {
+        self.check_rtti("try_bind_mut");
+        self.storage().unwrap().try_get_mut().map(GdMut::from_guard)
+    }
+
     /// Storage object associated with the extension instance.
     ///
     /// Returns `None` if self is null.
@@ -0,0 +1,193 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+
+use godot_ffi as sys;
+
+use crate::meta::error::BorrowError;
+use crate::obj::{Gd, GdMut, GdRef, InstanceId, ThreadSafeClass};
+use crate::storage::Storage;
+
+/// Smart pointer to a thread-safe user object, which can be sent to and shared between threads.
+///
+/// `Gd<T>` is neither `Send` nor `Sync`, because most engine APIs must be called on the main thread. For classes declared with
+/// `#[class(threadsafe)]`, a `Gd<T>` can be converted into a `ThreadSafeGd<T>`, which is both `Send` and `Sync`. It only gives access
+/// to the Rust instance via [`bind()`][Self::bind] and [`bind_mut()`][Self::bind_mut]. These go through the blocking storage of the
+/// `experimental-threads` feature: if another thread currently holds a conflicting guard, the calling thread waits until it is released.
+///
+/// To call engine methods, convert back to `Gd<T>` via [`to_gd()`][Self::to_gd] or [`into_gd()`][Self::into_gd], which is only possible on
+/// the main thread.
+///
+/// Only ref-counted classes can be thread-safe. The object stays alive as long as any `ThreadSafeGd<T>` or `Gd<T>` points to it, so it
+/// cannot be freed while other threads use it.
+///
+/// # Threading
+/// The base object is confined to the main thread. Inside a `bind()` or `bind_mut()` guard on another thread, accessing the `Base<T>`
+/// field panics: `self.base()`, `self.base_mut()` and `self.to_gd()`, but also direct use of the field, e.g. `Display` or
+/// `Base::to_gd()`. Only `Debug` works, limited to the instance ID and class name.
+///
+/// Cloning and dropping a `ThreadSafeGd<T>` changes the reference count, which the engine does atomically; both are allowed on any thread.
+/// However, if the last `ThreadSafeGd<T>` or `Gd<T>` is dropped on a worker thread, the object is destroyed on that thread, and the
+/// `Drop` impl of `T` runs there as well. Keep a handle on the main thread, if destruction must happen there.
+///
+/// # Example
+/// ```no_run
+/// # use godot::prelude::*;
+/// # use godot::obj::ThreadSafeGd;
+/// #[derive(GodotClass)]
+/// #[class(init, threadsafe, base=RefCounted)]
+/// struct Counter {
+///     count: u64,
+/// }
+///
+/// let counter = ThreadSafeGd::new(Counter::new_gd());
+///
+/// std::thread::scope(|s| {
+///     for _ in 0..4 {
+///         s.spawn(|| counter.bind_mut().count += 1);
+///     }
+/// });
+///
+/// assert_eq!(counter.bind().count, 4);
+/// ```
+pub struct ThreadSafeGd<T: ThreadSafeClass> {
+    gd: Gd<T>,
+
+    // Cached, so it can be queried without accessing the object from another thread.
+    instance_id: InstanceId,
+}
+
+// SAFETY: T's fields (apart from the base) are Send + Sync, as verified by #[class(threadsafe)]. The user instance is only accessed through
+// the blocking storage, which synchronizes across threads. The Gd<T> itself is only handed out on the main thread, and the Base<T> field
+// panics on access from other threads (see GodotClass::__IS_THREADSAFE). T is ref-counted, so the object cannot be freed while this
+// pointer exists; reference counting is atomic.
+unsafe impl<T: ThreadSafeClass> Send for ThreadSafeGd<T> {}
+unsafe impl<T: ThreadSafeClass> Sync for ThreadSafeGd<T> {}
+
+impl<T: ThreadSafeClass> ThreadSafeGd<T> {
+    /// Wraps a `Gd<T>` so it can be sent to other threads.
+    ///
+    /// # Panics
+    /// If `gd` points to a destroyed object.
+    pub fn new(gd: Gd<T>) -> Self {
+        let instance_id = gd.instance_id();
+        Self { gd, instance_id }
+    }
+
+    /// Hands out a guard for a shared borrow, through which the user instance can be read.
+    ///
+    /// Blocks while another thread holds a [`bind_mut()`][Self::bind_mut] guard.
+    ///
+    /// # Panics
+    /// If the object has been destroyed, or if the current thread already holds a `bind_mut()` guard (which would never be released).
+    #[track_caller]
+    pub fn bind(&self) -> GdRef<'_, T> {
+        self.gd.raw.bind()
+    }
+
+    /// Hands out a guard for an exclusive borrow, through which the user instance can be read and written.
+    ///
+    /// Unlike [`Gd::bind_mut()`], this takes `&self`, as the instance may be shared between threads. Blocks while other threads hold
+    /// any guards.
+    ///
+    /// # Panics
+    /// If the object has been destroyed, or if the current thread already holds a guard (which would never be released).
+    #[track_caller]
+    pub fn bind_mut(&self) -> GdMut<'_, T> {
+        let raw = &self.gd.raw;
+        raw.check_rtti("bind_mut");
+
+        GdMut::from_guard(raw.storage().unwrap().get_mut())
+    }
+
+    /// Fallible version of [`bind()`][Self::bind], returning an error instead of panicking on conflicts within the current thread.
+    ///
+    /// Still blocks while other threads hold conflicting guards.
+    #[track_caller]
+    pub fn try_bind(&self) -> Result<GdRef<'_, T>, BorrowError> {
+        self.gd.raw.try_bind()
+    }
+
+    /// Fallible version of [`bind_mut()`][Self::bind_mut], returning an error instead of panicking on conflicts within the current thread.
+    #[track_caller]
+    pub fn try_bind_mut(&self) -> Result<GdMut<'_, T>, BorrowError> {
+        let raw = &self.gd.raw;
+        raw.check_rtti("try_bind_mut");
+
+        raw.storage().unwrap().try_get_mut().map(GdMut::from_guard)
+    }
+
+    /// The instance ID of the object.
+    ///
+    /// Does not check whether the object is still alive.
+    pub fn instance_id(&self) -> InstanceId {
+        self.instance_id
+    }
+
+    /// Returns a regular `Gd<T>` pointing to the same object, to call engine APIs.
+    ///
+    /// # Panics
+    /// If not called on the main thread.
+    pub fn to_gd(&self) -> Gd<T> {
+        Self::ensure_main_thread("to_gd");
+        self.gd.clone()
+    }
+
+    /// Converts back into a regular `Gd<T>`, to call engine APIs.
+    ///
+    /// # Panics
+    /// If not called on the main thread.
+    pub fn into_gd(self) -> Gd<T> {
+        Self::ensure_main_thread("into_gd");
+        self.gd
+    }
+
+    fn ensure_main_thread(method: &str) {
+        assert!(
+            sys::is_main_thread(),
+            "ThreadSafeGd<{}>::{method}() must be called on the main thread; engine APIs are not thread-safe",
+            T::class_name()
+        );
+    }
+}
+
+impl<T: ThreadSafeClass> Gd<T> {
+    /// Converts this pointer into a [`ThreadSafeGd<T>`], which can be sent to other threads.
+    ///
+    /// Only available for classes declared with `#[class(threadsafe)]`.
+    pub fn into_thread_safe(self) -> ThreadSafeGd<T> {
+        ThreadSafeGd::new(self)
+    }
+}
+
+impl<T: ThreadSafeClass> From<Gd<T>> for ThreadSafeGd<T> {
+    fn from(gd: Gd<T>) -> Self {
+        Self::new(gd)
+    }
+}
+
+impl<T: ThreadSafeClass> Clone for ThreadSafeGd<T> {
+    /// Clones the pointer. For ref-counted objects, this increments the (atomic) reference count.
+    fn clone(&self) -> Self {
+        Self {
+            gd: self.gd.clone(),
+            instance_id: self.instance_id,
+        }
+    }
+}
+
+impl<T: ThreadSafeClass> Debug for ThreadSafeGd<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "ThreadSafeGd<{}>(id={})",
+            T::class_name(),
+            self.instance_id
+        )
+    }
+}
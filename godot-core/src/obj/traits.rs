@@ -45,6 +45,14 @@ where
     /// It must not be less than `Base::INIT_LEVEL`.
     const INIT_LEVEL: InitLevel = <Self::Base as GodotClass>::INIT_LEVEL;
 
+    /// Whether the class is declared with `#[class(abstract)]`, i.e. cannot be default-constructed from Rust.
+    #[doc(hidden)]
+    const __IS_ABSTRACT: bool = false;
+
+    /// Whether the class is declared with `#[class(threadsafe)]`, i.e. its base object is confined to the main thread.
+    #[doc(hidden)]
+    const __IS_THREADSAFE: bool = false;
+
     /// Returns whether `Self` inherits from `U`.
     ///
     /// This is reflexive, i.e `Self` inherits from itself.
@@ -175,6 +183,28 @@ pub trait UserClass: Bounds<Declarer = bounds::DeclUser> {
     }
 }
 
+/// Marks a user class whose instances may be accessed from multiple threads.
+///
+/// Implemented by `#[class(threadsafe)]`, which verifies that all fields except the `Base<T>` are `Send + Sync`. Instances of such classes
+/// can be wrapped in a `ThreadSafeGd<T>` (requires the `experimental-threads` feature) and sent to other threads.
+///
+/// Only ref-counted classes are supported, so that no thread can free an object which is still in use by others. The base object itself
+/// is not thread-safe: any access to the `Base<T>` field, including [`WithBaseField::base()`], [`base_mut()`][WithBaseField::base_mut]
+/// and [`to_gd()`][WithBaseField::to_gd], panics when done outside the main thread.
+///
+/// # Safety
+/// Do not implement this trait manually. All fields of the struct, except the base field, must be `Send + Sync`, and the base field
+/// must only be accessible on the main thread.
+#[diagnostic::on_unimplemented(
+    message = "Class `{Self}` is not marked as thread-safe",
+    label = "missing #[class(threadsafe)] attribute",
+    note = "only classes declared with #[class(threadsafe)] can be accessed from other threads"
+)]
+pub unsafe trait ThreadSafeClass:
+    GodotClass + Bounds<Declarer = bounds::DeclUser, Memory = bounds::MemRefCounted>
+{
+}
+
 /// Auto-implemented for all engine-provided enums.
 pub trait EngineEnum: Copy {
     fn try_from_ord(ord: i32) -> Option<Self>;
@@ -612,6 +642,9 @@ pub trait NewGd: GodotClass {
     ///
     /// # Panics
     /// If `Self` is user-defined and its default constructor `init()` panics, that panic is propagated.
+    ///
+    /// # Compile-time errors
+    /// If `Self` is declared with `#[class(abstract)]`. This is checked when the call is monomorphized, not by the trait bound.
     fn new_gd() -> Gd<Self>;
 }
 
@@ -633,6 +666,9 @@ pub trait NewAlloc: GodotClass {
     ///
     /// # Panics
     /// If `Self` is user-defined and its default constructor `init()` panics, that panic is propagated to the caller.
+    ///
+    /// # Compile-time errors
+    /// If `Self` is declared with `#[class(abstract)]`. This is checked when the call is monomorphized, not by the trait bound.
     #[must_use]
     fn new_alloc() -> Gd<Self>;
 }
@@ -808,6 +844,12 @@ pub mod cap {
     pub trait ImplementsGodotExports: GodotClass {
         #[doc(hidden)]
         fn __register_exports();
+
+        /// Value that the exported property `property` is reverted to in the editor, derived from its `#[init]` value.
+        #[doc(hidden)]
+        fn __default_property_revert(_property: &StringName) -> Option<Variant> {
+            None
+        }
     }
 
     /// Auto-implemented for `#[godot_api] impl XyVirtual for MyClass` blocks
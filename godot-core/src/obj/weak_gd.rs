@@ -0,0 +1,187 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+use std::marker::PhantomData;
+
+use crate::classes;
+use crate::classes::Node;
+use crate::meta::{ClassName, GodotConvert, PropertyHintInfo};
+use crate::obj::{bounds, Bounds, Gd, GodotClass, Inherits, InstanceId};
+use crate::registry::property::{Export, Var};
+
+/// Weak handle to a Godot object, which does not keep it alive.
+///
+/// A `WeakGd<T>` only stores the instance ID of an object, together with its static type `T`. Unlike [`Gd<T>`], it never increments the
+/// reference count of `RefCounted` objects, and it can be kept around after the object has been freed. To access the object, call
+/// [`upgrade()`][Self::upgrade], which looks up the instance ID and checks that the object is still alive and of type `T`.
+///
+/// This is the typed counterpart to storing an [`InstanceId`] and calling [`Gd::try_from_instance_id()`].
+///
+/// # Example
+/// ```no_run
+/// # use godot::prelude::*;
+/// let node = Node::new_alloc();
+/// let weak: WeakGd<Node> = node.downgrade();
+/// assert!(weak.upgrade().is_some());
+///
+/// node.free();
+/// assert!(weak.upgrade().is_none());
+/// ```
+///
+/// # Properties
+/// `WeakGd<T>` can be used as a `#[var]` field; the property appears as a nullable object in GDScript. Dead objects are read as `null`.
+///
+/// For classes inheriting `Node`, it can also be `#[export]`ed. This is useful to cache references to other nodes in the scene, which are
+/// owned by the scene tree. Resources cannot be exported, as nothing would keep the assigned resource alive.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Turret {
+///     #[export]
+///     target: WeakGd<Node3D>,
+/// }
+///
+/// impl Turret {
+///     fn aim(&self) {
+///         if let Some(target) = self.target.upgrade() {
+///             godot_print!("Aiming at {}", target.get_global_position());
+///         }
+///     }
+/// }
+/// ```
+pub struct WeakGd<T: GodotClass> {
+    instance_id: Option<InstanceId>,
+
+    // Not Send/Sync, like Gd<T>.
+    _marker: PhantomData<*const T>,
+}
+
+impl<T: GodotClass> WeakGd<T> {
+    /// Creates a weak handle to the object pointed to by `obj`.
+    ///
+    /// Equivalent to [`Gd::downgrade()`].
+    pub fn new(obj: &Gd<T>) -> Self {
+        Self {
+            instance_id: Some(obj.instance_id()),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Creates a weak handle that does not refer to any object; [`upgrade()`][Self::upgrade] always returns `None`.
+    ///
+    /// Same as `WeakGd::default()`.
+    pub fn null() -> Self {
+        Self {
+            instance_id: None,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns a strong `Gd<T>` pointer to the object, or `None` if it is no longer alive.
+    ///
+    /// Also returns `None` for null handles.
+    pub fn upgrade(&self) -> Option<Gd<T>> {
+        let instance_id = self.instance_id?;
+
+        // Godot does not reuse instance IDs, so a successful lookup is always the originally referenced object.
+        Gd::try_from_instance_id(instance_id).ok()
+    }
+
+    /// Returns `true` if the handle refers to an object that is still alive.
+    pub fn is_instance_valid(&self) -> bool {
+        self.instance_id
+            .is_some_and(|id| !classes::object_ptr_from_id(id).is_null())
+    }
+
+    /// The instance ID of the referenced object, or `None` for null handles.
+    ///
+    /// The ID is returned even if the object has been freed in the meantime.
+    pub fn instance_id(&self) -> Option<InstanceId> {
+        self.instance_id
+    }
+}
+
+impl<T: GodotClass> Gd<T> {
+    /// Creates a [`WeakGd<T>`] handle, which does not keep this object alive.
+    pub fn downgrade(&self) -> WeakGd<T> {
+        WeakGd::new(self)
+    }
+}
+
+impl<T: GodotClass> From<&Gd<T>> for WeakGd<T> {
+    fn from(obj: &Gd<T>) -> Self {
+        Self::new(obj)
+    }
+}
+
+impl<T: GodotClass> Default for WeakGd<T> {
+    fn default() -> Self {
+        Self::null()
+    }
+}
+
+// Manual impls, to avoid bounds on T.
+impl<T: GodotClass> Clone for WeakGd<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: GodotClass> Copy for WeakGd<T> {}
+
+impl<T: GodotClass> PartialEq for WeakGd<T> {
+    /// Returns whether both handles refer to the same object (or are both null).
+    fn eq(&self, other: &Self) -> bool {
+        self.instance_id == other.instance_id
+    }
+}
+
+impl<T: GodotClass> Eq for WeakGd<T> {}
+
+impl<T: GodotClass> Debug for WeakGd<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.instance_id {
+            Some(id) => write!(f, "WeakGd<{}>(id={id})", T::class_name()),
+            None => write!(f, "WeakGd<{}>(null)", T::class_name()),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Property support
+
+impl<T: GodotClass> GodotConvert for WeakGd<T> {
+    type Via = Option<Gd<T>>;
+}
+
+impl<T: GodotClass> Var for WeakGd<T> {
+    fn get_property(&self) -> Self::Via {
+        self.upgrade()
+    }
+
+    fn set_property(&mut self, value: Self::Via) {
+        *self = value.as_ref().map_or_else(Self::null, Self::new);
+    }
+}
+
+/// Only nodes can be exported; they are kept alive by the scene tree.
+impl<T> Export for WeakGd<T>
+where
+    T: GodotClass + Bounds<Exportable = bounds::Yes> + Inherits<Node>,
+{
+    fn export_hint() -> PropertyHintInfo {
+        PropertyHintInfo::export_gd::<T>()
+    }
+
+    #[doc(hidden)]
+    fn as_node_class() -> Option<ClassName> {
+        PropertyHintInfo::object_as_node_class::<T>()
+    }
+}
@@ -12,9 +12,10 @@ use std::sync::atomic;
 
 use sys::Global;
 
+use crate::builtin::{GString, VarDictionary, Variant, VariantArray};
 use crate::global::godot_error;
-use crate::meta::error::CallError;
-use crate::meta::CallContext;
+use crate::meta::error::{CallError, ConvertError, FromGodotError};
+use crate::meta::{CallContext, FromGodot};
 use crate::obj::Gd;
 use crate::{classes, sys};
 
@@ -196,6 +197,18 @@ pub unsafe fn has_virtual_script_method(
 /// Ensure `T` is an editor plugin.
 pub const fn is_editor_plugin<T: crate::obj::Inherits<crate::classes::EditorPlugin>>() {}
 
+/// Ensure field type `T` of a `#[class(threadsafe)]` struct can be shared across threads.
+pub const fn is_threadsafe_field<T: Send + Sync>() {}
+
+/// Ensure `#[class(threadsafe)]` is only used with blocking storage, i.e. the `experimental-threads` feature.
+#[cfg(feature = "experimental-threads")]
+pub const fn require_experimental_threads() {}
+
+#[cfg(not(feature = "experimental-threads"))]
+pub const fn require_experimental_threads() {
+    panic!("#[class(threadsafe)] requires the `experimental-threads` feature");
+}
+
 // Starting from 4.3, Godot has "runtime classes"; this emulation is no longer needed.
 #[cfg(before_api = "4.3")]
 pub fn is_class_inactive(is_tool: bool) -> bool {
@@ -504,6 +517,75 @@ pub fn rebuild_gd(object_ref: &classes::Object) -> Gd<classes::Object> {
     unsafe { Gd::from_obj_sys(ptr) }
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Derive support
+
+/// Reads a required field of a `#[godot(dictionary)]` type.
+pub fn dict_field_required<T: FromGodot>(
+    dict: &VarDictionary,
+    key: &'static str,
+) -> Result<T, ConvertError> {
+    match dict.get(key) {
+        Some(value) => convert_dict_value(key, value),
+        None => Err(FromGodotError::MissingDictionaryKey { key }.into_error(dict.clone())),
+    }
+}
+
+/// Reads a field of a `#[godot(dictionary)]` type, falling back to `default` if the key is absent.
+pub fn dict_field_or_else<T: FromGodot>(
+    dict: &VarDictionary,
+    key: &'static str,
+    default: impl FnOnce() -> T,
+) -> Result<T, ConvertError> {
+    match dict.get(key) {
+        Some(value) => convert_dict_value(key, value),
+        None => Ok(default()),
+    }
+}
+
+/// Reads a required element of a `#[godot(array)]` type.
+pub fn array_elem_required<T: FromGodot>(
+    array: &VariantArray,
+    index: usize,
+) -> Result<T, ConvertError> {
+    match array.get(index) {
+        Some(value) => convert_array_elem(index, value),
+        None => Err(FromGodotError::MissingArrayElement { index }.into_error(array.clone())),
+    }
+}
+
+/// Reads an element of a `#[godot(array)]` type, falling back to `default` if the array is too short.
+pub fn array_elem_or_else<T: FromGodot>(
+    array: &VariantArray,
+    index: usize,
+    default: impl FnOnce() -> T,
+) -> Result<T, ConvertError> {
+    match array.get(index) {
+        Some(value) => convert_array_elem(index, value),
+        None => Ok(default()),
+    }
+}
+
+/// Error for a tag that doesn't correspond to any variant of a `#[derive(GodotConvert)]` enum.
+pub fn enum_tag_error(enum_name: &'static str, tag: GString) -> ConvertError {
+    FromGodotError::UnknownEnumTag { enum_name }.into_error(tag)
+}
+
+// Value is attached to the outer errors; only keep the reason of the inner one.
+fn convert_dict_value<T: FromGodot>(key: &'static str, value: Variant) -> Result<T, ConvertError> {
+    value.try_to::<T>().map_err(|err| {
+        let cause = err.into_erased().to_string();
+        FromGodotError::BadDictionaryValue { key, cause }.into_error(value)
+    })
+}
+
+fn convert_array_elem<T: FromGodot>(index: usize, value: Variant) -> Result<T, ConvertError> {
+    value.try_to::<T>().map_err(|err| {
+        let cause = err.into_erased().to_string();
+        FromGodotError::BadArrayElement { index, cause }.into_error(value)
+    })
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
 #[cfg(test)]
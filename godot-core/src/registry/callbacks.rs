@@ -11,6 +11,8 @@
 #![allow(clippy::missing_safety_doc)]
 
 use std::any::Any;
+use std::cell::RefCell;
+use std::collections::HashMap;
 
 use godot_ffi as sys;
 use sys::conv::u32_to_usize;
@@ -19,7 +21,7 @@ use sys::interface_fn;
 use crate::builder::ClassBuilder;
 use crate::builtin::{StringName, Variant};
 use crate::classes::Object;
-use crate::meta::PropertyInfo;
+use crate::meta::{ClassName, PropertyInfo};
 use crate::obj::{bounds, cap, AsDyn, Base, Bounds, Gd, GodotClass, Inherits, UserClass};
 use crate::private::{handle_panic, IntoVirtualMethodReceiver, PanicPayload};
 use crate::registry::plugin::ErasedDynGd;
@@ -143,7 +145,10 @@ where
     let class_name = T::class_name();
     //out!("create callback: {}", class_name.backing);
 
-    let base = unsafe { Base::from_sys(base_ptr) };
+    let mut base = unsafe { Base::from_sys(base_ptr) };
+    if T::__IS_THREADSAFE {
+        base.confine_to_main_thread();
+    }
 
     // User constructor init() can panic, which crashes the engine if unhandled.
     let context = || format!("panic during {class_name}::init() constructor");
@@ -378,7 +383,7 @@ pub unsafe extern "C" fn free_property_list<T: cap::GodotGetPropertyList>(
 /// * `instance` must be a valid `T` instance pointer for the duration of this function call.
 /// * `property_name` must be a valid `StringName` pointer for the duration of this function call.
 #[deny(unsafe_op_in_unsafe_fn)]
-unsafe fn raw_property_get_revert<T: cap::GodotPropertyGetRevert>(
+unsafe fn raw_property_get_revert<T: cap::GodotPropertyGetRevert + cap::ImplementsGodotExports>(
     instance: sys::GDExtensionClassInstancePtr,
     property_name: sys::GDExtensionConstStringNamePtr,
 ) -> Option<Variant> {
@@ -389,13 +394,16 @@ unsafe fn raw_property_get_revert<T: cap::GodotPropertyGetRevert>(
     // SAFETY: `property_name` is a valid `StringName` pointer for the duration of this function call.
     let property = unsafe { StringName::borrow_string_sys(property_name) };
     T::__godot_property_get_revert(instance, property.clone())
+        .or_else(|| cached_default_property_revert::<T>(property))
 }
 
 /// # Safety
 ///
 /// - Must only be called by Godot as a callback for `property_can_revert` for a rust-defined class of type `T`.
 #[deny(unsafe_op_in_unsafe_fn)]
-pub unsafe extern "C" fn property_can_revert<T: cap::GodotPropertyGetRevert>(
+pub unsafe extern "C" fn property_can_revert<
+    T: cap::GodotPropertyGetRevert + cap::ImplementsGodotExports,
+>(
     instance: sys::GDExtensionClassInstancePtr,
     property_name: sys::GDExtensionConstStringNamePtr,
 ) -> sys::GDExtensionBool {
@@ -409,7 +417,9 @@ pub unsafe extern "C" fn property_can_revert<T: cap::GodotPropertyGetRevert>(
 ///
 /// - Must only be called by Godot as a callback for `property_get_revert` for a rust-defined class of type `T`.
 #[deny(unsafe_op_in_unsafe_fn)]
-pub unsafe extern "C" fn property_get_revert<T: cap::GodotPropertyGetRevert>(
+pub unsafe extern "C" fn property_get_revert<
+    T: cap::GodotPropertyGetRevert + cap::ImplementsGodotExports,
+>(
     instance: sys::GDExtensionClassInstancePtr,
     property_name: sys::GDExtensionConstStringNamePtr,
     ret: sys::GDExtensionVariantPtr,
@@ -427,6 +437,85 @@ pub unsafe extern "C" fn property_get_revert<T: cap::GodotPropertyGetRevert>(
     sys::conv::SYS_TRUE
 }
 
+/// # Safety
+///
+/// - Must only be called by Godot as a callback for `property_can_revert` for a rust-defined class of type `T`.
+#[deny(unsafe_op_in_unsafe_fn)]
+pub unsafe extern "C" fn default_property_can_revert<T: cap::ImplementsGodotExports>(
+    _instance: sys::GDExtensionClassInstancePtr,
+    property_name: sys::GDExtensionConstStringNamePtr,
+) -> sys::GDExtensionBool {
+    // SAFETY: Godot provides us with a valid `StringName` pointer for the duration of this call.
+    let property = unsafe { StringName::borrow_string_sys(property_name) };
+    let revert = cached_default_property_revert::<T>(property);
+
+    sys::conv::bool_to_sys(revert.is_some())
+}
+
+/// # Safety
+///
+/// - Must only be called by Godot as a callback for `property_get_revert` for a rust-defined class of type `T`.
+#[deny(unsafe_op_in_unsafe_fn)]
+pub unsafe extern "C" fn default_property_get_revert<T: cap::ImplementsGodotExports>(
+    _instance: sys::GDExtensionClassInstancePtr,
+    property_name: sys::GDExtensionConstStringNamePtr,
+    ret: sys::GDExtensionVariantPtr,
+) -> sys::GDExtensionBool {
+    // SAFETY: Godot provides us with a valid `StringName` pointer for the duration of this call.
+    let property = unsafe { StringName::borrow_string_sys(property_name) };
+    let Some(revert) = cached_default_property_revert::<T>(property) else {
+        return sys::conv::SYS_FALSE;
+    };
+
+    // SAFETY: Godot provides us with a valid `Variant` pointer.
+    unsafe {
+        revert.move_into_var_ptr(ret);
+    }
+
+    sys::conv::SYS_TRUE
+}
+
+thread_local! {
+    /// Revert values of exported properties, evaluated from their `#[init]` expressions once per class and property.
+    ///
+    /// Property reverts are only queried by the editor on the main thread. Entries are removed when their class is unregistered,
+    /// so no variant outlives the engine.
+    static DEFAULT_PROPERTY_REVERTS: RefCell<HashMap<ClassName, HashMap<StringName, Option<Variant>>>> =
+        RefCell::default();
+}
+
+fn cached_default_property_revert<T: cap::ImplementsGodotExports>(
+    property: &StringName,
+) -> Option<Variant> {
+    let class_name = T::class_name();
+    let cached = DEFAULT_PROPERTY_REVERTS.with(|cache| {
+        let cache = cache.borrow();
+        cache.get(&class_name)?.get(property).cloned()
+    });
+
+    if let Some(revert) = cached {
+        return revert;
+    }
+
+    // Evaluated outside the borrow, since #[init] expressions can run arbitrary code.
+    let revert = T::__default_property_revert(property);
+    DEFAULT_PROPERTY_REVERTS.with(|cache| {
+        cache
+            .borrow_mut()
+            .entry(class_name)
+            .or_default()
+            .insert(property.clone(), revert.clone());
+    });
+
+    revert
+}
+
+/// Drops the cached revert values of a class, see `DEFAULT_PROPERTY_REVERTS`.
+pub(crate) fn forget_default_property_reverts(class_name: ClassName) {
+    // Classes may be unregistered during thread-local destruction; the cache is gone by then.
+    let _ = DEFAULT_PROPERTY_REVERTS.try_with(|cache| cache.borrow_mut().remove(&class_name));
+}
+
 /// Callback for `validate_property`.
 ///
 /// Exposes `PropertyInfo` created out of `*mut GDExtensionPropertyInfo` ptr to user and moves edited values back to the pointer.
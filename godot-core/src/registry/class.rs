@@ -93,6 +93,8 @@ struct ClassRegistrationInfo {
     user_register_fn: Option<ErasedRegisterFn>,
     default_virtual_fn: Option<GodotGetVirtual>, // Optional (set if there is at least one OnReady field)
     user_virtual_fn: Option<GodotGetVirtual>, // Optional (set if there is a `#[godot_api] impl I*`)
+    default_property_can_revert_fn: sys::GDExtensionClassPropertyCanRevert, // Optional (set if there is a revertible #[export])
+    default_property_get_revert_fn: sys::GDExtensionClassPropertyGetRevert,
 
     /// Godot low-level class creation parameters.
     godot_params: GodotCreationInfo,
@@ -175,6 +177,8 @@ pub(crate) fn register_class<
         }),
         user_virtual_fn: None,
         default_virtual_fn: None,
+        default_property_can_revert_fn: None,
+        default_property_get_revert_fn: None,
         godot_params,
         init_level: T::INIT_LEVEL,
         is_editor_plugin: false,
@@ -295,6 +299,7 @@ pub fn unregister_classes(init_level: InitLevel) {
     for class in loaded_classes_current_level.into_iter().rev() {
         // Remove from other map.
         loaded_classes_by_name.remove(&class.name);
+        callbacks::forget_default_property_reverts(class.name);
 
         // Unregister from Godot.
         unregister_class_raw(class);
@@ -420,10 +425,13 @@ fn fill_class_info(item: PluginItem, c: &mut ClassRegistrationInfo) {
             register_properties_fn,
             free_fn,
             default_get_virtual_fn,
+            default_property_can_revert_fn,
+            default_property_get_revert_fn,
             is_tool,
             is_editor_plugin,
             is_internal,
             is_instantiable,
+            is_virtual,
             #[cfg(all(since_api = "4.3", feature = "register-docs"))]
                 docs: _,
             reference_fn,
@@ -431,6 +439,8 @@ fn fill_class_info(item: PluginItem, c: &mut ClassRegistrationInfo) {
         }) => {
             c.parent_class_name = Some(base_class_name);
             c.default_virtual_fn = default_get_virtual_fn;
+            c.default_property_can_revert_fn = default_property_can_revert_fn;
+            c.default_property_get_revert_fn = default_property_get_revert_fn;
             c.register_properties_fn = Some(register_properties_fn);
             c.is_editor_plugin = is_editor_plugin;
 
@@ -443,6 +453,10 @@ fn fill_class_info(item: PluginItem, c: &mut ClassRegistrationInfo) {
             //
             // See also: https://github.com/godotengine/godot/pull/58972
             c.godot_params.is_abstract = sys::conv::bool_to_sys(!is_instantiable);
+
+            // Classes marked #[class(abstract)] are "virtual": they keep their constructor, so that script subclasses can be instantiated,
+            // but they are greyed out in the editor's "Create New Node" dialog.
+            c.godot_params.is_virtual = sys::conv::bool_to_sys(is_virtual);
             c.godot_params.free_instance_func = Some(free_fn);
             c.godot_params.reference_func = reference_fn;
             c.godot_params.unreference_func = unreference_fn;
@@ -565,6 +579,12 @@ fn register_class_raw(mut info: ClassRegistrationInfo) {
         info.godot_params.get_virtual_func = info.user_virtual_fn.or(info.default_virtual_fn);
     }
 
+    // Same for reverting properties: a user-defined `property_get_revert` already falls back to the #[init] values of exports.
+    if info.godot_params.property_get_revert_func.is_none() {
+        info.godot_params.property_can_revert_func = info.default_property_can_revert_fn;
+        info.godot_params.property_get_revert_func = info.default_property_get_revert_fn;
+    }
+
     // The explicit () type notifies us if Godot API ever adds a return type.
     let registration_failed = unsafe {
         // Try to register class...
@@ -676,6 +696,8 @@ fn default_registration_info(class_name: ClassName) -> ClassRegistrationInfo {
         user_register_fn: None,
         default_virtual_fn: None,
         user_virtual_fn: None,
+        default_property_can_revert_fn: None,
+        default_property_get_revert_fn: None,
         godot_params: default_creation_info(),
         init_level: InitLevel::Scene,
         is_editor_plugin: false,
@@ -9,12 +9,14 @@
 
 use sys::GodotFfi;
 
-use crate::builtin::{GString, StringName};
+use crate::builtin::{GString, StringName, Variant, VariantType};
 use crate::global::PropertyUsageFlags;
-use crate::meta::{ClassName, GodotConvert, GodotType, PropertyHintInfo, PropertyInfo};
+use crate::meta::{
+    ClassName, FromGodot, GodotConvert, GodotFfiVariant, GodotType, PropertyHintInfo, PropertyInfo,
+};
 use crate::obj::GodotClass;
 use crate::registry::property::{Export, Var};
-use crate::{classes, sys};
+use crate::{classes, godot_error, godot_warn, sys};
 
 /// Same as [`register_var()`], but statically verifies the `Export` trait (again) and the fact that nodes can only be exported from nodes.
 pub fn register_export<C: GodotClass, T: Export>(
@@ -26,6 +28,13 @@ pub fn register_export<C: GodotClass, T: Export>(
 ) {
     // Note: if the user manually specifies `hint`, `hint_string` or `usage` keys, and thus is routed to `register_var()` instead,
     // they can bypass this validation.
+    validate_node_export::<C, T>();
+
+    register_var::<C, T>(property_name, getter_name, setter_name, hint_info, usage);
+}
+
+/// Panics if `T` is a node type, but the class `C` exporting it is not a node.
+fn validate_node_export<C: GodotClass, T: Export>() {
     if !C::inherits::<classes::Node>() {
         if let Some(class) = T::as_node_class() {
             panic!(
@@ -35,8 +44,6 @@ pub fn register_export<C: GodotClass, T: Export>(
             );
         }
     }
-
-    register_var::<C, T>(property_name, getter_name, setter_name, hint_info, usage);
 }
 
 pub fn register_var<C: GodotClass, T: Var>(
@@ -81,6 +88,136 @@ fn register_var_or_export_inner(
     }
 }
 
+/// Registers a property of a nested [`ExportGroup`][crate::registry::property::ExportGroup] field.
+///
+/// The getter and setter are shared by all properties of the group; they receive `index` as their first argument.
+pub fn register_export_indexed<C: GodotClass, T: Export>(
+    property_name: &str,
+    getter_name: &str,
+    setter_name: &str,
+    index: i64,
+    hint_info: PropertyHintInfo,
+    usage: PropertyUsageFlags,
+) {
+    // C is the class containing the #[export_nested] field, so the same restrictions as for direct fields apply.
+    validate_node_export::<C, T>();
+
+    let info = PropertyInfo {
+        variant_type: <<T as GodotConvert>::Via as GodotType>::Ffi::VARIANT_TYPE.variant_as_nil(),
+        class_name: <T as GodotConvert>::Via::class_name(),
+        property_name: StringName::from(property_name),
+        hint_info,
+        usage,
+    };
+
+    let class_name = C::class_name();
+    let getter_name = StringName::from(getter_name);
+    let setter_name = StringName::from(setter_name);
+
+    let property_info_sys = info.property_sys();
+
+    unsafe {
+        sys::interface_fn!(classdb_register_extension_class_property_indexed)(
+            sys::get_library(),
+            class_name.string_sys(),
+            std::ptr::addr_of!(property_info_sys),
+            setter_name.string_sys(),
+            getter_name.string_sys(),
+            index,
+        );
+    }
+}
+
+/// Reads a field of an [`ExportGroup`][crate::registry::property::ExportGroup] as variant.
+pub fn get_group_field<T: Var>(field: &T) -> Variant {
+    field.get_property().into_ffi().ffi_to_variant()
+}
+
+/// Evaluates the `#[init]` value of an exported field, as the variant that the property is reverted to in the editor.
+///
+/// Returns `None` for object properties without evaluating `init`: the revert value is computed once per class, and a single object
+/// (e.g. a resource) must not be shared by all instances.
+pub fn property_revert_value<T: Var>(init: impl FnOnce() -> T) -> Option<Variant> {
+    let variant_type = <<T as GodotConvert>::Via as GodotType>::Ffi::VARIANT_TYPE.variant_as_nil();
+    if variant_type == VariantType::OBJECT {
+        return None;
+    }
+
+    Some(init().get_property().into_ffi().ffi_to_variant())
+}
+
+/// Writes a field of an [`ExportGroup`][crate::registry::property::ExportGroup] from a variant.
+///
+/// If the variant cannot be converted to the field's type, an error is printed and the field keeps its previous value.
+pub fn set_group_field<T>(field: &mut T, property_name: &str, value: &Variant)
+where
+    T: Var,
+    T::Via: FromGodot,
+{
+    match T::Via::try_from_variant(value) {
+        Ok(via) => field.set_property(via),
+        Err(err) => godot_error!("invalid value for property `{property_name}`: {err}"),
+    }
+}
+
+/// Restricts the value passed to a generated setter to the bounds of `#[export(range = ..., clamp)]`.
+///
+/// The lower bound is not enforced with `or_less`, the upper bound not with `or_greater`. NaN is not within any range and is mapped to the
+/// lower bound, or to the upper bound if only that one is enforced.
+pub fn clamp_property_value<V: ClampToRange>(
+    value: V,
+    min: f64,
+    max: f64,
+    clamp_min: bool,
+    clamp_max: bool,
+) -> V {
+    value.clamp_to_range(clamp_min.then_some(min), clamp_max.then_some(max))
+}
+
+/// Warns that a generated setter keeps the previous value, because the `#[var(validate = ...)]` function rejected `value`.
+pub fn warn_rejected_property_value<C: GodotClass, V: GodotType>(property_name: &str, value: &V) {
+    let value = value.to_ffi().ffi_to_variant();
+    godot_warn!(
+        "{}.{property_name}: value {value} rejected by validator; keeping previous value",
+        C::class_name()
+    );
+}
+
+/// Numeric types that can be restricted by `#[export(range = ..., clamp)]`.
+#[diagnostic::on_unimplemented(
+    message = "#[export(range = ..., clamp)] requires a numeric property type",
+    label = "cannot be clamped"
+)]
+pub trait ClampToRange: Sized {
+    fn clamp_to_range(self, min: Option<f64>, max: Option<f64>) -> Self;
+}
+
+macro_rules! impl_clamp_to_range {
+    ($($ty:ty),+) => {
+        $(
+            impl ClampToRange for $ty {
+                fn clamp_to_range(self, min: Option<f64>, max: Option<f64>) -> Self {
+                    let value = self as f64;
+                    match (min, max) {
+                        (Some(min), _) if value.is_nan() || value < min => min as $ty,
+                        (_, Some(max)) if value.is_nan() || value > max => max as $ty,
+                        _ => self,
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_clamp_to_range!(i8, i16, i32, i64, u8, u16, u32, u64, f32, f64);
+
+pub fn register_category<C: GodotClass>(category_name: &str) {
+    let info = PropertyInfo::new_category(category_name);
+
+    // Categories have neither getter nor setter.
+    register_var_or_export_inner(info, C::class_name(), "", "");
+}
+
 pub fn register_group<C: GodotClass>(group_name: &str, prefix: &str) {
     let group_name = GString::from(group_name);
     let prefix = GString::from(prefix);
@@ -186,6 +186,13 @@ pub struct Struct {
     /// overriding ready.
     pub(crate) default_get_virtual_fn: Option<GodotGetVirtual>,
 
+    /// Reverts exported properties to their `#[init]` values. Used if there is no `#[godot_api] impl` block overriding
+    /// `property_get_revert`.
+    pub(crate) default_property_can_revert_fn: sys::GDExtensionClassPropertyCanRevert,
+
+    /// See [`Self::default_property_can_revert_fn`].
+    pub(crate) default_property_get_revert_fn: sys::GDExtensionClassPropertyGetRevert,
+
     /// Whether `#[class(tool)]` was used.
     pub(crate) is_tool: bool,
 
@@ -198,6 +205,9 @@ pub struct Struct {
     /// Whether the class has a default constructor.
     pub(crate) is_instantiable: bool,
 
+    /// Whether `#[class(abstract)]` was used. Godot calls such abstract base classes "virtual".
+    pub(crate) is_virtual: bool,
+
     /// Documentation extracted from the struct's RustDoc.
     #[cfg(all(since_api = "4.3", feature = "register-docs"))]
     pub(crate) docs: StructDocs,
@@ -218,10 +228,13 @@ impl Struct {
             },
             free_fn: callbacks::free::<T>,
             default_get_virtual_fn: None,
+            default_property_can_revert_fn: None,
+            default_property_get_revert_fn: None,
             is_tool: false,
             is_editor_plugin: false,
             is_internal: false,
             is_instantiable: false,
+            is_virtual: false,
             #[cfg(all(since_api = "4.3", feature = "register-docs"))]
             docs,
             // While Godot doesn't do anything with these callbacks for non-RefCounted classes, we can avoid instantiating them in Rust.
@@ -257,6 +270,20 @@ impl Struct {
         self
     }
 
+    pub fn with_default_property_revert<T: GodotClass + cap::ImplementsGodotExports>(
+        mut self,
+    ) -> Self {
+        set(
+            &mut self.default_property_can_revert_fn,
+            callbacks::default_property_can_revert::<T>,
+        );
+        set(
+            &mut self.default_property_get_revert_fn,
+            callbacks::default_property_get_revert::<T>,
+        );
+        self
+    }
+
     pub fn with_tool(mut self) -> Self {
         self.is_tool = true;
         self
@@ -276,6 +303,11 @@ impl Struct {
         self.is_instantiable = true;
         self
     }
+
+    pub fn with_virtual(mut self) -> Self {
+        self.is_virtual = true;
+        self
+    }
 }
 
 /// Stores registration functions for methods, constants, and documentation from inherent `#[godot_api]` impl blocks.
@@ -419,7 +451,7 @@ pub struct ITraitImpl {
     /// Part of user-defined `property_get_revert` function.
     ///
     /// This returns null when the return value of `property_get_revert` is `None`, and otherwise returns the value contained
-    /// within the `Some`.
+    /// within the `Some`. Falls back to the `#[init]` values of exported properties, if `property_get_revert` returns `None`.
     pub(crate) user_property_get_revert_fn: Option<
         unsafe extern "C" fn(
             p_instance: sys::GDExtensionClassInstancePtr,
@@ -503,7 +535,11 @@ impl ITraitImpl {
         self
     }
 
-    pub fn with_property_get_revert<T: GodotClass + cap::GodotPropertyGetRevert>(mut self) -> Self {
+    pub fn with_property_get_revert<
+        T: GodotClass + cap::GodotPropertyGetRevert + cap::ImplementsGodotExports,
+    >(
+        mut self,
+    ) -> Self {
         set(
             &mut self.user_property_get_revert_fn,
             callbacks::property_get_revert::<T>,
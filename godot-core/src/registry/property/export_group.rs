@@ -0,0 +1,62 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::Variant;
+use crate::obj::GodotClass;
+
+/// Plain Rust struct, whose fields are exported as a group of properties of an enclosing class.
+///
+/// This trait is derived with [`#[derive(ExportGroup)]`](../register/derive.ExportGroup.html). A field of such a type can then be exported in a
+/// [`GodotClass`] using `#[export_nested]`, which registers each of its `#[export]` fields as a property of the enclosing class. The
+/// properties are shown in the editor inside a group; their names are prefixed with the name of the field, e.g. `stats_health`.
+///
+/// Reading or writing such a property from Godot accesses the nested field directly.
+///
+/// # Example
+/// ```
+/// # use godot::prelude::*;
+/// #[derive(ExportGroup, Default)]
+/// struct Stats {
+///     #[export(range = (0.0, 100.0))]
+///     health: f32,
+///     #[export]
+///     speed: f32,
+/// }
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Enemy {
+///     // Registers the properties `stats_health` and `stats_speed`, in the group "Stats".
+///     #[export_nested(name = "Stats")]
+///     stats: Stats,
+/// }
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "`#[export_nested]` requires the field type to implement `ExportGroup`",
+    label = "type cannot be exported as a group",
+    note = "use #[derive(ExportGroup)] on a struct whose fields are #[export]ed"
+)]
+pub trait ExportGroup {
+    /// Registers the exported fields as properties of class `C`.
+    ///
+    /// Property names are prefixed with `prefix`. Each property is registered with its index, which is passed to the given getter
+    /// and setter of `C`.
+    #[doc(hidden)]
+    fn __register_group_properties<C: GodotClass>(
+        prefix: &str,
+        getter_name: &str,
+        setter_name: &str,
+    );
+
+    /// Returns the value of the exported field with the given index.
+    #[doc(hidden)]
+    fn __get_group_property(&self, index: i64) -> Variant;
+
+    /// Sets the value of the exported field with the given index.
+    #[doc(hidden)]
+    fn __set_group_property(&mut self, index: i64, value: &Variant);
+}
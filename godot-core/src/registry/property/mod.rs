@@ -17,8 +17,10 @@ use crate::global::PropertyHint;
 use crate::meta::{ClassName, FromGodot, GodotConvert, GodotType, PropertyHintInfo, ToGodot};
 use crate::obj::{EngineEnum, GodotClass};
 
+mod export_group;
 mod phantom_var;
 
+pub use export_group::ExportGroup;
 pub use phantom_var::PhantomVar;
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
@@ -85,6 +87,14 @@ pub trait Export: Var {
     fn as_node_class() -> Option<ClassName> {
         None
     }
+
+    /// If this is an array type whose elements can carry their own hints, returns the variant type of the elements; otherwise `None`.
+    ///
+    /// Overridden for typed `Array<T>` and `PackedArray<T>`, to support per-element hints like `#[export(range = (0.0, 1.0), elem)]`.
+    #[doc(hidden)]
+    fn element_variant_type() -> Option<VariantType> {
+        None
+    }
 }
 
 /// Marker trait to identify `GodotType`s that can be directly used with an `#[export]`.
@@ -233,7 +243,7 @@ pub mod export_info_functions {
 
     use crate::builtin::GString;
     use crate::global::PropertyHint;
-    use crate::meta::{GodotType, PropertyHintInfo, PropertyInfo};
+    use crate::meta::{ClassName, GodotType, PropertyHintInfo, PropertyInfo};
     use crate::obj::EngineEnum;
     use crate::registry::property::Export;
 
@@ -418,6 +428,14 @@ pub mod export_info_functions {
         }
     }
 
+    /// Equivalent to `@export_storage` in Godot.
+    ///
+    /// The property is not shown in the editor, but keeps the hint of its type. This way, element types of arrays (e.g. of resources)
+    /// are preserved when the property is serialized.
+    pub fn export_storage<T: Export>() -> PropertyHintInfo {
+        T::export_hint()
+    }
+
     /// Handles `@export_file`, `@export_global_file`, `@export_dir` and `@export_global_dir`.
     pub fn export_file_or_dir<T: Export>(
         is_file: bool,
@@ -456,10 +474,12 @@ pub mod export_info_functions {
             // Array<GString> or PackedStringArray field:
             // { "type": 28, "hint": 23, "hint_string": "4/13:*.png" }
             #[cfg(since_api = "4.3")]
-            VariantType::PACKED_STRING_ARRAY => to_string_array_hint(hint, filter),
+            VariantType::PACKED_STRING_ARRAY => {
+                to_array_elem_hint(VariantType::STRING, hint, filter)
+            }
             #[cfg(since_api = "4.3")]
             VariantType::ARRAY if field_ty.is_array_of_elem::<GString>() => {
-                to_string_array_hint(hint, filter)
+                to_array_elem_hint(VariantType::STRING, hint, filter)
             }
 
             _ => {
@@ -483,17 +503,107 @@ pub mod export_info_functions {
         }
     }
 
-    /// For `Array<GString>` and `PackedStringArray` fields using one of the `@export[_global]_{file|dir}` annotations.
+    /// For array fields using an annotation that applies to their elements, e.g. `@export_file` on `Array<GString>`.
     ///
-    /// Formats: `"4/13:"`, `"4/15:*.png"`, ...
-    fn to_string_array_hint(hint: PropertyHint, filter: &str) -> PropertyHintInfo {
-        let variant_ord = VariantType::STRING.ord(); // "4"
+    /// Formats: `"4/13:"`, `"4/15:*.png"`, `"22/35:Node2D"`, ...
+    #[cfg(since_api = "4.3")]
+    fn to_array_elem_hint(
+        elem_type: VariantType,
+        hint: PropertyHint,
+        elem_hint_string: &str,
+    ) -> PropertyHintInfo {
+        let variant_ord = elem_type.ord(); // "4"
         let hint_ord = hint.ord();
         let hint_string = format!("{variant_ord}/{hint_ord}");
 
         PropertyHintInfo {
             hint: PropertyHint::TYPE_STRING,
-            hint_string: format!("{hint_string}:{filter}").into(),
+            hint_string: format!("{hint_string}:{elem_hint_string}").into(),
+        }
+    }
+
+    /// Applies a hint to each element of an array field, instead of the field itself.
+    ///
+    /// Used by `#[export(..., elem)]`, e.g. `#[export(range = (0.0, 100.0), elem)]` on an `Array<f32>` or `PackedFloat32Array`. This
+    /// is the equivalent of annotating a typed array in GDScript, which applies hints such as `@export_range` to the elements.
+    ///
+    /// # Panics
+    /// If `T` is not a typed `Array<T>` or a `PackedArray<T>` with builtin elements.
+    #[cfg(since_api = "4.3")]
+    pub fn export_array_element<T: Export>(elem_hint: PropertyHintInfo) -> PropertyHintInfo {
+        let Some(elem_type) = T::element_variant_type() else {
+            panic!(
+                "#[export(..., elem)] only supports typed Array<T> or PackedArray<T> field types with builtin elements\n\
+                encountered: {:?}",
+                T::Via::property_info("")
+            );
+        };
+
+        to_array_elem_hint(
+            elem_type,
+            elem_hint.hint,
+            &elem_hint.hint_string.to_string(),
+        )
+    }
+
+    /// Equivalent to `@export_node_path` in Godot.
+    ///
+    /// Restricts the nodes that can be selected in the editor to the given classes (and their subclasses). An empty list allows any node.
+    pub fn export_node_path<T: Export>(class_names: &[ClassName]) -> PropertyHintInfo {
+        let field_ty = T::Via::property_info("");
+        let class_names = class_names
+            .iter()
+            .map(|class_name| class_name.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+
+        match field_ty.variant_type {
+            // NodePath field:
+            // { "type": 22, "hint": 35, "hint_string": "Button,TouchScreenButton" }
+            VariantType::NODE_PATH => PropertyHintInfo {
+                hint: PropertyHint::NODE_PATH_VALID_TYPES,
+                hint_string: class_names.into(),
+            },
+
+            // Array<NodePath> field:
+            // { "type": 28, "hint": 23, "hint_string": "22/35:Button,TouchScreenButton" }
+            #[cfg(since_api = "4.3")]
+            VariantType::ARRAY if field_ty.is_array_of_elem::<crate::builtin::NodePath>() => {
+                to_array_elem_hint(
+                    VariantType::NODE_PATH,
+                    PropertyHint::NODE_PATH_VALID_TYPES,
+                    &class_names,
+                )
+            }
+
+            _ => panic!(
+                "#[export(node_path)] only supports NodePath or Array<NodePath> field types\n\
+                encountered: {field_ty:?}"
+            ),
+        }
+    }
+
+    /// Equivalent to `@export_custom` in Godot.
+    ///
+    /// Uses the given hint and hint string verbatim. Useful for hints that have no dedicated `#[export]` key, or for custom hint strings
+    /// on typed arrays, e.g. `hint = TYPE_STRING, hint_string = "2/1:0,100"` for an `Array<i64>` with a range per element.
+    pub fn export_custom(hint: PropertyHint, hint_string: impl AsRef<str>) -> PropertyHintInfo {
+        PropertyHintInfo {
+            hint,
+            hint_string: GString::from(hint_string.as_ref()),
+        }
+    }
+
+    /// Selection of an input action from the project settings, for `StringName` or `GString` fields.
+    ///
+    /// Without flags, only user-defined actions are listed. `show_builtin` also lists the built-in `ui_*` actions, and `loose_mode`
+    /// (Godot 4.4+) allows entering action names that do not exist.
+    pub fn export_input_name(show_builtin: bool, loose_mode: bool) -> PropertyHintInfo {
+        let hint_string = comma_separate_boolean_idents!(show_builtin, loose_mode);
+
+        PropertyHintInfo {
+            hint: PropertyHint::INPUT_NAME,
+            hint_string: hint_string.into(),
         }
     }
 
@@ -504,6 +614,23 @@ pub mod export_info_functions {
         }
     }
 
+    /// Equivalent to `@export_tool_button` in Godot.
+    ///
+    /// The hint string has the format `"text"` or `"text,icon"`, where `icon` is the name of an editor theme icon.
+    #[cfg(since_api = "4.4")]
+    pub fn export_tool_button<S: AsRef<str>>(text: S, icon: Option<S>) -> PropertyHintInfo {
+        let text = text.as_ref();
+        let hint_string = match icon {
+            Some(icon) => format!("{text},{}", icon.as_ref()),
+            None => text.to_string(),
+        };
+
+        PropertyHintInfo {
+            hint: PropertyHint::TOOL_BUTTON,
+            hint_string: hint_string.into(),
+        }
+    }
+
     macro_rules! default_export_funcs {
         (
             $( $function_name:ident => $property_hint:ident, )*
@@ -523,16 +650,23 @@ pub mod export_info_functions {
     // right side are the corresponding property hint. Godot is not always consistent between the two, such
     // as `export_multiline` being `PROPERTY_HINT_MULTILINE_TEXT`.
     default_export_funcs!(
-        export_storage => NONE, // Storage exports don't display in the editor.
         export_flags_2d_physics => LAYERS_2D_PHYSICS,
         export_flags_2d_render => LAYERS_2D_RENDER,
         export_flags_2d_navigation => LAYERS_2D_NAVIGATION,
         export_flags_3d_physics => LAYERS_3D_PHYSICS,
         export_flags_3d_render => LAYERS_3D_RENDER,
         export_flags_3d_navigation => LAYERS_3D_NAVIGATION,
+        export_flags_avoidance => LAYERS_AVOIDANCE,
         export_multiline => MULTILINE_TEXT,
         export_color_no_alpha => COLOR_NO_ALPHA,
     );
+
+    // Hints without a dedicated GDScript annotation (only reachable via `@export_custom`).
+    default_export_funcs!(
+        export_password => PASSWORD,
+        export_expression => EXPRESSION,
+        export_locale_id => LOCALE_ID,
+    );
 }
 
 mod export_impls {
@@ -605,8 +739,6 @@ mod export_impls {
 
     impl_property_by_godot_convert!(Color);
 
-    // Dictionary: will need to be done manually once they become typed.
-    impl_property_by_godot_convert!(Dictionary);
     impl_property_by_godot_convert!(Variant);
 
     // Primitives
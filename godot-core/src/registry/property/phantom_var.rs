@@ -10,6 +10,7 @@ use std::fmt;
 use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
 
+use crate::builtin::VariantType;
 use crate::meta::{ClassName, GodotConvert, GodotType, PropertyHintInfo};
 use crate::registry::property::{Export, Var};
 
@@ -86,6 +87,10 @@ impl<T: GodotType + Var + Export> Export for PhantomVar<T> {
     fn as_node_class() -> Option<ClassName> {
         <T as Export>::as_node_class()
     }
+
+    fn element_variant_type() -> Option<VariantType> {
+        <T as Export>::element_variant_type()
+    }
 }
 
 impl<T: GodotType + Var> Default for PhantomVar<T> {
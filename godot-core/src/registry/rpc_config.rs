@@ -5,7 +5,7 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use crate::builtin::{Dictionary, StringName};
+use crate::builtin::{StringName, VarDictionary};
 use crate::classes::multiplayer_api::RpcMode;
 use crate::classes::multiplayer_peer::TransferMode;
 use crate::classes::Node;
@@ -43,8 +43,8 @@ impl RpcConfig {
         node.rpc_config(method_name, &self.to_dictionary().to_variant());
     }
 
-    /// Returns a [`Dictionary`] populated with the values required for a call to [`Node::rpc_config()`].
-    pub fn to_dictionary(&self) -> Dictionary {
+    /// Returns a [`VarDictionary`] populated with the values required for a call to [`Node::rpc_config()`].
+    pub fn to_dictionary(&self) -> VarDictionary {
         vdict! {
             "rpc_mode": self.rpc_mode,
             "transfer_mode": self.transfer_mode,
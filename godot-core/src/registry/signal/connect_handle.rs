@@ -16,7 +16,9 @@ use crate::obj::Gd;
 /// Returned by connections made by the `connect_*` methods of
 /// [`TypedSignal`][crate::registry::signal::TypedSignal] and [`ConnectBuilder`][crate::registry::signal::ConnectBuilder].
 ///
-/// Connections managed by a handle can be disconnected using [`disconnect()`][Self::disconnect].
+/// Connections managed by a handle can be disconnected using [`disconnect()`][Self::disconnect]. Dropping the handle keeps the connection
+/// alive; to disconnect automatically, convert it into a [`ConnectGuard`] via [`into_guard()`][Self::into_guard], or add it to a
+/// [`ConnectionSet`].
 pub struct ConnectHandle {
     receiver_object: Gd<Object>,
     signal_name: Cow<'static, str>,
@@ -48,6 +50,18 @@ impl ConnectHandle {
             .disconnect(&*self.signal_name, &self.callable);
     }
 
+    /// Converts this handle into a guard, which disconnects the signal once dropped.
+    pub fn into_guard(self) -> ConnectGuard {
+        ConnectGuard { handle: Some(self) }
+    }
+
+    /// Disconnects the signal, unless it has already been disconnected in another way or the broadcaster is freed.
+    fn disconnect_if_connected(self) {
+        if self.is_connected() {
+            self.disconnect();
+        }
+    }
+
     /// Whether the handle represents a valid connection.
     ///
     /// Returns false if:
@@ -62,3 +76,173 @@ impl ConnectHandle {
                 .is_connected(&*self.signal_name, &self.callable)
     }
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// RAII guard for a typed signal connection, which disconnects the signal when dropped.
+///
+/// Created with [`ConnectHandle::into_guard()`]. This is useful to tie a connection to the lifetime of a Rust value, e.g. a field in a
+/// struct or a local variable in a scope.
+///
+/// If the connection has already been removed in another way (e.g. because the broadcaster was freed), dropping the guard does nothing.
+///
+/// # Example
+/// ```no_run
+/// # use godot::prelude::*;
+/// # use godot::register::ConnectGuard;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Hud {
+///     player_ready: Option<ConnectGuard>,
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl Hud {
+///     fn watch(&mut self, player: &Gd<Node>) {
+///         let guard = player
+///             .signals()
+///             .ready()
+///             .connect(|| godot_print!("Player ready."))
+///             .into_guard();
+///
+///         // Replacing the guard disconnects the previous connection.
+///         self.player_ready = Some(guard);
+///     }
+/// }
+/// ```
+#[must_use = "dropping a ConnectGuard immediately disconnects the signal"]
+pub struct ConnectGuard {
+    // Only None after into_handle() or disconnect().
+    handle: Option<ConnectHandle>,
+}
+
+impl ConnectGuard {
+    /// Disconnects the signal now, instead of when dropped.
+    ///
+    /// Does nothing if the connection has already been removed in another way.
+    pub fn disconnect(mut self) {
+        if let Some(handle) = self.handle.take() {
+            handle.disconnect_if_connected();
+        }
+    }
+
+    /// Releases the connection from the guard, which keeps it alive beyond the guard's lifetime.
+    pub fn into_handle(mut self) -> ConnectHandle {
+        self.handle
+            .take()
+            .expect("ConnectGuard holds a handle until consumed")
+    }
+
+    /// Whether the guard represents a valid connection.
+    ///
+    /// See [`ConnectHandle::is_connected()`] for details.
+    pub fn is_connected(&self) -> bool {
+        self.handle
+            .as_ref()
+            .is_some_and(|handle| handle.is_connected())
+    }
+}
+
+impl From<ConnectHandle> for ConnectGuard {
+    fn from(handle: ConnectHandle) -> Self {
+        handle.into_guard()
+    }
+}
+
+impl Drop for ConnectGuard {
+    fn drop(&mut self) {
+        if let Some(handle) = self.handle.take() {
+            handle.disconnect_if_connected();
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Group of typed signal connections, which are disconnected together.
+///
+/// Store a `ConnectionSet` in a field of your class, add all connections that the class makes, and tear them down at once with
+/// [`disconnect_all()`][Self::disconnect_all] -- typically in `exit_tree()`. All remaining connections are also disconnected when the set
+/// is dropped.
+///
+/// Connections that have already been removed in another way (e.g. because the broadcaster was freed) are skipped.
+///
+/// # Example
+/// ```no_run
+/// # use godot::prelude::*;
+/// # use godot::register::ConnectionSet;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Minimap {
+///     connections: ConnectionSet,
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl INode for Minimap {
+///     fn enter_tree(&mut self) {
+///         let tree = self.base().get_tree().unwrap();
+///         let handle = tree.signals().node_added().connect(|node| godot_print!("Added: {node}"));
+///
+///         self.connections.add(handle);
+///     }
+///
+///     fn exit_tree(&mut self) {
+///         self.connections.disconnect_all();
+///     }
+/// }
+/// ```
+#[derive(Default)]
+pub struct ConnectionSet {
+    handles: Vec<ConnectHandle>,
+}
+
+impl ConnectionSet {
+    /// Creates an empty set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a connection to the set.
+    pub fn add(&mut self, handle: ConnectHandle) {
+        self.handles.push(handle);
+    }
+
+    /// Disconnects all connections in the set, leaving it empty.
+    pub fn disconnect_all(&mut self) {
+        for handle in self.handles.drain(..) {
+            handle.disconnect_if_connected();
+        }
+    }
+
+    /// Number of connections in the set, including ones that have been removed in another way.
+    pub fn len(&self) -> usize {
+        self.handles.len()
+    }
+
+    /// Whether the set contains no connections.
+    pub fn is_empty(&self) -> bool {
+        self.handles.is_empty()
+    }
+}
+
+impl Extend<ConnectHandle> for ConnectionSet {
+    fn extend<I: IntoIterator<Item = ConnectHandle>>(&mut self, iter: I) {
+        self.handles.extend(iter);
+    }
+}
+
+impl FromIterator<ConnectHandle> for ConnectionSet {
+    fn from_iter<I: IntoIterator<Item = ConnectHandle>>(iter: I) -> Self {
+        Self {
+            handles: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl Drop for ConnectionSet {
+    fn drop(&mut self) {
+        self.disconnect_all();
+    }
+}
@@ -16,6 +16,7 @@ use godot_cell::panicking::{InaccessibleGuard, MutGuard, RefGuard};
 use godot_ffi as sys;
 
 use crate::godot_error;
+use crate::meta::error::BorrowError;
 use crate::obj::{Base, Gd, GodotClass, Inherits};
 use crate::storage::log_pre_drop;
 
@@ -95,6 +96,12 @@ pub unsafe trait Storage {
     /// they are violated.
     fn get_mut(&self) -> MutGuard<'_, Self::Instance>;
 
+    /// Like [`get()`](Self::get), but returns an error instead of panicking if the instance is mutably bound.
+    fn try_get(&self) -> Result<RefGuard<'_, Self::Instance>, BorrowError>;
+
+    /// Like [`get_mut()`](Self::get_mut), but returns an error instead of panicking if the instance is bound.
+    fn try_get_mut(&self) -> Result<MutGuard<'_, Self::Instance>, BorrowError>;
+
     /// Returns a guard that allows calling methods on `Gd<Base>` that take `&mut self`.
     ///
     /// This can use the provided `instance` to provide extra safety guarantees such as allowing reentrant
@@ -15,30 +15,28 @@ use std::any::type_name;
 
 pub use instance_storage::*;
 
+use crate::meta::error::BorrowError;
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Shared code for submodules
 
-fn bind_failed<T>(err: Box<dyn std::error::Error>, tracker: &DebugBorrowTracker) -> ! {
-    let ty = type_name::<T>();
-
+#[track_caller]
+fn bind_failed(err: BorrowError, tracker: &DebugBorrowTracker) -> ! {
     eprint!("{tracker}");
 
     panic!(
-        "Gd<T>::bind() failed, already bound; T = {ty}.\n  \
-        Make sure to use `self.base_mut()` or `self.base()` instead of `self.to_gd()` when possible.\n  \
-        Details: {err}."
+        "{err}\n  \
+        Make sure to use `self.base_mut()` or `self.base()` instead of `self.to_gd()` when possible."
     )
 }
 
-fn bind_mut_failed<T>(err: Box<dyn std::error::Error>, tracker: &DebugBorrowTracker) -> ! {
-    let ty = type_name::<T>();
-
+#[track_caller]
+fn bind_mut_failed(err: BorrowError, tracker: &DebugBorrowTracker) -> ! {
     eprint!("{tracker}");
 
     panic!(
-        "Gd<T>::bind_mut() failed, already bound; T = {ty}.\n  \
-        Make sure to use `self.base_mut()` instead of `self.to_gd()` when possible.\n  \
-        Details: {err}."
+        "{err}\n  \
+        Make sure to use `self.base_mut()` instead of `self.to_gd()` when possible."
     )
 }
 
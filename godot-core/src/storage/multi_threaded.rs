@@ -10,6 +10,7 @@ use godot_cell::blocking::{GdCell, InaccessibleGuard, MutGuard, RefGuard};
 #[cfg(not(feature = "experimental-threads"))]
 use godot_cell::panicking::{GdCell, InaccessibleGuard, MutGuard, RefGuard};
 
+use crate::meta::error::BorrowError;
 use crate::obj::{Base, GodotClass};
 use crate::storage::{AtomicLifecycle, DebugBorrowTracker, Lifecycle, Storage, StorageRefCounted};
 
@@ -60,24 +61,42 @@ unsafe impl<T: GodotClass> Storage for InstanceStorage<T> {
     // Multi-threaded binds are currently blocking. However, if they still report an error, we follow the single-threaded behavior
     // of capturing the backtrace. This may be changed as the threading model (#18) evolves.
 
+    #[track_caller]
     fn get(&self) -> RefGuard<'_, T> {
+        match self.try_get() {
+            Ok(guard) => guard,
+            Err(e) => super::bind_failed(e, &self.borrow_tracker),
+        }
+    }
+
+    #[track_caller]
+    fn get_mut(&self) -> MutGuard<'_, T> {
+        match self.try_get_mut() {
+            Ok(guard) => guard,
+            Err(e) => super::bind_mut_failed(e, &self.borrow_tracker),
+        }
+    }
+
+    #[track_caller]
+    fn try_get(&self) -> Result<RefGuard<'_, T>, BorrowError> {
         let guard = self
             .user_instance
             .borrow()
-            .unwrap_or_else(|e| super::bind_failed::<T>(e, &self.borrow_tracker));
+            .map_err(|e| BorrowError::new::<T>(false, e))?;
 
         self.borrow_tracker.track_ref_borrow();
-        guard
+        Ok(guard)
     }
 
-    fn get_mut(&self) -> MutGuard<'_, T> {
+    #[track_caller]
+    fn try_get_mut(&self) -> Result<MutGuard<'_, T>, BorrowError> {
         let guard = self
             .user_instance
             .borrow_mut()
-            .unwrap_or_else(|e| super::bind_mut_failed::<T>(e, &self.borrow_tracker));
+            .map_err(|e| BorrowError::new::<T>(true, e))?;
 
         self.borrow_tracker.track_mut_borrow();
-        guard
+        Ok(guard)
     }
 
     fn get_inaccessible<'a: 'b, 'b>(
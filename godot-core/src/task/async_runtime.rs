@@ -6,15 +6,17 @@
  */
 
 use std::cell::RefCell;
+use std::fmt;
 use std::future::Future;
 use std::marker::PhantomData;
 use std::panic::AssertUnwindSafe;
 use std::pin::Pin;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::task::{Context, Poll, Wake, Waker};
 use std::thread::{self, LocalKey, ThreadId};
 
-use crate::builtin::{Callable, Variant};
+use crate::builtin::{Callable, RustCallable, Signal, Variant};
+use crate::obj::{Gd, GodotClass, InstanceId};
 use crate::private::handle_panic;
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
@@ -24,6 +26,7 @@ use crate::private::handle_panic;
 ///
 /// This function allows creating a new async task in which Godot signals can be awaited, like it is possible in GDScript. The
 /// [`TaskHandle`] that is returned provides synchronous introspection into the current state of the task.
+/// To await the task and obtain its output, use [`spawn_joinable()`](crate::task::spawn_joinable) instead.
 ///
 /// Signals can be converted to futures in the following ways:
 ///
@@ -88,6 +91,52 @@ use crate::private::handle_panic;
 /// ```
 #[doc(alias = "async")]
 pub fn spawn(future: impl Future<Output = ()> + 'static) -> TaskHandle {
+    spawn_with_owner(future, None)
+}
+
+/// Create a new async background task, which is bound to the lifetime of an object.
+///
+/// Works like [`spawn()`], but the task is canceled as soon as `owner` is freed. The future is dropped without being polled again, so it
+/// is safe to access `owner` (or objects kept alive by it) from within the task, without checking their validity after each `await`.
+///
+/// Note that the task must not hold a strong reference to a [`RefCounted`][crate::classes::RefCounted] owner, as that would keep the
+/// owner alive for as long as the task is running.
+///
+/// # Panics
+/// - If called from any other thread than the main thread.
+/// - If `owner` is already freed.
+///
+/// # Example
+/// ```no_run
+/// use std::time::Duration;
+/// use godot::classes::Node2D;
+/// use godot::obj::Gd;
+/// use godot::task;
+///
+/// fn blink(node: Gd<Node2D>) {
+///     let mut node_ref = node.clone();
+///
+///     // If the node is freed while blinking, the task stops.
+///     task::spawn_bound(&node, async move {
+///         loop {
+///             let visible = node_ref.is_visible();
+///             node_ref.set_visible(!visible);
+///             task::sleep(Duration::from_millis(500)).await;
+///         }
+///     });
+/// }
+/// ```
+pub fn spawn_bound<T: GodotClass>(
+    owner: &Gd<T>,
+    future: impl Future<Output = ()> + 'static,
+) -> TaskHandle {
+    spawn_with_owner(future, Some(OwnerRef::new(owner)))
+}
+
+fn spawn_with_owner(
+    future: impl Future<Output = ()> + 'static,
+    owner: Option<OwnerRef>,
+) -> TaskHandle {
     // Spawning new tasks is only allowed on the main thread for now.
     // We can not accept Sync + Send futures since all object references (i.e. Gd<T>) are not thread-safe. So a future has to remain on the
     // same thread it was created on. Godots signals on the other hand can be emitted on any thread, so it can't be guaranteed on which thread
@@ -111,6 +160,13 @@ pub fn spawn(future: impl Future<Output = ()> + 'static) -> TaskHandle {
         (task_handle, godot_waker)
     });
 
+    if let Some(owner) = owner {
+        // Connecting to the owner calls into the engine, so it happens outside the runtime borrow.
+        let bound_owner = BoundOwner::new(owner, Waker::from(godot_waker.clone()));
+
+        ASYNC_RUNTIME.with_runtime_mut(|rt| rt.bind_task(task_handle.index, bound_owner));
+    }
+
     poll_future(godot_waker);
     task_handle
 }
@@ -201,10 +257,18 @@ pub(crate) fn cleanup() {
 }
 
 #[cfg(feature = "trace")]
-pub fn has_godot_task_panicked(task_handle: TaskHandle) -> bool {
+pub fn has_godot_task_panicked(task_handle: &TaskHandle) -> bool {
     ASYNC_RUNTIME.with_runtime(|rt| rt.panicked_tasks.contains(&task_handle.id))
 }
 
+/// Whether a task spawned with [`spawn_bound()`] has been canceled because its owner was freed.
+///
+/// Such tasks are not considered panicked by [`has_godot_task_panicked()`].
+#[cfg(feature = "trace")]
+pub fn has_godot_task_owner_freed(task_handle: &TaskHandle) -> bool {
+    ASYNC_RUNTIME.with_runtime(|rt| rt.owner_freed_tasks.contains(&task_handle.id))
+}
+
 /// The current state of a future inside the async runtime.
 enum FutureSlotState<T> {
     /// Slot is currently empty.
@@ -223,6 +287,8 @@ enum FutureSlotState<T> {
 struct FutureSlot<T> {
     value: FutureSlotState<T>,
     id: u64,
+    /// Object to whose lifetime the task is bound, see [`spawn_bound()`].
+    owner: Option<BoundOwner>,
 }
 
 impl<T> FutureSlot<T> {
@@ -231,6 +297,7 @@ impl<T> FutureSlot<T> {
         Self {
             value: FutureSlotState::Pending(value),
             id,
+            owner: None,
         }
     }
 
@@ -244,6 +311,18 @@ impl<T> FutureSlot<T> {
     /// This transitions the slot into the [`FutureSlotState::Gone`] state.
     fn clear(&mut self) {
         self.value = FutureSlotState::Gone;
+        self.owner = None;
+    }
+
+    /// Checks if the slot holds the pending future with the given ID, and that future is bound to an object which has been freed.
+    fn is_owner_freed(&self, id: u64) -> bool {
+        let is_pending = matches!(self.value, FutureSlotState::Pending(_)) && self.id == id;
+
+        is_pending
+            && self
+                .owner
+                .as_ref()
+                .is_some_and(|owner| !owner.instance_id.lookup_validity())
     }
 
     /// Attempts to extract the future with the given ID from the slot.
@@ -291,6 +370,8 @@ struct AsyncRuntime {
     next_task_id: u64,
     #[cfg(feature = "trace")]
     panicked_tasks: std::collections::HashSet<u64>,
+    #[cfg(feature = "trace")]
+    owner_freed_tasks: std::collections::HashSet<u64>,
 }
 
 impl AsyncRuntime {
@@ -301,6 +382,8 @@ impl AsyncRuntime {
             next_task_id: 0,
             #[cfg(feature = "trace")]
             panicked_tasks: std::collections::HashSet::default(),
+            #[cfg(feature = "trace")]
+            owner_freed_tasks: std::collections::HashSet::default(),
         }
     }
 
@@ -356,6 +439,11 @@ impl AsyncRuntime {
             .unwrap_or(FutureSlotState::Empty)
     }
 
+    /// Bind the lifetime of a newly added task to an object.
+    fn bind_task(&mut self, index: usize, owner: BoundOwner) {
+        self.tasks[index].owner = Some(owner);
+    }
+
     /// Remove a future from the storage and free up its slot.
     ///
     /// The slot is left in the [`FutureSlotState::Gone`] state.
@@ -378,6 +466,14 @@ impl AsyncRuntime {
     fn track_panic(&mut self, task_id: u64) {
         self.panicked_tasks.insert(task_id);
     }
+
+    /// Track that a future was canceled because its owner was freed.
+    ///
+    /// This is only available for itest.
+    #[cfg(feature = "trace")]
+    fn track_owner_freed(&mut self, task_id: u64) {
+        self.owner_freed_tasks.insert(task_id);
+    }
 }
 
 trait WithRuntime {
@@ -423,7 +519,22 @@ fn poll_future(godot_waker: Arc<GodotWaker>) {
 
     // Move future out of the runtime while we are polling it to avoid holding a mutable reference for the entire runtime.
     let future = ASYNC_RUNTIME.with_runtime_mut(|rt| {
-        match rt.take_task_for_polling(godot_waker.runtime_index, godot_waker.task_id) {
+        let index = godot_waker.runtime_index;
+
+        // Tasks bound to a freed object are canceled instead of being polled.
+        if rt
+            .tasks
+            .get(index)
+            .is_some_and(|slot| slot.is_owner_freed(godot_waker.task_id))
+        {
+            #[cfg(feature = "trace")]
+            rt.track_owner_freed(godot_waker.task_id);
+            rt.clear_task(index);
+
+            return None;
+        }
+
+        match rt.take_task_for_polling(index, godot_waker.task_id) {
             FutureSlotState::Empty => {
                 panic!("Future slot is empty when waking it! This is a bug!");
             }
@@ -439,7 +550,7 @@ fn poll_future(godot_waker: Arc<GodotWaker>) {
     });
 
     let Some(future) = future else {
-        // Future has been canceled while the waker was already triggered.
+        // Future has been canceled while the waker was already triggered, or its owner has been freed.
         return;
     };
 
@@ -523,3 +634,128 @@ impl Wake for GodotWaker {
         callable.call_deferred(&[]);
     }
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Owner binding
+
+/// Object passed to [`spawn_bound()`], before the task is created.
+struct OwnerRef {
+    instance_id: InstanceId,
+    signal: Signal,
+}
+
+impl OwnerRef {
+    fn new<T: GodotClass>(owner: &Gd<T>) -> Self {
+        Self {
+            instance_id: owner.instance_id(),
+            // Every object has this signal. Its connections are released when the object is freed, which is observed by OwnerWatcher.
+            signal: Signal::from_object_signal(owner, "script_changed"),
+        }
+    }
+}
+
+/// Binds a task to the lifetime of an object.
+///
+/// Connects an [`OwnerWatcher`] to the object, which wakes the task once the object is freed. On the next poll, the runtime then notices
+/// the freed owner and cancels the task. When the task ends for other reasons, the watcher is disconnected again.
+struct BoundOwner {
+    instance_id: InstanceId,
+    signal: Signal,
+    watcher: OwnerWatcher,
+}
+
+impl BoundOwner {
+    fn new(owner: OwnerRef, waker: Waker) -> Self {
+        let watcher = OwnerWatcher {
+            waker: Arc::new(Mutex::new(Some(waker))),
+            armed: false,
+        };
+
+        let armed_watcher = OwnerWatcher {
+            waker: watcher.waker.clone(),
+            armed: true,
+        };
+
+        owner
+            .signal
+            .connect(&Callable::from_custom(armed_watcher), 0);
+
+        Self {
+            instance_id: owner.instance_id,
+            signal: owner.signal,
+            watcher,
+        }
+    }
+}
+
+impl Drop for BoundOwner {
+    fn drop(&mut self) {
+        // Disarm, so that disconnecting doesn't wake the task.
+        self.watcher.waker.lock().unwrap().take();
+
+        if self.signal.is_null() {
+            return;
+        }
+
+        // Equal to the connected callable, but with independent reference counting.
+        let callable = Callable::from_custom(self.watcher.clone());
+        if self.signal.is_connected(&callable) {
+            self.signal.disconnect(&callable);
+        }
+    }
+}
+
+/// Callable connected to the owner of a bound task, waking the task when dropped by the engine (i.e. when the owner is freed).
+///
+/// Only the instance owned by the engine is `armed`; clones used for disconnecting don't wake the task.
+struct OwnerWatcher {
+    waker: Arc<Mutex<Option<Waker>>>,
+    armed: bool,
+}
+
+impl Clone for OwnerWatcher {
+    fn clone(&self) -> Self {
+        Self {
+            waker: self.waker.clone(),
+            armed: false,
+        }
+    }
+}
+
+impl std::hash::Hash for OwnerWatcher {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        state.write_usize(Arc::as_ptr(&self.waker) as usize);
+    }
+}
+
+impl PartialEq for OwnerWatcher {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.waker, &other.waker)
+    }
+}
+
+impl fmt::Display for OwnerWatcher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "OwnerWatcher")
+    }
+}
+
+impl RustCallable for OwnerWatcher {
+    fn invoke(&mut self, _args: &[&Variant]) -> Result<Variant, ()> {
+        // The signal itself is irrelevant; only the release of the connection matters.
+        Ok(Variant::nil())
+    }
+}
+
+impl Drop for OwnerWatcher {
+    fn drop(&mut self) {
+        if !self.armed {
+            return;
+        }
+
+        let waker = self.waker.lock().unwrap().take();
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
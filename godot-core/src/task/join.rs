@@ -0,0 +1,288 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::cell::RefCell;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll, Waker};
+
+use crate::task::{spawn, TaskHandle};
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Public interface
+
+/// Create a new async background task, whose result can be awaited.
+///
+/// Works like [`spawn()`], but returns a [`JoinHandle`] instead of a [`TaskHandle`]. The join handle is itself a future, which resolves
+/// to the output of the spawned task. This allows to wait for tasks from other tasks, and to combine them with [`join_all()`] or
+/// [`select()`].
+///
+/// # Panics
+/// If called from any other thread than the main thread.
+///
+/// # Example
+/// ```no_run
+/// use godot::task;
+///
+/// let child = task::spawn_joinable(async {
+///     task::next_frame().await;
+///     42
+/// });
+///
+/// task::spawn(async move {
+///     let value = child.await;
+///     assert_eq!(value, 42);
+/// });
+/// ```
+pub fn spawn_joinable<T: 'static>(future: impl Future<Output = T> + 'static) -> JoinHandle<T> {
+    let state = Rc::new(RefCell::new(JoinState {
+        result: JoinResult::Pending,
+        waker: None,
+    }));
+
+    let task = spawn(ResultForwarder {
+        future: Box::pin(future),
+        state: state.clone(),
+    });
+
+    JoinHandle { task, state }
+}
+
+/// Waits for all futures to complete, and returns their outputs in the original order.
+///
+/// The futures are polled concurrently within the current task. If one of them panics, the panic propagates to the awaiting task; this
+/// includes [`JoinHandle`]s of child tasks that panicked.
+///
+/// # Example
+/// ```no_run
+/// use godot::task;
+///
+/// task::spawn(async {
+///     let children = (0..3).map(|i| task::spawn_joinable(async move { i * 2 }));
+///     let values = task::join_all(children).await;
+///
+///     assert_eq!(values, [0, 2, 4]);
+/// });
+/// ```
+pub fn join_all<F: Future>(futures: impl IntoIterator<Item = F>) -> JoinAll<F> {
+    let futures: Vec<_> = futures.into_iter().map(Box::pin).collect();
+    let outputs = futures.iter().map(|_| None).collect();
+
+    JoinAll { futures, outputs }
+}
+
+/// Waits for the first of several futures to complete, and returns its index and output.
+///
+/// The remaining futures are dropped. Since dropping a [`JoinHandle`] does not cancel its task, child tasks that have not completed
+/// continue to run in the background.
+///
+/// If one of the futures panics, the panic propagates to the awaiting task; this includes [`JoinHandle`]s of child tasks that panicked.
+///
+/// # Panics
+/// If `futures` is empty.
+pub fn select<F: Future>(futures: impl IntoIterator<Item = F>) -> Select<F> {
+    let futures: Vec<_> = futures.into_iter().map(Box::pin).collect();
+    assert!(!futures.is_empty(), "select() requires at least one future");
+
+    Select {
+        futures: Some(futures),
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// JoinHandle
+
+/// Handle for an active background task, which resolves to the task's output when awaited.
+///
+/// Returned by [`spawn_joinable()`]. Like [`TaskHandle`], dropping the handle does **not** cancel the task.
+///
+/// # Panics
+/// When awaited, if the task panicked or was canceled before completing. This way, panics in child tasks propagate to their parents.
+pub struct JoinHandle<T> {
+    task: TaskHandle,
+    state: Rc<RefCell<JoinState<T>>>,
+}
+
+impl<T> JoinHandle<T> {
+    /// Cancels the task if it is still pending and does nothing if it is already completed.
+    pub fn cancel(self) {
+        self.task.cancel();
+    }
+
+    /// Synchronously checks if the task is still pending or has already completed.
+    pub fn is_pending(&self) -> bool {
+        self.task.is_pending()
+    }
+}
+
+impl<T> Future for JoinHandle<T> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.state.borrow_mut();
+
+        match std::mem::replace(&mut state.result, JoinResult::Taken) {
+            JoinResult::Pending => {
+                state.result = JoinResult::Pending;
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+            JoinResult::Ready(value) => Poll::Ready(value),
+
+            // Release the borrow before unwinding.
+            JoinResult::Panicked => {
+                drop(state);
+                panic!("joined task panicked; see error above")
+            }
+            JoinResult::Canceled => {
+                drop(state);
+                panic!("joined task was canceled before completing")
+            }
+            JoinResult::Taken => {
+                drop(state);
+                panic!("JoinHandle polled after completion")
+            }
+        }
+    }
+}
+
+struct JoinState<T> {
+    result: JoinResult<T>,
+    waker: Option<Waker>,
+}
+
+enum JoinResult<T> {
+    /// Task has not completed yet.
+    Pending,
+    /// Task completed, output not yet retrieved.
+    Ready(T),
+    /// Task panicked.
+    Panicked,
+    /// Task was dropped before completing, e.g. via `TaskHandle::cancel()`.
+    Canceled,
+    /// Output has been retrieved by the `JoinHandle`.
+    Taken,
+}
+
+/// Future running inside the async runtime, which forwards the output of the user future to the `JoinHandle`.
+struct ResultForwarder<F: Future> {
+    future: Pin<Box<F>>,
+    state: Rc<RefCell<JoinState<F::Output>>>,
+}
+
+impl<F: Future> ResultForwarder<F> {
+    fn resolve(&self, result: JoinResult<F::Output>) {
+        let waker = {
+            let mut state = self.state.borrow_mut();
+            state.result = result;
+            state.waker.take()
+        };
+
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+impl<F: Future> Future for ResultForwarder<F> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+
+        this.future.as_mut().poll(cx).map(|output| {
+            this.resolve(JoinResult::Ready(output));
+        })
+    }
+}
+
+// The async runtime drops the future without completion if it was canceled, or if polling it panicked (during unwinding).
+impl<F: Future> Drop for ResultForwarder<F> {
+    fn drop(&mut self) {
+        if !matches!(self.state.borrow().result, JoinResult::Pending) {
+            return;
+        }
+
+        let result = if std::thread::panicking() {
+            JoinResult::Panicked
+        } else {
+            JoinResult::Canceled
+        };
+
+        self.resolve(result);
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Combinators
+
+/// Future returned by [`join_all()`].
+pub struct JoinAll<F: Future> {
+    futures: Vec<Pin<Box<F>>>,
+    outputs: Vec<Option<F::Output>>,
+}
+
+// Outputs are never pinned, and futures are pinned in their own boxes.
+impl<F: Future> Unpin for JoinAll<F> {}
+
+impl<F: Future> Future for JoinAll<F> {
+    type Output = Vec<F::Output>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+
+        let mut all_ready = true;
+        for (future, output) in this.futures.iter_mut().zip(this.outputs.iter_mut()) {
+            if output.is_some() {
+                continue;
+            }
+
+            match future.as_mut().poll(cx) {
+                Poll::Ready(value) => *output = Some(value),
+                Poll::Pending => all_ready = false,
+            }
+        }
+
+        if !all_ready {
+            return Poll::Pending;
+        }
+
+        let outputs = std::mem::take(&mut this.outputs)
+            .into_iter()
+            .map(|output| output.expect("all futures are ready"))
+            .collect();
+
+        Poll::Ready(outputs)
+    }
+}
+
+/// Future returned by [`select()`].
+pub struct Select<F: Future> {
+    /// Remaining futures; `None` once one of them has completed.
+    futures: Option<Vec<Pin<Box<F>>>>,
+}
+
+impl<F: Future> Future for Select<F> {
+    type Output = (usize, F::Output);
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        let Some(futures) = this.futures.as_mut() else {
+            panic!("Select polled after completion");
+        };
+
+        for (index, future) in futures.iter_mut().enumerate() {
+            if let Poll::Ready(output) = future.as_mut().poll(cx) {
+                this.futures = None;
+                return Poll::Ready((index, output));
+            }
+        }
+
+        Poll::Pending
+    }
+}
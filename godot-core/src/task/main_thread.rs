@@ -0,0 +1,181 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+use crate::obj::call_deferred_sync;
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Public interface
+
+/// Runs `f` on the main thread, during idle time of the current or next frame.
+///
+/// This can be called from any thread, which makes it the way to deliver results of background work (e.g. computed on a thread pool)
+/// back to the scene tree. Inside `f`, all engine APIs and `Gd<T>` pointers can be used as usual. Objects cannot be captured by `f` directly,
+/// as `Gd<T>` is not `Send`; capture an [`InstanceId`](crate::obj::InstanceId) instead and look up the object inside the closure.
+///
+/// Work is queued through [`Callable::call_deferred()`](crate::builtin::Callable::call_deferred). Calling this function on the main thread
+/// does not run `f` immediately, but defers it as well.
+///
+/// Requires the `experimental-threads` feature.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::task;
+///
+/// fn compute_path_in_background(agent: &Gd<Node2D>) {
+///     let agent_id = agent.instance_id();
+///
+///     std::thread::spawn(move || {
+///         let target = Vector2::new(100.0, 50.0); // Expensive computation here.
+///
+///         task::run_on_main_thread(move || {
+///             if let Ok(mut agent) = Gd::<Node2D>::try_from_instance_id(agent_id) {
+///                 agent.set_position(target);
+///             }
+///         });
+///     });
+/// }
+/// ```
+#[cfg_attr(published_docs, doc(cfg(feature = "experimental-threads")))]
+pub fn run_on_main_thread<F>(f: F)
+where
+    F: FnOnce() + Send + 'static,
+{
+    call_deferred_sync(f);
+}
+
+/// Runs `f` on the main thread and returns a future that resolves to its result.
+///
+/// Like [`run_on_main_thread()`], but the return value of `f` is sent back to the caller. The returned [`MainThreadFuture`] is `Send`, so
+/// it can be awaited on any executor, including one running on a worker thread.
+///
+/// Requires the `experimental-threads` feature.
+///
+/// # Panics
+/// When polling the future, if `f` panicked or was dropped without being run (e.g. because the engine shut down).
+///
+/// # Example
+/// ```no_run
+/// use godot::classes::Engine;
+/// use godot::task;
+///
+/// async fn frames_drawn() -> i32 {
+///     task::run_on_main_thread_async(|| Engine::singleton().get_frames_drawn()).await
+/// }
+/// ```
+#[cfg_attr(published_docs, doc(cfg(feature = "experimental-threads")))]
+pub fn run_on_main_thread_async<F, R>(f: F) -> MainThreadFuture<R>
+where
+    F: FnOnce() -> R + Send + 'static,
+    R: Send + 'static,
+{
+    let data = Arc::new(Mutex::new(OneshotData::default()));
+    let sender = OneshotSender { data: data.clone() };
+
+    call_deferred_sync(move || sender.send(f()));
+
+    MainThreadFuture { data }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Future types
+
+/// Future returned by [`run_on_main_thread_async()`].
+///
+/// Resolves to the return value of the closure, once it has run on the main thread. Requires the `experimental-threads` feature.
+#[cfg_attr(published_docs, doc(cfg(feature = "experimental-threads")))]
+pub struct MainThreadFuture<R> {
+    data: Arc<Mutex<OneshotData<R>>>,
+}
+
+impl<R> Future for MainThreadFuture<R> {
+    type Output = R;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut data = self.data.lock().unwrap();
+
+        match std::mem::replace(&mut data.state, OneshotState::Taken) {
+            OneshotState::Pending => {
+                data.state = OneshotState::Pending;
+                data.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+            OneshotState::Ready(value) => Poll::Ready(value),
+            OneshotState::Dropped => {
+                panic!("closure passed to run_on_main_thread_async() panicked or was dropped without running")
+            }
+            OneshotState::Taken => panic!("MainThreadFuture polled after completion"),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Oneshot channel
+
+// Not derived, otherwise an extra bound `R: Default` is required.
+struct OneshotData<R> {
+    state: OneshotState<R>,
+    waker: Option<Waker>,
+}
+
+impl<R> Default for OneshotData<R> {
+    fn default() -> Self {
+        Self {
+            state: OneshotState::Pending,
+            waker: None,
+        }
+    }
+}
+
+enum OneshotState<R> {
+    Pending,
+    Ready(R),
+    /// Sender was dropped without sending a value.
+    Dropped,
+    /// Value has been handed out by the future.
+    Taken,
+}
+
+/// Sending half, moved into the deferred closure.
+struct OneshotSender<R> {
+    data: Arc<Mutex<OneshotData<R>>>,
+}
+
+impl<R> OneshotSender<R> {
+    fn send(self, value: R) {
+        self.resolve(OneshotState::Ready(value));
+    }
+
+    fn resolve(&self, state: OneshotState<R>) {
+        let waker = {
+            let mut data = self.data.lock().unwrap();
+            if !matches!(data.state, OneshotState::Pending) {
+                return;
+            }
+
+            data.state = state;
+            data.waker.take()
+        };
+
+        // Wake outside the lock, in case the executor polls synchronously.
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+// Runs after send() as well, in which case the state is no longer pending and this is a no-op.
+impl<R> Drop for OneshotSender<R> {
+    fn drop(&mut self) {
+        self.resolve(OneshotState::Dropped);
+    }
+}
@@ -9,21 +9,39 @@
 //!
 //! This module contains:
 //! - Implementations of [`Future`](std::future::Future) for [`Signal`](crate::builtin::Signal) and [`TypedSignal`](crate::registry::signal::TypedSignal).
+//! - [`SignalStream`], to await every emission of a signal.
 //! - A way to [`spawn`] new async tasks by using the engine as the async runtime.
+//! - Awaitable tasks via [`spawn_joinable`], and combinators [`join_all`] and [`select`] to wait for several futures.
+//! - Futures to wait for time or frames: [`sleep`], [`next_frame`], [`next_physics_frame`] and [`timeout`].
+//! - Dispatch from worker threads to the main thread: `run_on_main_thread` and `run_on_main_thread_async`.
+//!
+//! The main-thread dispatch functions and `MainThreadFuture` are only available with the **`experimental-threads`** feature.
 
 mod async_runtime;
 mod futures;
+mod join;
+#[cfg(feature = "experimental-threads")]
+mod main_thread;
+mod signal_stream;
+mod time;
 
 // Public re-exports
-pub use async_runtime::{spawn, TaskHandle};
+pub use async_runtime::{spawn, spawn_bound, TaskHandle};
 pub use futures::{
     DynamicSend, FallibleSignalFuture, FallibleSignalFutureError, IntoDynamicSend, SignalFuture,
 };
+pub use join::{join_all, select, spawn_joinable, JoinAll, JoinHandle, Select};
+#[cfg(feature = "experimental-threads")]
+pub use main_thread::{run_on_main_thread, run_on_main_thread_async, MainThreadFuture};
+pub use signal_stream::{OverflowPolicy, SignalStream, SignalStreamNext};
+pub use time::{
+    next_frame, next_physics_frame, sleep, timeout, NextFrame, Sleep, Timeout, TimeoutError,
+};
 
 // For use in integration tests.
 #[cfg(feature = "trace")]
 mod reexport_test {
-    pub use super::async_runtime::has_godot_task_panicked;
+    pub use super::async_runtime::{has_godot_task_owner_freed, has_godot_task_panicked};
     pub use super::futures::{create_test_signal_future_resolver, SignalFutureResolver};
 }
 
@@ -0,0 +1,309 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::collections::VecDeque;
+use std::fmt::Display;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+use crate::builtin::{Callable, RustCallable, Signal, Variant};
+use crate::meta::InParamTuple;
+use crate::obj::WithSignals;
+use crate::registry::signal::TypedSignal;
+use crate::task::{DynamicSend, IntoDynamicSend};
+
+/// Default number of emissions buffered by [`TypedSignal::to_stream()`] and [`Signal::to_stream()`].
+const DEFAULT_CAPACITY: usize = 16;
+
+/// Determines what happens when a signal is emitted while the buffer of a [`SignalStream`] is full.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub enum OverflowPolicy {
+    /// Discard the oldest buffered emission, to make room for the new one.
+    #[default]
+    DropOldest,
+
+    /// Discard the new emission, keeping the buffer as is.
+    DropNewest,
+}
+
+/// Async iterator over the emissions of a signal.
+///
+/// Unlike a [`SignalFuture`][crate::task::SignalFuture], which resolves only once, a stream stays connected to the signal and buffers
+/// each emission until it is consumed. This allows to process every emission in an async loop, without missing any that happen between
+/// two `await` points. The buffer is bounded; when it is full, emissions are discarded according to the [`OverflowPolicy`].
+///
+/// The stream ends (i.e. [`next()`][Self::next] returns `None`) once the signal object is freed and all buffered emissions have been
+/// consumed. Dropping the stream disconnects it from the signal.
+///
+/// [`poll_next()`][Self::poll_next] has the same signature as `futures_core::Stream::poll_next()`, so the type can easily be adapted to
+/// stream combinators of the async ecosystem.
+///
+/// # Panics
+/// - If one of the signal arguments is `!Send`, but the signal was emitted on a different thread.
+///
+/// # Example
+/// ```no_run
+/// # use godot::prelude::*;
+/// # use godot::classes::Area2D;
+/// fn count_bodies(area: &Gd<Area2D>) {
+///     let mut bodies = area.signals().body_entered().to_stream();
+///
+///     godot::task::spawn(async move {
+///         let mut count = 0;
+///         while let Some((body,)) = bodies.next().await {
+///             count += 1;
+///             godot_print!("Body #{count} entered: {body}");
+///         }
+///     });
+/// }
+/// ```
+pub struct SignalStream<R: InParamTuple + IntoDynamicSend> {
+    data: Arc<Mutex<SignalStreamData<R::Target>>>,
+    sender: SignalStreamSender<R>,
+    signal: Signal,
+}
+
+impl<R: InParamTuple + IntoDynamicSend> SignalStream<R> {
+    fn new(signal: Signal, capacity: usize, overflow: OverflowPolicy) -> Self {
+        assert!(capacity > 0, "SignalStream capacity must be at least 1");
+        debug_assert!(
+            !signal.is_null(),
+            "Failed to create a stream for an invalid Signal!\nEither the signal object was already freed or the signal was not registered in the object before using it.",
+        );
+
+        let data = Arc::new(Mutex::new(SignalStreamData {
+            buffer: VecDeque::with_capacity(capacity),
+            capacity,
+            overflow,
+            state: SignalStreamState::Open,
+            waker: None,
+        }));
+
+        let sender = SignalStreamSender { data: data.clone() };
+        signal.connect(&Callable::from_custom(sender.clone()), 0);
+
+        Self {
+            data,
+            sender,
+            signal,
+        }
+    }
+
+    /// Returns a future that resolves to the next emission, or `None` if the stream has ended.
+    #[allow(clippy::should_implement_trait)] // Async counterpart of Iterator::next().
+    pub fn next(&mut self) -> SignalStreamNext<'_, R> {
+        SignalStreamNext { stream: self }
+    }
+
+    /// Attempts to retrieve the next emission, registering the current task for wakeup if none is available yet.
+    ///
+    /// Returns `Poll::Ready(None)` once the signal object has been freed and all buffered emissions have been consumed.
+    pub fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<R>> {
+        let mut data = self.data.lock().unwrap();
+
+        let Some(value) = data.buffer.pop_front() else {
+            if data.state == SignalStreamState::Closed {
+                return Poll::Ready(None);
+            }
+
+            data.waker = Some(cx.waker().clone());
+            return Poll::Pending;
+        };
+
+        // Drop the lock before a potential panic, to not poison the mutex.
+        drop(data);
+
+        let Some(value) = DynamicSend::extract_if_safe(value) else {
+            panic!("the streamed signal was not emitted on the main-thread, but contained a non Send argument");
+        };
+
+        Poll::Ready(Some(value))
+    }
+
+    /// Number of emissions that are buffered and can be retrieved without waiting.
+    pub fn buffered_len(&self) -> usize {
+        self.data.lock().unwrap().buffer.len()
+    }
+}
+
+impl<R: InParamTuple + IntoDynamicSend> Drop for SignalStream<R> {
+    fn drop(&mut self) {
+        // Prevent the sender from treating its own disconnection as the signal object being freed.
+        self.data.lock().unwrap().state = SignalStreamState::Dropped;
+
+        if self.signal.is_null() {
+            return;
+        }
+
+        // Equal to the connected callable, but with independent reference counting.
+        let gd_callable = Callable::from_custom(self.sender.clone());
+
+        if self.signal.is_connected(&gd_callable) {
+            self.signal.disconnect(&gd_callable);
+        }
+    }
+}
+
+/// Future returned by [`SignalStream::next()`].
+pub struct SignalStreamNext<'a, R: InParamTuple + IntoDynamicSend> {
+    stream: &'a mut SignalStream<R>,
+}
+
+impl<R: InParamTuple + IntoDynamicSend> Future for SignalStreamNext<'_, R> {
+    type Output = Option<R>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut *self.get_mut().stream).poll_next(cx)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+struct SignalStreamData<T> {
+    buffer: VecDeque<T>,
+    capacity: usize,
+    overflow: OverflowPolicy,
+    state: SignalStreamState,
+    waker: Option<Waker>,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+enum SignalStreamState {
+    /// Connected to the signal.
+    Open,
+    /// The connection has been released by the engine, i.e. the signal object was freed.
+    Closed,
+    /// The stream itself has been dropped.
+    Dropped,
+}
+
+/// Callable connected to the signal, which pushes emissions into the stream's buffer.
+struct SignalStreamSender<R: IntoDynamicSend> {
+    data: Arc<Mutex<SignalStreamData<R::Target>>>,
+}
+
+impl<R: IntoDynamicSend> Clone for SignalStreamSender<R> {
+    fn clone(&self) -> Self {
+        Self {
+            data: self.data.clone(),
+        }
+    }
+}
+
+impl<R: IntoDynamicSend> std::hash::Hash for SignalStreamSender<R> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        state.write_usize(Arc::as_ptr(&self.data) as usize);
+    }
+}
+
+impl<R: IntoDynamicSend> PartialEq for SignalStreamSender<R> {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.data, &other.data)
+    }
+}
+
+impl<R: IntoDynamicSend> Display for SignalStreamSender<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SignalStreamSender::<{}>", std::any::type_name::<R>())
+    }
+}
+
+impl<R: InParamTuple + IntoDynamicSend> RustCallable for SignalStreamSender<R> {
+    fn invoke(&mut self, args: &[&Variant]) -> Result<Variant, ()> {
+        let value = R::from_variant_array(args).into_dynamic_send();
+
+        let waker = {
+            let mut data = self.data.lock().unwrap();
+
+            if data.buffer.len() >= data.capacity {
+                match data.overflow {
+                    OverflowPolicy::DropOldest => {
+                        data.buffer.pop_front();
+                    }
+                    OverflowPolicy::DropNewest => return Ok(Variant::nil()),
+                }
+            }
+
+            data.buffer.push_back(value);
+            data.waker.take()
+        };
+
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+
+        Ok(Variant::nil())
+    }
+}
+
+// The engine releases the connected callable when the signal object is freed. This ends the stream, once the buffer is consumed.
+impl<R: IntoDynamicSend> Drop for SignalStreamSender<R> {
+    fn drop(&mut self) {
+        let mut data = self.data.lock().unwrap();
+
+        if data.state != SignalStreamState::Open {
+            return;
+        }
+
+        // The stream holds its own sender, so only the engine's copy can be dropped while the stream is still alive.
+        data.state = SignalStreamState::Closed;
+
+        if let Some(waker) = data.waker.take() {
+            drop(data);
+            waker.wake();
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Constructors
+
+impl Signal {
+    /// Creates a stream of emissions of this signal.
+    ///
+    /// Buffers up to 16 emissions and discards the oldest ones on overflow. See [`SignalStream`] for details, and
+    /// [`to_stream_with()`][Self::to_stream_with] to customize the buffer.
+    ///
+    /// Since the `Signal` type does not contain information on the signal argument types, the stream item type has to be inferred from
+    /// the call to this function.
+    pub fn to_stream<R: InParamTuple + IntoDynamicSend>(&self) -> SignalStream<R> {
+        self.to_stream_with(DEFAULT_CAPACITY, OverflowPolicy::default())
+    }
+
+    /// Creates a stream of emissions of this signal, with a custom buffer size and overflow policy.
+    ///
+    /// # Panics
+    /// If `capacity` is 0.
+    pub fn to_stream_with<R: InParamTuple + IntoDynamicSend>(
+        &self,
+        capacity: usize,
+        overflow: OverflowPolicy,
+    ) -> SignalStream<R> {
+        SignalStream::new(self.clone(), capacity, overflow)
+    }
+}
+
+impl<C: WithSignals, R: InParamTuple + IntoDynamicSend> TypedSignal<'_, C, R> {
+    /// Creates a stream of emissions of this signal.
+    ///
+    /// Buffers up to 16 emissions and discards the oldest ones on overflow. See [`SignalStream`] for details, and
+    /// [`to_stream_with()`][Self::to_stream_with] to customize the buffer.
+    pub fn to_stream(&self) -> SignalStream<R> {
+        self.to_stream_with(DEFAULT_CAPACITY, OverflowPolicy::default())
+    }
+
+    /// Creates a stream of emissions of this signal, with a custom buffer size and overflow policy.
+    ///
+    /// # Panics
+    /// If `capacity` is 0.
+    pub fn to_stream_with(&self, capacity: usize, overflow: OverflowPolicy) -> SignalStream<R> {
+        SignalStream::new(self.to_untyped(), capacity, overflow)
+    }
+}
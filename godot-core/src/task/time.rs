@@ -0,0 +1,193 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt::Display;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use crate::classes::{Engine, SceneTree, SceneTreeTimer};
+use crate::obj::Gd;
+use crate::task::SignalFuture;
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Public interface
+
+/// Returns a future that resolves after `duration` has elapsed.
+///
+/// The time is measured by a [`SceneTreeTimer`], so it follows the engine's process loop: the future resolves at the earliest in the
+/// frame in which the timer expires. The timer keeps running while the scene tree is paused, and is affected by
+/// [`Engine::time_scale`](crate::classes::Engine::set_time_scale).
+///
+/// If the task awaiting the future is canceled via [`TaskHandle::cancel()`](crate::task::TaskHandle::cancel), the future is dropped and
+/// the timer no longer resumes the task.
+///
+/// # Panics
+/// If the engine's main loop is not a [`SceneTree`].
+///
+/// # Example
+/// ```no_run
+/// use std::time::Duration;
+/// use godot::task;
+///
+/// task::spawn(async {
+///     task::sleep(Duration::from_millis(500)).await;
+///     godot::global::godot_print!("Half a second later.");
+/// });
+/// ```
+pub fn sleep(duration: Duration) -> Sleep {
+    let timer = scene_tree("sleep")
+        .create_timer(duration.as_secs_f64())
+        .expect("SceneTree::create_timer() returned null");
+
+    let signal_future = timer.signals().timeout().to_future();
+
+    Sleep {
+        _timer: timer,
+        signal_future,
+    }
+}
+
+/// Returns a future that resolves at the next process frame.
+///
+/// The future resolves when [`SceneTree`] emits `process_frame`, i.e. right before `INode::process()` is invoked on nodes.
+///
+/// # Panics
+/// If the engine's main loop is not a [`SceneTree`].
+pub fn next_frame() -> NextFrame {
+    let tree = scene_tree("next_frame");
+
+    NextFrame {
+        signal_future: tree.signals().process_frame().to_future(),
+    }
+}
+
+/// Returns a future that resolves at the next physics frame.
+///
+/// The future resolves when [`SceneTree`] emits `physics_frame`, i.e. right before `INode::physics_process()` is invoked on nodes.
+///
+/// # Panics
+/// If the engine's main loop is not a [`SceneTree`].
+pub fn next_physics_frame() -> NextFrame {
+    let tree = scene_tree("next_physics_frame");
+
+    NextFrame {
+        signal_future: tree.signals().physics_frame().to_future(),
+    }
+}
+
+/// Limits the time that `future` may take to complete.
+///
+/// The returned future resolves to `Ok(output)` if `future` completes before `duration` has elapsed, and to `Err(TimeoutError)`
+/// otherwise. In the latter case, `future` is dropped without being polled again.
+///
+/// Time is measured like in [`sleep()`].
+///
+/// # Panics
+/// If the engine's main loop is not a [`SceneTree`].
+///
+/// # Example
+/// ```no_run
+/// use std::time::Duration;
+/// use godot::classes::Node;
+/// use godot::obj::Gd;
+/// use godot::task;
+///
+/// fn wait_for_ready(node: Gd<Node>) {
+///     task::spawn(async move {
+///         match task::timeout(Duration::from_secs(2), node.signals().ready().to_future()).await {
+///             Ok(()) => godot::global::godot_print!("Node is ready."),
+///             Err(err) => godot::global::godot_warn!("{err}"),
+///         }
+///     });
+/// }
+/// ```
+pub fn timeout<F: Future>(duration: Duration, future: F) -> Timeout<F> {
+    Timeout {
+        future: Box::pin(future),
+        sleep: sleep(duration),
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Future types
+
+/// Future returned by [`sleep()`].
+pub struct Sleep {
+    // Keeps the timer alive independently of the scene tree.
+    _timer: Gd<SceneTreeTimer>,
+    signal_future: SignalFuture<()>,
+}
+
+impl Future for Sleep {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.get_mut().signal_future).poll(cx)
+    }
+}
+
+/// Future returned by [`next_frame()`] and [`next_physics_frame()`].
+pub struct NextFrame {
+    signal_future: SignalFuture<()>,
+}
+
+impl Future for NextFrame {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.get_mut().signal_future).poll(cx)
+    }
+}
+
+/// Future returned by [`timeout()`].
+pub struct Timeout<F: Future> {
+    future: Pin<Box<F>>,
+    sleep: Sleep,
+}
+
+impl<F: Future> Future for Timeout<F> {
+    type Output = Result<F::Output, TimeoutError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+
+        // Poll the inner future first, so that it wins if both are ready in the same frame.
+        if let Poll::Ready(output) = this.future.as_mut().poll(cx) {
+            return Poll::Ready(Ok(output));
+        }
+
+        Pin::new(&mut this.sleep)
+            .poll(cx)
+            .map(|()| Err(TimeoutError))
+    }
+}
+
+/// Error returned by [`timeout()`] if the future did not complete in time.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct TimeoutError;
+
+impl Display for TimeoutError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "The future did not complete before the timeout elapsed")
+    }
+}
+
+impl std::error::Error for TimeoutError {}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Helpers
+
+fn scene_tree(function: &str) -> Gd<SceneTree> {
+    Engine::singleton()
+        .get_main_loop()
+        .and_then(|main_loop| main_loop.try_cast::<SceneTree>().ok())
+        .unwrap_or_else(|| {
+            panic!("godot::task::{function}() requires the main loop to be a SceneTree")
+        })
+}
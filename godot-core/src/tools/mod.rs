@@ -11,9 +11,14 @@
 //! or better integrated with Rust.
 
 mod gfile;
+mod node_pool;
 mod save_load;
 mod translate;
+mod wire_variant;
 
 pub use gfile::*;
+pub(crate) use node_pool::release_if_pooled;
+pub use node_pool::{NodePool, Poolable};
 pub use save_load::*;
 pub use translate::*;
+pub use wire_variant::*;
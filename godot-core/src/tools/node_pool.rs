@@ -0,0 +1,319 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::{Rc, Weak};
+
+use crate::classes::Node;
+use crate::godot_warn;
+use crate::obj::{bounds, Base, Bounds, Gd, GodotClass, Inherits, InstanceId, NewAlloc};
+
+/// User class that can be recycled by a [`NodePool`].
+///
+/// # Example
+/// ```no_run
+/// # use godot::prelude::*;
+/// use godot::tools::Poolable;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node2D)]
+/// struct Bullet {
+///     velocity: Vector2,
+///     base: Base<Node2D>,
+/// }
+///
+/// impl Poolable for Bullet {
+///     fn reset(&mut self) {
+///         self.velocity = Vector2::ZERO;
+///         self.base_mut().set_position(Vector2::ZERO);
+///     }
+/// }
+/// ```
+pub trait Poolable: GodotClass + Inherits<Node> + Bounds<Declarer = bounds::DeclUser> {
+    /// Called when an instance is returned to the pool, to bring it back into a fresh state.
+    ///
+    /// At this point, the node has already been removed from its parent.
+    fn reset(&mut self);
+}
+
+/// Recycles instances of a Rust-defined node class, to avoid allocating and freeing them repeatedly.
+///
+/// Creating nodes and adding them to the scene tree is relatively expensive. For objects that are spawned and despawned in large numbers
+/// (projectiles, particles, enemies, ...), a pool keeps released nodes around and hands them out again.
+///
+/// Nodes are created up front or on demand, and are _idle_ while stored in the pool: idle nodes are not in the scene tree and thus don't
+/// receive process callbacks. [`acquire()`][Self::acquire] hands out a detached node, [`acquire_child()`][Self::acquire_child] adds it to a
+/// parent. Once no longer needed, [`release()`][Self::release] detaches the node, calls [`Poolable::reset()`] and stores it for reuse.
+///
+/// # Interaction with `free()`
+/// Calling [`Gd::free()`] on a node that belongs to a pool releases it back into the pool, instead of destroying it. This makes it safe to
+/// pass pooled nodes to code that frees them. Calling `free()` on a node that is already idle has no effect apart from a warning.
+///
+/// Freeing through other means (`queue_free()`, freeing a parent node, GDScript `free()`) cannot be intercepted and destroys the node.
+/// The pool forgets about such nodes once it needs to create a new node, or when [`idle_count()`][Self::idle_count] or
+/// [`total_count()`][Self::total_count] are queried.
+///
+/// When the pool is dropped, idle nodes are destroyed. Nodes that are currently handed out are no longer associated with the pool and
+/// follow regular ownership rules (e.g. they're freed with their parent).
+///
+/// # Example
+/// ```no_run
+/// # use godot::prelude::*;
+/// # use godot::tools::{NodePool, Poolable};
+/// # #[derive(GodotClass)] #[class(init, base=Node2D)] struct Bullet { base: Base<Node2D> }
+/// # impl Poolable for Bullet { fn reset(&mut self) {} }
+/// #[derive(GodotClass)]
+/// #[class(base=Node2D)]
+/// struct Gun {
+///     bullets: NodePool<Bullet>,
+///     base: Base<Node2D>,
+/// }
+///
+/// #[godot_api]
+/// impl INode2D for Gun {
+///     fn init(base: Base<Node2D>) -> Self {
+///         Self {
+///             bullets: NodePool::new(256),
+///             base,
+///         }
+///     }
+/// }
+///
+/// impl Gun {
+///     fn fire(&mut self) -> Gd<Bullet> {
+///         let gun = self.to_gd();
+///         self.bullets.acquire_child(&gun)
+///     }
+///
+///     fn on_bullet_hit(&mut self, bullet: Gd<Bullet>) {
+///         self.bullets.release(bullet);
+///     }
+/// }
+/// ```
+pub struct NodePool<T: Poolable> {
+    inner: Rc<PoolInner<T>>,
+}
+
+impl<T> NodePool<T>
+where
+    T: Poolable + NewAlloc,
+{
+    /// Creates a pool with `capacity` preallocated nodes, using the class' default constructor.
+    pub fn new(capacity: usize) -> Self {
+        Self::from_create_fn(capacity, Box::new(T::new_alloc))
+    }
+}
+
+impl<T: Poolable> NodePool<T> {
+    /// Creates a pool with `capacity` preallocated nodes, each constructed by `init`.
+    ///
+    /// The function is invoked again whenever the pool runs out of idle nodes. See also [`Gd::from_init_fn()`].
+    pub fn from_init_fn<F>(capacity: usize, mut init: F) -> Self
+    where
+        F: FnMut(Base<T::Base>) -> T + 'static,
+    {
+        Self::from_create_fn(capacity, Box::new(move || Gd::from_init_fn(&mut init)))
+    }
+
+    fn from_create_fn(capacity: usize, create_fn: Box<dyn FnMut() -> Gd<T>>) -> Self {
+        let inner = Rc::new(PoolInner {
+            idle: RefCell::new(Vec::with_capacity(capacity)),
+            members: RefCell::new(HashMap::with_capacity(capacity)),
+            create_fn: RefCell::new(create_fn),
+        });
+
+        let pool = Self { inner };
+        for _ in 0..capacity {
+            let node = pool.create();
+            pool.inner.push_idle(node);
+        }
+
+        pool
+    }
+
+    /// Hands out an idle node, or creates a new one if none is available.
+    ///
+    /// The node is not part of the scene tree.
+    pub fn acquire(&self) -> Gd<T> {
+        loop {
+            let Some(node) = self.inner.idle.borrow_mut().pop() else {
+                break;
+            };
+
+            let id = node.instance_id_unchecked();
+            if node.is_instance_valid() {
+                self.inner.members.borrow_mut().insert(id, SlotState::InUse);
+                return node;
+            }
+
+            // Destroyed by other means than Gd::free() while idle; forget about it.
+            self.inner.members.borrow_mut().remove(&id);
+            unregister(id);
+        }
+
+        // Before growing, drop bookkeeping of handed-out nodes that have been destroyed in the meantime.
+        self.inner.prune_destroyed();
+        self.create()
+    }
+
+    /// Hands out a node like [`acquire()`][Self::acquire], and adds it as a child of `parent`.
+    pub fn acquire_child<P>(&self, parent: &Gd<P>) -> Gd<T>
+    where
+        P: Inherits<Node>,
+    {
+        let node = self.acquire();
+        parent.clone().upcast::<Node>().add_child(&node);
+        node
+    }
+
+    /// Returns a node to the pool.
+    ///
+    /// Removes the node from its parent (if any) and calls [`Poolable::reset()`].
+    ///
+    /// # Panics
+    /// If `node` does not belong to this pool, or is already idle.
+    pub fn release(&self, node: Gd<T>) {
+        self.inner.release(node);
+    }
+
+    /// Number of nodes currently stored in the pool, ready to be handed out.
+    pub fn idle_count(&self) -> usize {
+        self.inner.prune_destroyed();
+        self.inner.idle.borrow().len()
+    }
+
+    /// Number of nodes associated with this pool, both idle and handed out.
+    ///
+    /// Nodes that have been destroyed (other than through [`Gd::free()`]) are not counted.
+    pub fn total_count(&self) -> usize {
+        self.inner.prune_destroyed();
+        self.inner.members.borrow().len()
+    }
+
+    fn create(&self) -> Gd<T> {
+        let node = (self.inner.create_fn.borrow_mut())();
+        let id = node.instance_id();
+
+        self.inner.members.borrow_mut().insert(id, SlotState::InUse);
+
+        let weak: Weak<PoolInner<T>> = Rc::downgrade(&self.inner);
+        POOL_REGISTRY.with(|registry| registry.borrow_mut().insert(id, weak));
+
+        node
+    }
+}
+
+impl<T: Poolable> Drop for NodePool<T> {
+    fn drop(&mut self) {
+        for (id, _) in self.inner.members.borrow_mut().drain() {
+            unregister(id);
+        }
+
+        for node in self.inner.idle.borrow_mut().drain(..) {
+            if node.is_instance_valid() {
+                node.upcast::<Node>().free();
+            }
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+enum SlotState {
+    Idle,
+    InUse,
+}
+
+struct PoolInner<T: Poolable> {
+    idle: RefCell<Vec<Gd<T>>>,
+    members: RefCell<HashMap<InstanceId, SlotState>>,
+    create_fn: RefCell<Box<dyn FnMut() -> Gd<T>>>,
+}
+
+impl<T: Poolable> PoolInner<T> {
+    fn release(&self, mut node: Gd<T>) {
+        let id = node.instance_id();
+
+        match self.members.borrow().get(&id) {
+            Some(SlotState::InUse) => {}
+            Some(SlotState::Idle) => panic!("NodePool::release(): node {id} is already idle"),
+            None => panic!("NodePool::release(): node {id} does not belong to this pool"),
+        }
+
+        let base = node.clone().upcast::<Node>();
+        if let Some(mut parent) = base.get_parent() {
+            parent.remove_child(&base);
+        }
+
+        node.bind_mut().reset();
+        self.push_idle(node);
+    }
+
+    /// Forgets about nodes that were destroyed by other means than `Gd::free()`, e.g. `queue_free()` or together with their parent.
+    fn prune_destroyed(&self) {
+        self.idle
+            .borrow_mut()
+            .retain(|node| node.is_instance_valid());
+        self.members.borrow_mut().retain(|&id, _| {
+            let is_alive = Gd::<T>::try_from_instance_id(id).is_ok();
+            if !is_alive {
+                unregister(id);
+            }
+            is_alive
+        });
+    }
+
+    fn push_idle(&self, node: Gd<T>) {
+        let id = node.instance_id_unchecked();
+        self.members.borrow_mut().insert(id, SlotState::Idle);
+        self.idle.borrow_mut().push(node);
+    }
+}
+
+/// Type-erased access to a pool, for [`release_if_pooled()`].
+trait ReleaseById {
+    fn release_by_id(&self, instance_id: InstanceId);
+}
+
+impl<T: Poolable> ReleaseById for PoolInner<T> {
+    fn release_by_id(&self, instance_id: InstanceId) {
+        // Freeing an idle node would destroy it while the pool still holds it; keep it instead.
+        if self.members.borrow().get(&instance_id) == Some(&SlotState::Idle) {
+            godot_warn!("Gd::free(): node {instance_id} is already idle in its NodePool; ignored");
+            return;
+        }
+
+        let node = Gd::<T>::from_instance_id(instance_id);
+        self.release(node);
+    }
+}
+
+thread_local! {
+    /// Maps each pooled node to its pool. Nodes are main-thread objects, so pools are never accessed from other threads.
+    static POOL_REGISTRY: RefCell<HashMap<InstanceId, Weak<dyn ReleaseById>>> = RefCell::default();
+}
+
+fn unregister(instance_id: InstanceId) {
+    POOL_REGISTRY.with(|registry| registry.borrow_mut().remove(&instance_id));
+}
+
+/// If the object belongs to a [`NodePool`], returns it to the pool (unless already idle) and returns `true`. Used by `Gd::free()`.
+pub(crate) fn release_if_pooled(instance_id: InstanceId) -> bool {
+    let pool =
+        POOL_REGISTRY.with(|registry| registry.borrow().get(&instance_id).and_then(Weak::upgrade));
+
+    match pool {
+        Some(pool) => {
+            pool.release_by_id(instance_id);
+            true
+        }
+        None => false,
+    }
+}
@@ -0,0 +1,1195 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::error::Error;
+use std::fmt;
+
+use crate::builtin::*;
+use crate::obj::EngineEnum;
+
+/// Set in the header for 64-bit integers and floats, as well as for `real`-based types in double-precision builds.
+const FLAG_64: u32 = 1 << 16;
+
+/// Lower bits of the header, holding the variant type.
+const TYPE_MASK: u32 = 0xFF;
+
+/// Flag of the header that `real`-based types carry in this build.
+const REAL_FLAG: u32 = if cfg!(feature = "double-precision") {
+    FLAG_64
+} else {
+    0
+};
+
+/// Maximum nesting depth of arrays and dictionaries, same as Godot's `Variant::MAX_RECURSION_DEPTH`.
+const MAX_DEPTH: usize = 1024;
+
+/// Container element type information in the header of arrays and dictionaries.
+const CONTAINER_TYPE_NONE: u32 = 0;
+const CONTAINER_TYPE_BUILTIN: u32 = 1;
+const CONTAINER_TYPE_CLASS_NAME: u32 = 2;
+const CONTAINER_TYPE_SCRIPT: u32 = 3;
+
+/// Engine-independent representation of a `Variant`, which can be encoded to and decoded from Godot's binary serialization format.
+///
+/// The format is the one used by [`var_to_bytes()`][crate::global::var_to_bytes] and [`bytes_to_var()`][crate::global::bytes_to_var]
+/// in GDScript, as well as [`PackedByteArray::encode_var()`] and by Godot's high-level multiplayer API. See also the
+/// [Godot docs](https://docs.godotengine.org/en/stable/tutorials/io/binary_serialization_api.html).
+///
+/// Unlike the engine functions, `WireVariant` works without a running Godot instance. This allows to exchange data with Godot
+/// from dedicated server tools, external processes or unit tests. All builtin types except `Object`, `Callable` and `Signal`
+/// are supported; since those cannot be meaningfully serialized without the engine, they result in a [`WireError`].
+///
+/// Math types use [`real`] components and are encoded as 32-bit or 64-bit floats, depending on the `double-precision` feature,
+/// matching the corresponding Godot build. When decoding, both widths are accepted.
+///
+/// # Typed containers
+/// Element types of typed arrays and dictionaries are not represented: when decoding, they are skipped; when encoding, containers
+/// are always untyped. Godot will accept the resulting data, but produce untyped containers.
+///
+/// # Example
+/// ```no_run
+/// use godot::tools::WireVariant;
+///
+/// let value = WireVariant::Array(vec![
+///     WireVariant::Int(42),
+///     WireVariant::String("hello".to_string()),
+/// ]);
+///
+/// let bytes = value.encode();
+/// let decoded = WireVariant::decode(&bytes).expect("valid data");
+/// assert_eq!(decoded, value);
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub enum WireVariant {
+    Nil,
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    String(String),
+    Vector2(Vector2),
+    Vector2i(Vector2i),
+    Rect2(Rect2),
+    Rect2i(Rect2i),
+    Vector3(Vector3),
+    Vector3i(Vector3i),
+    Transform2D(Transform2D),
+    Vector4(Vector4),
+    Vector4i(Vector4i),
+    Plane(Plane),
+    Quaternion(Quaternion),
+    Aabb(Aabb),
+    Basis(Basis),
+    Transform3D(Transform3D),
+    Projection(Projection),
+    Color(Color),
+    StringName(String),
+    /// Node path in its textual form, e.g. `"/root/Node:position:x"`.
+    NodePath(String),
+    Rid(Rid),
+    /// Key-value pairs in insertion order.
+    Dictionary(Vec<(WireVariant, WireVariant)>),
+    Array(Vec<WireVariant>),
+    PackedByteArray(Vec<u8>),
+    PackedInt32Array(Vec<i32>),
+    PackedInt64Array(Vec<i64>),
+    PackedFloat32Array(Vec<f32>),
+    PackedFloat64Array(Vec<f64>),
+    PackedStringArray(Vec<String>),
+    PackedVector2Array(Vec<Vector2>),
+    PackedVector3Array(Vec<Vector3>),
+    PackedColorArray(Vec<Color>),
+    /// Only understood by Godot 4.3 and later.
+    PackedVector4Array(Vec<Vector4>),
+}
+
+impl WireVariant {
+    /// Encodes the value in Godot's binary serialization format.
+    ///
+    /// The result is equivalent to GDScript's `var_to_bytes()`, and can be decoded with `bytes_to_var()`.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        self.encode_into(&mut bytes);
+        bytes
+    }
+
+    /// Like [`encode()`][Self::encode], but appends to an existing buffer.
+    pub fn encode_into(&self, out: &mut Vec<u8>) {
+        Writer { out }.write_variant(self);
+    }
+
+    /// Decodes a value from Godot's binary serialization format.
+    ///
+    /// Fails if `bytes` is malformed, contains unsupported types, or has trailing data after the value.
+    pub fn decode(bytes: &[u8]) -> Result<Self, WireError> {
+        let (value, len) = Self::decode_prefix(bytes)?;
+        if len != bytes.len() {
+            return Err(WireError::new(format!(
+                "{} trailing bytes after encoded value",
+                bytes.len() - len
+            )));
+        }
+
+        Ok(value)
+    }
+
+    /// Decodes a value from the beginning of `bytes`, returning it together with the number of bytes consumed.
+    ///
+    /// Useful for streams containing multiple consecutive values.
+    pub fn decode_prefix(bytes: &[u8]) -> Result<(Self, usize), WireError> {
+        let mut reader = Reader { bytes, pos: 0 };
+        let value = reader.read_variant(0)?;
+
+        Ok((value, reader.pos))
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Conversion from/to Variant (requires engine)
+
+impl WireVariant {
+    /// Converts an engine `Variant` into its engine-independent representation.
+    ///
+    /// Fails for objects, callables and signals. Typed arrays and dictionaries are converted to untyped ones.
+    pub fn from_variant(variant: &Variant) -> Result<Self, WireError> {
+        let value = match variant.get_type() {
+            VariantType::NIL => Self::Nil,
+            VariantType::BOOL => Self::Bool(variant.to()),
+            VariantType::INT => Self::Int(variant.to()),
+            VariantType::FLOAT => Self::Float(variant.to()),
+            VariantType::STRING => Self::String(variant.to::<GString>().to_string()),
+            VariantType::VECTOR2 => Self::Vector2(variant.to()),
+            VariantType::VECTOR2I => Self::Vector2i(variant.to()),
+            VariantType::RECT2 => Self::Rect2(variant.to()),
+            VariantType::RECT2I => Self::Rect2i(variant.to()),
+            VariantType::VECTOR3 => Self::Vector3(variant.to()),
+            VariantType::VECTOR3I => Self::Vector3i(variant.to()),
+            VariantType::TRANSFORM2D => Self::Transform2D(variant.to()),
+            VariantType::VECTOR4 => Self::Vector4(variant.to()),
+            VariantType::VECTOR4I => Self::Vector4i(variant.to()),
+            VariantType::PLANE => Self::Plane(variant.to()),
+            VariantType::QUATERNION => Self::Quaternion(variant.to()),
+            VariantType::AABB => Self::Aabb(variant.to()),
+            VariantType::BASIS => Self::Basis(variant.to()),
+            VariantType::TRANSFORM3D => Self::Transform3D(variant.to()),
+            VariantType::PROJECTION => Self::Projection(variant.to()),
+            VariantType::COLOR => Self::Color(variant.to()),
+            VariantType::STRING_NAME => Self::StringName(variant.to::<StringName>().to_string()),
+            VariantType::NODE_PATH => Self::NodePath(variant.to::<NodePath>().to_string()),
+            VariantType::RID => Self::Rid(variant.to()),
+            VariantType::DICTIONARY => {
+                // SAFETY: type is checked, and the dictionary is only read. Typed dictionaries are converted like untyped ones.
+                let dictionary = unsafe { VarDictionary::from_variant_unchecked(variant) };
+                let entries = dictionary
+                    .iter_shared()
+                    .map(|(key, value)| {
+                        Ok((Self::from_variant(&key)?, Self::from_variant(&value)?))
+                    })
+                    .collect::<Result<_, WireError>>()?;
+
+                Self::Dictionary(entries)
+            }
+            VariantType::ARRAY => {
+                // SAFETY: type is checked, and the array is only read. Typed arrays are converted like untyped ones.
+                let array = unsafe { VariantArray::from_variant_unchecked(variant) };
+                let elements = array
+                    .iter_shared()
+                    .map(|element| Self::from_variant(&element))
+                    .collect::<Result<_, WireError>>()?;
+
+                Self::Array(elements)
+            }
+            VariantType::PACKED_BYTE_ARRAY => {
+                Self::PackedByteArray(variant.to::<PackedByteArray>().to_vec())
+            }
+            VariantType::PACKED_INT32_ARRAY => {
+                Self::PackedInt32Array(variant.to::<PackedInt32Array>().to_vec())
+            }
+            VariantType::PACKED_INT64_ARRAY => {
+                Self::PackedInt64Array(variant.to::<PackedInt64Array>().to_vec())
+            }
+            VariantType::PACKED_FLOAT32_ARRAY => {
+                Self::PackedFloat32Array(variant.to::<PackedFloat32Array>().to_vec())
+            }
+            VariantType::PACKED_FLOAT64_ARRAY => {
+                Self::PackedFloat64Array(variant.to::<PackedFloat64Array>().to_vec())
+            }
+            VariantType::PACKED_STRING_ARRAY => Self::PackedStringArray(
+                variant
+                    .to::<PackedStringArray>()
+                    .as_slice()
+                    .iter()
+                    .map(GString::to_string)
+                    .collect(),
+            ),
+            VariantType::PACKED_VECTOR2_ARRAY => {
+                Self::PackedVector2Array(variant.to::<PackedVector2Array>().to_vec())
+            }
+            VariantType::PACKED_VECTOR3_ARRAY => {
+                Self::PackedVector3Array(variant.to::<PackedVector3Array>().to_vec())
+            }
+            VariantType::PACKED_COLOR_ARRAY => {
+                Self::PackedColorArray(variant.to::<PackedColorArray>().to_vec())
+            }
+            #[cfg(since_api = "4.3")]
+            VariantType::PACKED_VECTOR4_ARRAY => {
+                Self::PackedVector4Array(variant.to::<PackedVector4Array>().to_vec())
+            }
+            other => return Err(WireError::unsupported(other)),
+        };
+
+        Ok(value)
+    }
+
+    /// Converts the value into an engine `Variant`.
+    ///
+    /// # Panics
+    /// If the value is a [`PackedVector4Array`][Self::PackedVector4Array] and the API version is below Godot 4.3.
+    pub fn to_variant(&self) -> Variant {
+        use crate::meta::ToGodot;
+
+        match self {
+            Self::Nil => Variant::nil(),
+            Self::Bool(value) => value.to_variant(),
+            Self::Int(value) => value.to_variant(),
+            Self::Float(value) => value.to_variant(),
+            Self::String(value) => GString::from(value.as_str()).to_variant(),
+            Self::Vector2(value) => value.to_variant(),
+            Self::Vector2i(value) => value.to_variant(),
+            Self::Rect2(value) => value.to_variant(),
+            Self::Rect2i(value) => value.to_variant(),
+            Self::Vector3(value) => value.to_variant(),
+            Self::Vector3i(value) => value.to_variant(),
+            Self::Transform2D(value) => value.to_variant(),
+            Self::Vector4(value) => value.to_variant(),
+            Self::Vector4i(value) => value.to_variant(),
+            Self::Plane(value) => value.to_variant(),
+            Self::Quaternion(value) => value.to_variant(),
+            Self::Aabb(value) => value.to_variant(),
+            Self::Basis(value) => value.to_variant(),
+            Self::Transform3D(value) => value.to_variant(),
+            Self::Projection(value) => value.to_variant(),
+            Self::Color(value) => value.to_variant(),
+            Self::StringName(value) => StringName::from(value.as_str()).to_variant(),
+            Self::NodePath(value) => NodePath::from(value.as_str()).to_variant(),
+            Self::Rid(value) => value.to_variant(),
+            Self::Dictionary(entries) => entries
+                .iter()
+                .map(|(key, value)| (key.to_variant(), value.to_variant()))
+                .collect::<VarDictionary>()
+                .to_variant(),
+            Self::Array(elements) => elements
+                .iter()
+                .map(Self::to_variant)
+                .collect::<VariantArray>()
+                .to_variant(),
+            Self::PackedByteArray(values) => PackedByteArray::from(values.as_slice()).to_variant(),
+            Self::PackedInt32Array(values) => {
+                PackedInt32Array::from(values.as_slice()).to_variant()
+            }
+            Self::PackedInt64Array(values) => {
+                PackedInt64Array::from(values.as_slice()).to_variant()
+            }
+            Self::PackedFloat32Array(values) => {
+                PackedFloat32Array::from(values.as_slice()).to_variant()
+            }
+            Self::PackedFloat64Array(values) => {
+                PackedFloat64Array::from(values.as_slice()).to_variant()
+            }
+            Self::PackedStringArray(values) => values
+                .iter()
+                .map(|value| GString::from(value.as_str()))
+                .collect::<PackedStringArray>()
+                .to_variant(),
+            Self::PackedVector2Array(values) => {
+                PackedVector2Array::from(values.as_slice()).to_variant()
+            }
+            Self::PackedVector3Array(values) => {
+                PackedVector3Array::from(values.as_slice()).to_variant()
+            }
+            Self::PackedColorArray(values) => {
+                PackedColorArray::from(values.as_slice()).to_variant()
+            }
+            #[cfg(since_api = "4.3")]
+            Self::PackedVector4Array(values) => {
+                PackedVector4Array::from(values.as_slice()).to_variant()
+            }
+            #[cfg(before_api = "4.3")]
+            Self::PackedVector4Array(_) => {
+                panic!("PackedVector4Array requires Godot 4.3 or later")
+            }
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Error
+
+/// Error when decoding Godot's binary serialization format, or converting from a `Variant` with unsupported type.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct WireError {
+    message: String,
+}
+
+impl WireError {
+    fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+
+    fn unsupported(variant_type: VariantType) -> Self {
+        Self::new(format!(
+            "variant type {variant_type:?} is not supported; objects, callables and signals cannot be serialized"
+        ))
+    }
+}
+
+impl fmt::Display for WireError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for WireError {}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Encoding
+
+/// Type IDs as they appear in the header.
+///
+/// Kept independent of `VariantType`, so that the format doesn't depend on the API version (e.g. `PACKED_VECTOR4_ARRAY`).
+mod ord {
+    pub const NIL: u32 = 0;
+    pub const BOOL: u32 = 1;
+    pub const INT: u32 = 2;
+    pub const FLOAT: u32 = 3;
+    pub const STRING: u32 = 4;
+    pub const VECTOR2: u32 = 5;
+    pub const VECTOR2I: u32 = 6;
+    pub const RECT2: u32 = 7;
+    pub const RECT2I: u32 = 8;
+    pub const VECTOR3: u32 = 9;
+    pub const VECTOR3I: u32 = 10;
+    pub const TRANSFORM2D: u32 = 11;
+    pub const VECTOR4: u32 = 12;
+    pub const VECTOR4I: u32 = 13;
+    pub const PLANE: u32 = 14;
+    pub const QUATERNION: u32 = 15;
+    pub const AABB: u32 = 16;
+    pub const BASIS: u32 = 17;
+    pub const TRANSFORM3D: u32 = 18;
+    pub const PROJECTION: u32 = 19;
+    pub const COLOR: u32 = 20;
+    pub const STRING_NAME: u32 = 21;
+    pub const NODE_PATH: u32 = 22;
+    pub const RID: u32 = 23;
+    pub const OBJECT: u32 = 24;
+    pub const CALLABLE: u32 = 25;
+    pub const SIGNAL: u32 = 26;
+    pub const DICTIONARY: u32 = 27;
+    pub const ARRAY: u32 = 28;
+    pub const PACKED_BYTE_ARRAY: u32 = 29;
+    pub const PACKED_INT32_ARRAY: u32 = 30;
+    pub const PACKED_INT64_ARRAY: u32 = 31;
+    pub const PACKED_FLOAT32_ARRAY: u32 = 32;
+    pub const PACKED_FLOAT64_ARRAY: u32 = 33;
+    pub const PACKED_STRING_ARRAY: u32 = 34;
+    pub const PACKED_VECTOR2_ARRAY: u32 = 35;
+    pub const PACKED_VECTOR3_ARRAY: u32 = 36;
+    pub const PACKED_COLOR_ARRAY: u32 = 37;
+    pub const PACKED_VECTOR4_ARRAY: u32 = 38;
+}
+
+struct Writer<'a> {
+    out: &'a mut Vec<u8>,
+}
+
+impl Writer<'_> {
+    fn write_variant(&mut self, value: &WireVariant) {
+        use WireVariant as W;
+
+        match value {
+            W::Nil => self.header(ord::NIL, 0),
+            W::Bool(value) => {
+                self.header(ord::BOOL, 0);
+                self.u32(u32::from(*value));
+            }
+            W::Int(value) => match i32::try_from(*value) {
+                Ok(value) => {
+                    self.header(ord::INT, 0);
+                    self.i32(value);
+                }
+                Err(_) => {
+                    self.header(ord::INT, FLAG_64);
+                    self.i64(*value);
+                }
+            },
+            W::Float(value) => {
+                let single = *value as f32;
+                if f64::from(single) == *value {
+                    self.header(ord::FLOAT, 0);
+                    self.f32(single);
+                } else {
+                    self.header(ord::FLOAT, FLAG_64);
+                    self.f64(*value);
+                }
+            }
+            W::String(value) => {
+                self.header(ord::STRING, 0);
+                self.string(value);
+            }
+            W::Vector2(value) => {
+                self.header(ord::VECTOR2, REAL_FLAG);
+                self.vector2(*value);
+            }
+            W::Vector2i(value) => {
+                self.header(ord::VECTOR2I, 0);
+                self.i32(value.x);
+                self.i32(value.y);
+            }
+            W::Rect2(value) => {
+                self.header(ord::RECT2, REAL_FLAG);
+                self.vector2(value.position);
+                self.vector2(value.size);
+            }
+            W::Rect2i(value) => {
+                self.header(ord::RECT2I, 0);
+                for component in [value.position, value.size] {
+                    self.i32(component.x);
+                    self.i32(component.y);
+                }
+            }
+            W::Vector3(value) => {
+                self.header(ord::VECTOR3, REAL_FLAG);
+                self.vector3(*value);
+            }
+            W::Vector3i(value) => {
+                self.header(ord::VECTOR3I, 0);
+                self.i32(value.x);
+                self.i32(value.y);
+                self.i32(value.z);
+            }
+            W::Transform2D(value) => {
+                self.header(ord::TRANSFORM2D, REAL_FLAG);
+                self.vector2(value.a);
+                self.vector2(value.b);
+                self.vector2(value.origin);
+            }
+            W::Vector4(value) => {
+                self.header(ord::VECTOR4, REAL_FLAG);
+                self.vector4(*value);
+            }
+            W::Vector4i(value) => {
+                self.header(ord::VECTOR4I, 0);
+                self.i32(value.x);
+                self.i32(value.y);
+                self.i32(value.z);
+                self.i32(value.w);
+            }
+            W::Plane(value) => {
+                self.header(ord::PLANE, REAL_FLAG);
+                self.vector3(value.normal);
+                self.real(value.d);
+            }
+            W::Quaternion(value) => {
+                self.header(ord::QUATERNION, REAL_FLAG);
+                self.real(value.x);
+                self.real(value.y);
+                self.real(value.z);
+                self.real(value.w);
+            }
+            W::Aabb(value) => {
+                self.header(ord::AABB, REAL_FLAG);
+                self.vector3(value.position);
+                self.vector3(value.size);
+            }
+            W::Basis(value) => {
+                self.header(ord::BASIS, REAL_FLAG);
+                self.basis(value);
+            }
+            W::Transform3D(value) => {
+                self.header(ord::TRANSFORM3D, REAL_FLAG);
+                self.basis(&value.basis);
+                self.vector3(value.origin);
+            }
+            W::Projection(value) => {
+                self.header(ord::PROJECTION, REAL_FLAG);
+                for column in value.cols {
+                    self.vector4(column);
+                }
+            }
+            W::Color(value) => {
+                self.header(ord::COLOR, 0);
+                self.color(*value);
+            }
+            W::StringName(value) => {
+                self.header(ord::STRING_NAME, 0);
+                self.string(value);
+            }
+            W::NodePath(value) => {
+                self.header(ord::NODE_PATH, 0);
+                self.node_path(value);
+            }
+            W::Rid(value) => {
+                self.header(ord::RID, 0);
+                self.u64(value.to_u64());
+            }
+            W::Dictionary(entries) => {
+                self.header(ord::DICTIONARY, 0);
+                self.len(entries.len());
+                for (key, value) in entries {
+                    self.write_variant(key);
+                    self.write_variant(value);
+                }
+            }
+            W::Array(elements) => {
+                self.header(ord::ARRAY, 0);
+                self.len(elements.len());
+                for element in elements {
+                    self.write_variant(element);
+                }
+            }
+            W::PackedByteArray(values) => {
+                self.header(ord::PACKED_BYTE_ARRAY, 0);
+                self.len(values.len());
+                self.out.extend_from_slice(values);
+                self.pad(values.len());
+            }
+            W::PackedInt32Array(values) => {
+                self.header(ord::PACKED_INT32_ARRAY, 0);
+                self.len(values.len());
+                values.iter().for_each(|value| self.i32(*value));
+            }
+            W::PackedInt64Array(values) => {
+                self.header(ord::PACKED_INT64_ARRAY, 0);
+                self.len(values.len());
+                values.iter().for_each(|value| self.i64(*value));
+            }
+            W::PackedFloat32Array(values) => {
+                self.header(ord::PACKED_FLOAT32_ARRAY, 0);
+                self.len(values.len());
+                values.iter().for_each(|value| self.f32(*value));
+            }
+            W::PackedFloat64Array(values) => {
+                self.header(ord::PACKED_FLOAT64_ARRAY, 0);
+                self.len(values.len());
+                values.iter().for_each(|value| self.f64(*value));
+            }
+            W::PackedStringArray(values) => {
+                self.header(ord::PACKED_STRING_ARRAY, 0);
+                self.len(values.len());
+                values.iter().for_each(|value| self.string(value));
+            }
+            W::PackedVector2Array(values) => {
+                self.header(ord::PACKED_VECTOR2_ARRAY, REAL_FLAG);
+                self.len(values.len());
+                values.iter().for_each(|value| self.vector2(*value));
+            }
+            W::PackedVector3Array(values) => {
+                self.header(ord::PACKED_VECTOR3_ARRAY, REAL_FLAG);
+                self.len(values.len());
+                values.iter().for_each(|value| self.vector3(*value));
+            }
+            W::PackedColorArray(values) => {
+                self.header(ord::PACKED_COLOR_ARRAY, 0);
+                self.len(values.len());
+                values.iter().for_each(|value| self.color(*value));
+            }
+            W::PackedVector4Array(values) => {
+                self.header(ord::PACKED_VECTOR4_ARRAY, REAL_FLAG);
+                self.len(values.len());
+                values.iter().for_each(|value| self.vector4(*value));
+            }
+        }
+    }
+
+    fn header(&mut self, type_ord: u32, flags: u32) {
+        self.u32(type_ord | flags);
+    }
+
+    fn len(&mut self, len: usize) {
+        let len = u32::try_from(len).expect("container too large to be encoded");
+        self.u32(len);
+    }
+
+    fn u32(&mut self, value: u32) {
+        self.out.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn i32(&mut self, value: i32) {
+        self.out.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn u64(&mut self, value: u64) {
+        self.out.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn i64(&mut self, value: i64) {
+        self.out.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn f32(&mut self, value: f32) {
+        self.out.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn f64(&mut self, value: f64) {
+        self.out.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn real(&mut self, value: real) {
+        self.out.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn vector2(&mut self, value: Vector2) {
+        self.real(value.x);
+        self.real(value.y);
+    }
+
+    fn vector3(&mut self, value: Vector3) {
+        self.real(value.x);
+        self.real(value.y);
+        self.real(value.z);
+    }
+
+    fn vector4(&mut self, value: Vector4) {
+        self.real(value.x);
+        self.real(value.y);
+        self.real(value.z);
+        self.real(value.w);
+    }
+
+    fn basis(&mut self, value: &Basis) {
+        for row in value.rows {
+            self.vector3(row);
+        }
+    }
+
+    fn color(&mut self, value: Color) {
+        self.f32(value.r);
+        self.f32(value.g);
+        self.f32(value.b);
+        self.f32(value.a);
+    }
+
+    /// Writes UTF-8 length, bytes and zero padding to the next multiple of 4.
+    fn string(&mut self, value: &str) {
+        self.len(value.len());
+        self.out.extend_from_slice(value.as_bytes());
+        self.pad(value.len());
+    }
+
+    fn node_path(&mut self, path: &str) {
+        let (absolute, names, subnames) = split_node_path(path);
+
+        // The highest bit marks the "new" format (since Godot 3), which is the only one still written by the engine.
+        self.u32(names.len() as u32 | 0x8000_0000);
+        self.len(subnames.len());
+        self.u32(u32::from(absolute));
+
+        for name in names.iter().chain(subnames.iter()) {
+            self.string(name);
+        }
+    }
+
+    fn pad(&mut self, len: usize) {
+        let padding = (4 - len % 4) % 4;
+        self.out.extend(std::iter::repeat_n(0, padding));
+    }
+}
+
+/// Splits a node path into absolute flag, names and subnames, e.g. `"/root/Node:position:x"` -> `(true, [root, Node], [position, x])`.
+fn split_node_path(path: &str) -> (bool, Vec<&str>, Vec<&str>) {
+    let absolute = path.starts_with('/');
+    let (names, subnames) = match path.split_once(':') {
+        Some((names, subnames)) => (names, Some(subnames)),
+        None => (path, None),
+    };
+
+    let names = names.split('/').filter(|name| !name.is_empty()).collect();
+    let subnames = subnames
+        .map(|subnames| {
+            subnames
+                .split(':')
+                .filter(|name| !name.is_empty())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    (absolute, names, subnames)
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Decoding
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn read_variant(&mut self, depth: usize) -> Result<WireVariant, WireError> {
+        if depth > MAX_DEPTH {
+            return Err(WireError::new(format!(
+                "containers nested deeper than {MAX_DEPTH} levels"
+            )));
+        }
+
+        // Containers are handled here and all other types in read_value(), to keep the stack frames of recursion small.
+        let header = self.u32()?;
+        match header & TYPE_MASK {
+            ord::DICTIONARY => self.read_dictionary(header, depth),
+            ord::ARRAY => self.read_array(header, depth),
+            _ => self.read_value(header),
+        }
+    }
+
+    fn read_dictionary(&mut self, header: u32, depth: usize) -> Result<WireVariant, WireError> {
+        self.container_type((header >> 16) & 0b11)?;
+        self.container_type((header >> 18) & 0b11)?;
+
+        let len = self.container_len(8)?;
+        let mut entries = Vec::with_capacity(len);
+        for _ in 0..len {
+            let key = self.read_variant(depth + 1)?;
+            let value = self.read_variant(depth + 1)?;
+            entries.push((key, value));
+        }
+
+        Ok(WireVariant::Dictionary(entries))
+    }
+
+    fn read_array(&mut self, header: u32, depth: usize) -> Result<WireVariant, WireError> {
+        self.container_type((header >> 16) & 0b11)?;
+
+        let len = self.container_len(4)?;
+        let mut elements = Vec::with_capacity(len);
+        for _ in 0..len {
+            elements.push(self.read_variant(depth + 1)?);
+        }
+
+        Ok(WireVariant::Array(elements))
+    }
+
+    /// Reads any non-container value, after its header.
+    fn read_value(&mut self, header: u32) -> Result<WireVariant, WireError> {
+        use WireVariant as W;
+
+        let is_64 = header & FLAG_64 != 0;
+        let value = match header & TYPE_MASK {
+            ord::NIL => W::Nil,
+            ord::BOOL => W::Bool(self.u32()? != 0),
+            ord::INT if is_64 => W::Int(self.i64()?),
+            ord::INT => W::Int(i64::from(self.i32()?)),
+            ord::FLOAT if is_64 => W::Float(self.f64()?),
+            ord::FLOAT => W::Float(f64::from(self.f32()?)),
+            ord::STRING => W::String(self.string()?),
+            ord::VECTOR2 => W::Vector2(self.vector2(is_64)?),
+            ord::VECTOR2I => W::Vector2i(Vector2i::new(self.i32()?, self.i32()?)),
+            ord::RECT2 => W::Rect2(Rect2 {
+                position: self.vector2(is_64)?,
+                size: self.vector2(is_64)?,
+            }),
+            ord::RECT2I => W::Rect2i(Rect2i {
+                position: Vector2i::new(self.i32()?, self.i32()?),
+                size: Vector2i::new(self.i32()?, self.i32()?),
+            }),
+            ord::VECTOR3 => W::Vector3(self.vector3(is_64)?),
+            ord::VECTOR3I => W::Vector3i(Vector3i::new(self.i32()?, self.i32()?, self.i32()?)),
+            ord::TRANSFORM2D => W::Transform2D(Transform2D::from_cols(
+                self.vector2(is_64)?,
+                self.vector2(is_64)?,
+                self.vector2(is_64)?,
+            )),
+            ord::VECTOR4 => W::Vector4(self.vector4(is_64)?),
+            ord::VECTOR4I => W::Vector4i(Vector4i::new(
+                self.i32()?,
+                self.i32()?,
+                self.i32()?,
+                self.i32()?,
+            )),
+            ord::PLANE => W::Plane(Plane {
+                normal: self.vector3(is_64)?,
+                d: self.real(is_64)?,
+            }),
+            ord::QUATERNION => W::Quaternion(Quaternion::new(
+                self.real(is_64)?,
+                self.real(is_64)?,
+                self.real(is_64)?,
+                self.real(is_64)?,
+            )),
+            ord::AABB => W::Aabb(Aabb {
+                position: self.vector3(is_64)?,
+                size: self.vector3(is_64)?,
+            }),
+            ord::BASIS => W::Basis(self.basis(is_64)?),
+            ord::TRANSFORM3D => W::Transform3D(Transform3D {
+                basis: self.basis(is_64)?,
+                origin: self.vector3(is_64)?,
+            }),
+            ord::PROJECTION => W::Projection(Projection {
+                cols: [
+                    self.vector4(is_64)?,
+                    self.vector4(is_64)?,
+                    self.vector4(is_64)?,
+                    self.vector4(is_64)?,
+                ],
+            }),
+            ord::COLOR => W::Color(self.color()?),
+            ord::STRING_NAME => W::StringName(self.string()?),
+            ord::NODE_PATH => W::NodePath(self.node_path()?),
+            ord::RID => W::Rid(Rid::new(self.u64()?)),
+            ord::PACKED_BYTE_ARRAY => {
+                let len = self.u32()? as usize;
+                let bytes = self.take(len)?.to_vec();
+                self.skip_padding(len)?;
+                W::PackedByteArray(bytes)
+            }
+            ord::PACKED_INT32_ARRAY => W::PackedInt32Array(self.packed(4, Self::i32)?),
+            ord::PACKED_INT64_ARRAY => W::PackedInt64Array(self.packed(8, Self::i64)?),
+            ord::PACKED_FLOAT32_ARRAY => W::PackedFloat32Array(self.packed(4, Self::f32)?),
+            ord::PACKED_FLOAT64_ARRAY => W::PackedFloat64Array(self.packed(8, Self::f64)?),
+            ord::PACKED_STRING_ARRAY => W::PackedStringArray(self.packed(4, Self::string)?),
+            ord::PACKED_VECTOR2_ARRAY => {
+                W::PackedVector2Array(self.packed(real_size(is_64) * 2, |r| r.vector2(is_64))?)
+            }
+            ord::PACKED_VECTOR3_ARRAY => {
+                W::PackedVector3Array(self.packed(real_size(is_64) * 3, |r| r.vector3(is_64))?)
+            }
+            ord::PACKED_COLOR_ARRAY => W::PackedColorArray(self.packed(16, Self::color)?),
+            ord::PACKED_VECTOR4_ARRAY => {
+                W::PackedVector4Array(self.packed(real_size(is_64) * 4, |r| r.vector4(is_64))?)
+            }
+            ord::OBJECT | ord::CALLABLE | ord::SIGNAL => {
+                return Err(WireError::unsupported(VariantType::from_ord(
+                    (header & TYPE_MASK) as i32,
+                )));
+            }
+            other => return Err(WireError::new(format!("unknown variant type {other}"))),
+        };
+
+        Ok(value)
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8], WireError> {
+        let end = self
+            .pos
+            .checked_add(len)
+            .filter(|end| *end <= self.bytes.len())
+            .ok_or_else(|| {
+                WireError::new(format!(
+                    "unexpected end of data: need {len} bytes at offset {}, but only {} are left",
+                    self.pos,
+                    self.bytes.len() - self.pos
+                ))
+            })?;
+
+        let slice = &self.bytes[self.pos..end];
+        self.pos = end;
+        Ok(slice)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], WireError> {
+        let slice = self.take(N)?;
+        Ok(slice.try_into().expect("slice has length N"))
+    }
+
+    fn u32(&mut self) -> Result<u32, WireError> {
+        self.array().map(u32::from_le_bytes)
+    }
+
+    fn i32(&mut self) -> Result<i32, WireError> {
+        self.array().map(i32::from_le_bytes)
+    }
+
+    fn u64(&mut self) -> Result<u64, WireError> {
+        self.array().map(u64::from_le_bytes)
+    }
+
+    fn i64(&mut self) -> Result<i64, WireError> {
+        self.array().map(i64::from_le_bytes)
+    }
+
+    fn f32(&mut self) -> Result<f32, WireError> {
+        self.array().map(f32::from_le_bytes)
+    }
+
+    fn f64(&mut self) -> Result<f64, WireError> {
+        self.array().map(f64::from_le_bytes)
+    }
+
+    fn real(&mut self, is_64: bool) -> Result<real, WireError> {
+        if is_64 {
+            self.f64().map(real::from_f64)
+        } else {
+            self.f32().map(real::from_f32)
+        }
+    }
+
+    fn vector2(&mut self, is_64: bool) -> Result<Vector2, WireError> {
+        Ok(Vector2::new(self.real(is_64)?, self.real(is_64)?))
+    }
+
+    fn vector3(&mut self, is_64: bool) -> Result<Vector3, WireError> {
+        Ok(Vector3::new(
+            self.real(is_64)?,
+            self.real(is_64)?,
+            self.real(is_64)?,
+        ))
+    }
+
+    fn vector4(&mut self, is_64: bool) -> Result<Vector4, WireError> {
+        Ok(Vector4::new(
+            self.real(is_64)?,
+            self.real(is_64)?,
+            self.real(is_64)?,
+            self.real(is_64)?,
+        ))
+    }
+
+    fn basis(&mut self, is_64: bool) -> Result<Basis, WireError> {
+        Ok(Basis {
+            rows: [
+                self.vector3(is_64)?,
+                self.vector3(is_64)?,
+                self.vector3(is_64)?,
+            ],
+        })
+    }
+
+    fn color(&mut self) -> Result<Color, WireError> {
+        Ok(Color::from_rgba(
+            self.f32()?,
+            self.f32()?,
+            self.f32()?,
+            self.f32()?,
+        ))
+    }
+
+    fn string(&mut self) -> Result<String, WireError> {
+        let len = self.u32()? as usize;
+        let bytes = self.take(len)?;
+        let string = std::str::from_utf8(bytes)
+            .map_err(|err| WireError::new(format!("invalid UTF-8 in string: {err}")))?
+            .to_string();
+
+        self.skip_padding(len)?;
+        Ok(string)
+    }
+
+    fn node_path(&mut self) -> Result<String, WireError> {
+        let name_count = self.u32()?;
+        if name_count & 0x8000_0000 == 0 {
+            return Err(WireError::new(
+                "node path in obsolete Godot 2 format is not supported",
+            ));
+        }
+
+        let name_count = name_count & 0x7FFF_FFFF;
+        let mut subname_count = self.u32()?;
+        let flags = self.u32()?;
+        if flags & 2 != 0 {
+            // Obsolete format, with the property stored separately from the subnames.
+            subname_count += 1;
+        }
+
+        let mut path = String::new();
+        if flags & 1 != 0 {
+            path.push('/');
+        }
+
+        for i in 0..name_count {
+            if i > 0 {
+                path.push('/');
+            }
+            path.push_str(&self.string()?);
+        }
+
+        for _ in 0..subname_count {
+            path.push(':');
+            path.push_str(&self.string()?);
+        }
+
+        Ok(path)
+    }
+
+    /// Skips type information of typed arrays/dictionaries, given the 2-bit kind from the header.
+    fn container_type(&mut self, kind: u32) -> Result<(), WireError> {
+        match kind {
+            CONTAINER_TYPE_NONE => {}
+            CONTAINER_TYPE_BUILTIN => {
+                self.u32()?;
+            }
+            CONTAINER_TYPE_CLASS_NAME | CONTAINER_TYPE_SCRIPT => {
+                self.string()?;
+            }
+            _ => unreachable!("2-bit container type"),
+        }
+
+        Ok(())
+    }
+
+    /// Reads a container length, and validates it against the remaining bytes (`min_element_size` each).
+    ///
+    /// This avoids huge allocations for malformed input.
+    fn container_len(&mut self, min_element_size: usize) -> Result<usize, WireError> {
+        let len = (self.u32()? & 0x7FFF_FFFF) as usize;
+        let remaining = self.bytes.len() - self.pos;
+        if len.saturating_mul(min_element_size) > remaining {
+            return Err(WireError::new(format!(
+                "unexpected end of data: {len} elements announced, but only {remaining} bytes are left"
+            )));
+        }
+
+        Ok(len)
+    }
+
+    fn packed<T>(
+        &mut self,
+        min_element_size: usize,
+        mut read_element: impl FnMut(&mut Self) -> Result<T, WireError>,
+    ) -> Result<Vec<T>, WireError> {
+        let len = self.container_len(min_element_size)?;
+        (0..len).map(|_| read_element(self)).collect()
+    }
+
+    fn skip_padding(&mut self, len: usize) -> Result<(), WireError> {
+        let padding = (4 - len % 4) % 4;
+        self.take(padding).map(|_| ())
+    }
+}
+
+fn real_size(is_64: bool) -> usize {
+    if is_64 {
+        8
+    } else {
+        4
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Tests
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn roundtrip(value: WireVariant) {
+        let bytes = value.encode();
+        assert_eq!(bytes.len() % 4, 0, "encoding is 4-byte aligned: {value:?}");
+
+        let decoded = WireVariant::decode(&bytes).expect("decode");
+        assert_eq!(decoded, value);
+    }
+
+    #[test]
+    fn encode_scalars() {
+        assert_eq!(WireVariant::Nil.encode(), [0, 0, 0, 0]);
+        assert_eq!(WireVariant::Bool(true).encode(), [1, 0, 0, 0, 1, 0, 0, 0]);
+        assert_eq!(
+            WireVariant::Int(-2).encode(),
+            [2, 0, 0, 0, 0xFE, 0xFF, 0xFF, 0xFF]
+        );
+        assert_eq!(
+            WireVariant::Int(1 << 40).encode(),
+            [2, 0, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0]
+        );
+        assert_eq!(
+            WireVariant::Float(0.5).encode(),
+            [3, 0, 0, 0, 0, 0, 0, 0x3F]
+        );
+        assert_eq!(WireVariant::Float(0.1).encode()[..4], [3, 0, 1, 0]);
+    }
+
+    #[test]
+    fn encode_string_padding() {
+        assert_eq!(
+            WireVariant::String("abcde".to_string()).encode(),
+            [4, 0, 0, 0, 5, 0, 0, 0, b'a', b'b', b'c', b'd', b'e', 0, 0, 0]
+        );
+        assert_eq!(
+            WireVariant::String("abcd".to_string()).encode(),
+            [4, 0, 0, 0, 4, 0, 0, 0, b'a', b'b', b'c', b'd']
+        );
+    }
+
+    #[test]
+    fn roundtrip_values() {
+        roundtrip(WireVariant::Nil);
+        roundtrip(WireVariant::Int(i64::MIN));
+        roundtrip(WireVariant::Float(std::f64::consts::PI));
+        roundtrip(WireVariant::String("Grüße 🦀".to_string()));
+        roundtrip(WireVariant::Vector2(Vector2::new(1.5, -2.0)));
+        roundtrip(WireVariant::Rect2i(Rect2i::from_components(1, 2, 3, 4)));
+        roundtrip(WireVariant::Transform3D(Transform3D::IDENTITY));
+        roundtrip(WireVariant::Projection(Projection::IDENTITY));
+        roundtrip(WireVariant::Color(Color::from_rgba(0.25, 0.5, 0.75, 1.0)));
+        roundtrip(WireVariant::StringName("name".to_string()));
+        roundtrip(WireVariant::Rid(Rid::new(u64::MAX)));
+        roundtrip(WireVariant::PackedByteArray(vec![1, 2, 3]));
+        roundtrip(WireVariant::PackedStringArray(vec![
+            "a".to_string(),
+            String::new(),
+        ]));
+        roundtrip(WireVariant::PackedVector4Array(vec![Vector4::ONE]));
+        roundtrip(WireVariant::Array(vec![
+            WireVariant::Bool(false),
+            WireVariant::Dictionary(vec![(
+                WireVariant::StringName("key".to_string()),
+                WireVariant::Array(vec![]),
+            )]),
+        ]));
+    }
+
+    #[test]
+    fn roundtrip_node_paths() {
+        for path in [
+            "",
+            "Node",
+            "../Sibling/Child",
+            "/root/Node:position:x",
+            ":property",
+        ] {
+            roundtrip(WireVariant::NodePath(path.to_string()));
+        }
+    }
+
+    #[test]
+    fn decode_typed_array() {
+        // Array[int] with one element: header with builtin-typed flag, element type INT, length 1, then int 7.
+        let bytes = [28, 0, 1, 0, 2, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 7, 0, 0, 0];
+
+        let decoded = WireVariant::decode(&bytes).expect("decode");
+        assert_eq!(decoded, WireVariant::Array(vec![WireVariant::Int(7)]));
+    }
+
+    #[test]
+    fn decode_errors() {
+        // Truncated.
+        assert!(WireVariant::decode(&[2, 0, 0, 0, 1]).is_err());
+
+        // Trailing data, but accepted as prefix.
+        let bytes = [1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0];
+        assert!(WireVariant::decode(&bytes).is_err());
+        assert_eq!(
+            WireVariant::decode_prefix(&bytes),
+            Ok((WireVariant::Bool(true), 8))
+        );
+
+        // Object.
+        assert!(WireVariant::decode(&[24, 0, 0, 0, 0, 0, 0, 0]).is_err());
+
+        // Huge length without data.
+        assert!(WireVariant::decode(&[29, 0, 0, 0, 0xFF, 0xFF, 0xFF, 0x7F]).is_err());
+
+        // Deeply nested arrays.
+        let mut bytes = Vec::new();
+        for _ in 0..=MAX_DEPTH + 1 {
+            bytes.extend_from_slice(&[28, 0, 0, 0, 1, 0, 0, 0]);
+        }
+        assert!(WireVariant::decode(&bytes).is_err());
+    }
+}
@@ -6,8 +6,9 @@
  */
 
 use proc_macro2::{Ident, TokenStream};
-use quote::quote;
+use quote::{format_ident, quote};
 
+use crate::class::{into_signature_info, FuncDefinition};
 use crate::util::bail;
 use crate::{util, ParseResult};
 
@@ -15,6 +16,49 @@ pub struct ConstDefinition {
     pub raw_constant: venial::Constant,
 }
 
+impl ConstDefinition {
+    /// Generates a static function returning the constant's value, which is registered under the constant's name.
+    ///
+    /// Used for `#[constant(as_method)]`, since Godot only supports integer constants in classes.
+    ///
+    /// The function itself has a different name, since associated constants and functions share a namespace.
+    pub fn make_static_getter(&self, class_name: &Ident) -> (venial::Function, FuncDefinition) {
+        let venial::Constant {
+            attributes,
+            name,
+            ty,
+            ..
+        } = &self.raw_constant;
+
+        let getter_name = format_ident!("__godot_constant_{name}");
+        let getter_tokens = quote! {
+            #( #attributes )*
+            #[doc(hidden)]
+            #[allow(non_snake_case)]
+            fn #getter_name() -> #ty {
+                Self::#name
+            }
+        };
+
+        let getter = venial::parse_item(getter_tokens)
+            .expect("constant getter should be a valid item")
+            .as_function()
+            .expect("constant getter should be a function")
+            .clone();
+
+        let signature = util::reduce_to_signature(&getter);
+        let func_definition = FuncDefinition {
+            signature_info: into_signature_info(signature, class_name, false),
+            external_attributes: attributes.clone(),
+            registered_name: Some(name.to_string()),
+            is_script_virtual: false,
+            rpc_info: None,
+        };
+
+        (getter, func_definition)
+    }
+}
+
 pub fn make_constant_registration(
     consts: Vec<ConstDefinition>,
     class_name: &Ident,
@@ -8,7 +8,8 @@
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::{quote, ToTokens};
 
-use crate::class::data_models::group_export::FieldGroup;
+use crate::class::data_models::group_export::{FieldCategory, FieldGroup};
+use crate::class::data_models::tool_button_export::FieldToolButton;
 use crate::class::{FieldExport, FieldVar};
 use crate::util::{error, KvParser};
 
@@ -18,8 +19,12 @@ pub struct Field {
     pub default_val: Option<FieldDefault>,
     pub var: Option<FieldVar>,
     pub export: Option<FieldExport>,
+    pub category: Option<FieldCategory>,
     pub group: Option<FieldGroup>,
     pub subgroup: Option<FieldGroup>,
+    /// Group of a nested `ExportGroup` struct, from `#[export_nested]`.
+    pub nested: Option<FieldGroup>,
+    pub tool_button: Option<FieldToolButton>,
     pub is_onready: bool,
     pub is_oneditor: bool,
     pub is_phantomvar: bool,
@@ -36,8 +41,11 @@ impl Field {
             default_val: None,
             var: None,
             export: None,
+            category: None,
             group: None,
             subgroup: None,
+            nested: None,
+            tool_button: None,
             is_onready: false,
             is_oneditor: false,
             is_phantomvar: false,
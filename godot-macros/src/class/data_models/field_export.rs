@@ -7,29 +7,104 @@
 
 use std::collections::{HashMap, HashSet};
 
-use proc_macro2::{Ident, Span, TokenStream};
+use proc_macro2::{Delimiter, Ident, Span, TokenStream};
 use quote::quote;
 
-use crate::util::{ident, KvParser, ListParser};
+use crate::class::data_models::group_export::FieldPlacement;
+use crate::util::{bail, ident, require_api_version, KvParser, ListParser};
 use crate::ParseResult;
 
 pub struct FieldExport {
     pub export_type: ExportType,
+    /// Whether the hint applies to each element of an array, rather than the field itself (`#[export(..., elem)]`).
+    pub elem: bool,
+    /// Explicit group, subgroup and order in the inspector (`#[export(group = ..., subgroup = ..., order = ...)]`).
+    pub placement: Option<FieldPlacement>,
+    /// Whether the editor should not offer to revert the property to its `#[init]` value (`#[export(..., no_revert)]`).
+    pub no_revert: bool,
+    /// Whether the generated setter restricts values to the range (`#[export(range = ..., clamp)]`).
+    pub clamp: Option<Ident>,
     pub span: Span,
 }
 
 impl FieldExport {
     pub(crate) fn new_from_kv(parser: &mut KvParser) -> ParseResult<Self> {
         let span = parser.span();
+        let elem = parser.handle_alone_with_span("elem")?;
+        let placement = FieldPlacement::new_from_kv(parser)?;
+        let no_revert = parser.handle_alone("no_revert")?;
+        let clamp = parser.handle_alone_with_span("clamp")?;
         let export_type = ExportType::new_from_kv(parser)?;
-        Ok(Self { export_type, span })
+
+        if let Some(clamp_key) = &clamp {
+            if !matches!(export_type, ExportType::Range { .. }) {
+                return bail!(
+                    clamp_key,
+                    "`clamp` requires a range, e.g. #[export(range = (0.0, 1.0), clamp)]"
+                );
+            }
+
+            if let Some(elem_key) = &elem {
+                return bail!(elem_key, "`clamp` cannot be combined with `elem`");
+            }
+        }
+
+        if let Some(elem_key) = &elem {
+            require_api_version!("4.3", elem_key, "#[export(..., elem)]")?;
+            export_type.validate_elem(elem_key)?;
+        }
+
+        Ok(Self {
+            export_type,
+            elem: elem.is_some(),
+            placement,
+            no_revert,
+            clamp,
+            span,
+        })
+    }
+
+    /// Generates a statement that restricts the setter parameter `value` to the range, for `#[export(range = ..., clamp)]`.
+    pub fn make_setter_clamp(&self, value: &Ident) -> Option<TokenStream> {
+        self.clamp.as_ref()?;
+
+        let ExportType::Range {
+            min,
+            max,
+            or_greater,
+            or_less,
+            ..
+        } = &self.export_type
+        else {
+            unreachable!("`clamp` is validated to require a range");
+        };
+
+        // Unbounded sides are not clamped.
+        let clamp_min = !or_less;
+        let clamp_max = !or_greater;
+
+        Some(quote! {
+            let #value = ::godot::register::private::clamp_property_value(#value, #min, #max, #clamp_min, #clamp_max);
+        })
     }
 
     pub fn to_export_hint(&self) -> Option<TokenStream> {
-        self.export_type.to_export_hint()
+        let export_hint = self.export_type.to_export_hint()?;
+
+        if !self.elem {
+            return Some(export_hint);
+        }
+
+        // Block instead of nested call, since hints can contain statements (deprecation warnings).
+        Some(quote! {
+            {
+                let elem_hint = #export_hint;
+                ::godot::register::property::export_info_functions::export_array_element::<FieldType>(elem_hint)
+            }
+        })
     }
 
-    pub fn to_export_usage(&self) -> Option<Ident> {
+    pub fn to_export_usage(&self) -> Option<Vec<Ident>> {
         self.export_type.to_export_usage()
     }
 }
@@ -49,14 +124,14 @@ pub enum ExportType {
     /// - `@export_storage`
     ///
     /// ### Property hints
-    /// - `NONE`
+    /// - Inferred from the type, like `#[export]`.
     ///
     /// ### Property usage
     /// - `STORAGE`
     ///
     /// This is used to indicate that the property should be exported
-    /// but should not be visible in the editor. Therefore, it does not
-    /// have a property hint, but uses the `STORAGE` property usage.
+    /// but should not be visible in the editor. It keeps the property hint
+    /// of its type (e.g. array element types), but uses the `STORAGE` property usage.
     Storage,
 
     /// ### GDScript annotations
@@ -122,6 +197,13 @@ pub enum ExportType {
         kind: LayerKind,
     },
 
+    /// ### GDScript annotations
+    /// - `@export_flags_avoidance`
+    ///
+    /// ### Property hints
+    /// - `LAYERS_AVOIDANCE`
+    LayersAvoidance,
+
     /// ### GDScript annotations
     /// - `@export_file`
     /// - `@export_global_file`
@@ -155,6 +237,58 @@ pub enum ExportType {
     /// ### Property hints
     /// - `COLOR_NO_ALPHA`
     ColorNoAlpha,
+
+    /// ### GDScript annotations
+    /// - `@export_node_path`
+    ///
+    /// ### Property hints
+    /// - `NODE_PATH_VALID_TYPES`
+    NodePath { classes: Vec<TokenStream> },
+
+    /// ### GDScript annotations
+    /// - None, only reachable via `@export_custom`.
+    ///
+    /// ### Property hints
+    /// - `PASSWORD`
+    Password,
+
+    /// ### GDScript annotations
+    /// - None, only reachable via `@export_custom`.
+    ///
+    /// ### Property hints
+    /// - `EXPRESSION`
+    Expression,
+
+    /// ### GDScript annotations
+    /// - None, only reachable via `@export_custom`.
+    ///
+    /// ### Property hints
+    /// - `LOCALE_ID`
+    LocaleId,
+
+    /// ### GDScript annotations
+    /// - None, only reachable via `@export_custom`.
+    ///
+    /// ### Property hints
+    /// - `INPUT_NAME`
+    InputName {
+        show_builtin: bool,
+        loose_mode: bool,
+    },
+
+    /// ### GDScript annotations
+    /// - `@export_custom`
+    ///
+    /// ### Property hints
+    /// - Any, as specified.
+    ///
+    /// ### Property usage
+    /// - As specified, `DEFAULT` otherwise.
+    Custom {
+        hint: Ident,
+        hint_string: Option<TokenStream>,
+        usage_flags: Option<Vec<Ident>>,
+    },
 }
 
 impl ExportType {
@@ -231,6 +365,10 @@ impl ExportType {
             });
         }
 
+        if parser.handle_alone("flags_avoidance")? {
+            return Ok(Self::LayersAvoidance);
+        }
+
         match parser.handle_any("file") {
             Some(None) => {
                 return Ok(Self::File {
@@ -293,9 +431,51 @@ impl ExportType {
             return Ok(Self::ColorNoAlpha);
         }
 
+        if let Some(list_parser) = parser.handle_list("node_path")? {
+            return Self::new_node_path(list_parser);
+        }
+
+        if parser.handle_alone("password")? {
+            return Ok(Self::Password);
+        }
+
+        if parser.handle_alone("expression")? {
+            return Ok(Self::Expression);
+        }
+
+        if parser.handle_alone("locale_id")? {
+            return Ok(Self::LocaleId);
+        }
+
+        if let Some(list_parser) = parser.handle_list("input_name")? {
+            return Self::new_input_name(list_parser);
+        }
+
+        if let Some(list_parser) = parser.handle_list("custom")? {
+            return Self::new_custom(list_parser);
+        }
+
         Ok(Self::Default)
     }
 
+    /// Checks that this hint can be applied to array elements via `elem`.
+    fn validate_elem(&self, elem_key: &Ident) -> ParseResult<()> {
+        match self {
+            Self::Default | Self::Storage | Self::Custom { .. } => bail!(
+                elem_key,
+                "`elem` requires a hint that applies to each element, e.g. #[export(range = (0.0, 1.0), elem)]"
+            ),
+
+            // These are already applied to elements of Array<GString>/PackedStringArray and Array<NodePath>.
+            Self::File { .. } | Self::NodePath { .. } => bail!(
+                elem_key,
+                "`elem` is not needed here; file/dir and node_path hints on arrays automatically apply to their elements"
+            ),
+
+            _ => Ok(()),
+        }
+    }
+
     fn new_storage() -> ParseResult<Self> {
         Ok(Self::Storage)
     }
@@ -392,6 +572,80 @@ impl ExportType {
         })
     }
 
+    fn new_node_path(mut parser: ListParser) -> ParseResult<Self> {
+        let mut classes = Vec::new();
+
+        while parser.peek().is_some() {
+            classes.push(parser.next_expr()?);
+        }
+
+        parser.finish()?;
+
+        Ok(Self::NodePath { classes })
+    }
+
+    fn new_input_name(mut parser: ListParser) -> ParseResult<Self> {
+        const ALLOWED_OPTIONS: [&str; 2] = ["show_builtin", "loose_mode"];
+
+        let mut options = HashSet::new();
+
+        while let Some(option) = parser.next_allowed_ident(&ALLOWED_OPTIONS[..])? {
+            options.insert(option.to_string());
+        }
+
+        parser.finish()?;
+
+        Ok(Self::InputName {
+            show_builtin: options.contains("show_builtin"),
+            loose_mode: options.contains("loose_mode"),
+        })
+    }
+
+    fn new_custom(mut parser: ListParser) -> ParseResult<Self> {
+        const KV_OPTIONS: [&str; 3] = ["hint", "hint_string", "usage_flags"];
+
+        let mut hint = None;
+        let mut hint_string = None;
+        let mut usage_flags = None;
+
+        while let Some((key, value)) = parser.next_allowed_key_optional_value(&[], &KV_OPTIONS)? {
+            let value = value.expect("KV_OPTIONS keys always have a value");
+
+            match key.to_string().as_str() {
+                "hint" => hint = Some(value.ident()?),
+                "hint_string" => hint_string = Some(value.expr()?),
+                "usage_flags" => {
+                    let mut flags_parser =
+                        ListParser::new_from_tree(value.single()?, Delimiter::Bracket)?;
+                    let mut flags = Vec::new();
+
+                    while let Some(flag) = flags_parser.next_ident()? {
+                        flags.push(flag);
+                    }
+
+                    flags_parser.finish()?;
+                    usage_flags = Some(flags);
+                }
+                _ => unreachable!("key already validated"),
+            }
+        }
+
+        parser.finish()?;
+
+        let Some(hint) = hint else {
+            return bail!(
+                parser.span_close(),
+                "#[export(custom)] requires key `hint = HINT`"
+            );
+        };
+
+        Ok(Self::Custom {
+            hint,
+            hint_string,
+            usage_flags,
+        })
+    }
+
     fn new_flags(mut parser: ListParser) -> ParseResult<Self> {
         let mut bits = Vec::new();
 
@@ -431,7 +685,7 @@ impl ExportType {
         match self {
             Self::Default => None,
 
-            Self::Storage => quote_export_func! { export_storage() },
+            Self::Storage => quote_export_func! { export_storage<T>() },
 
             Self::Range {
                 min,
@@ -539,6 +793,8 @@ impl ExportType {
                 quote_export_func! { export_file_or_dir<T>(true, #global, #filter) }
             }
 
+            Self::LayersAvoidance => quote_export_func! { export_flags_avoidance() },
+
             Self::Multiline => quote_export_func! { export_multiline() },
 
             Self::PlaceholderText { placeholder } => quote_export_func! {
@@ -546,13 +802,41 @@ impl ExportType {
             },
 
             Self::ColorNoAlpha => quote_export_func! { export_color_no_alpha() },
+
+            Self::NodePath { classes } => quote_export_func! {
+                export_node_path<T>(&[#(<#classes as ::godot::obj::GodotClass>::class_name()),*])
+            },
+
+            Self::Password => quote_export_func! { export_password() },
+
+            Self::Expression => quote_export_func! { export_expression() },
+
+            Self::LocaleId => quote_export_func! { export_locale_id() },
+
+            Self::InputName {
+                show_builtin,
+                loose_mode,
+            } => quote_export_func! {
+                export_input_name(#show_builtin, #loose_mode)
+            },
+
+            Self::Custom {
+                hint, hint_string, ..
+            } => {
+                let hint_string = hint_string.clone().unwrap_or(quote! { "" });
+
+                quote_export_func! {
+                    export_custom(::godot::global::PropertyHint::#hint, #hint_string)
+                }
+            }
         }
     }
 
-    /// Returns a `PropertyUsageFlags` identifier if this export type has a _usage_.
-    pub fn to_export_usage(&self) -> Option<Ident> {
+    /// Returns `PropertyUsageFlags` identifiers if this export type has a _usage_.
+    pub fn to_export_usage(&self) -> Option<Vec<Ident>> {
         match self {
-            Self::Storage => Some(ident("STORAGE")),
+            Self::Storage => Some(vec![ident("STORAGE")]),
+            Self::Custom { usage_flags, .. } => usage_flags.clone(),
             _ => None,
         }
     }
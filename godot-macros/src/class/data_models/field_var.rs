@@ -22,6 +22,12 @@ pub struct FieldVar {
     pub setter: GetterSetter,
     pub hint: FieldHint,
     pub usage_flags: UsageFlags,
+    /// Method deciding whether the generated setter accepts a value (`#[var(validate = method)]`).
+    pub validate: Option<Ident>,
+    /// Method called by the generated setter when the value changes (`#[var(notify = method)]`).
+    pub notify: Option<Ident>,
+    /// Signal emitted by the generated setter when the value changes (`#[var(emit = signal)]`).
+    pub emit: Option<Ident>,
     pub span: Span,
 }
 
@@ -34,6 +40,9 @@ impl FieldVar {
     /// - `hint = ident`
     /// - `hint_string = expr`
     /// - `usage_flags =
+    /// - `validate = ident`
+    /// - `notify = ident`
+    /// - `emit = ident`
     pub(crate) fn new_from_kv(parser: &mut KvParser) -> ParseResult<Self> {
         let span = parser.span();
         let getter = GetterSetter::parse(parser, "get")?;
@@ -63,15 +72,54 @@ impl FieldVar {
             UsageFlags::Inferred
         };
 
+        let validate = parser.handle_ident("validate")?;
+        let notify = parser.handle_ident("notify")?;
+        let emit = parser.handle_ident("emit")?;
+
         Ok(FieldVar {
             getter,
             setter,
             hint,
             usage_flags,
+            validate,
+            notify,
+            emit,
             span,
         })
     }
 
+    /// Wraps the assignment in a generated setter, such that `notify` and `emit` are invoked with the old and new value if it changes.
+    ///
+    /// The new value is read back from the field, in case `Var::set_property()` does not store the value as is.
+    fn make_change_handlers(&self, field: &Field, set_property: TokenStream) -> TokenStream {
+        if self.notify.is_none() && self.emit.is_none() {
+            return set_property;
+        }
+
+        let field_name = &field.name;
+        let field_type = &field.ty;
+
+        let notify = self.notify.as_ref().map(|method| {
+            quote! { self.#method(&__old_value, &__new_value); }
+        });
+        let emit = self.emit.as_ref().map(|signal| {
+            quote! {
+                ::godot::obj::WithUserSignals::signals(self).#signal().emit_tuple((__old_value, __new_value));
+            }
+        });
+
+        quote! {
+            let __old_value = <#field_type as ::godot::register::property::Var>::get_property(&self.#field_name);
+            #set_property
+            let __new_value = <#field_type as ::godot::register::property::Var>::get_property(&self.#field_name);
+
+            if __old_value != __new_value {
+                #notify
+                #emit
+            }
+        }
+    }
+
     /// If both `get` and `set` are omitted, pretends that autogenerated getters and setters were requested.
     pub(crate) fn default_to_generated_getter_setter(&mut self) {
         if self.getter.is_omitted() && self.setter.is_omitted() {
@@ -88,6 +136,9 @@ impl Default for FieldVar {
             setter: Default::default(),
             hint: Default::default(),
             usage_flags: Default::default(),
+            validate: None,
+            notify: None,
+            emit: None,
             span: Span::call_site(),
         }
     }
@@ -198,12 +249,107 @@ impl GetterSetterImpl {
                 signature = quote! {
                     fn #function_name(&mut self, #field_name: <#field_type as ::godot::meta::GodotConvert>::Via)
                 };
-                function_body = quote! {
+
+                let clamp = field
+                    .export
+                    .as_ref()
+                    .and_then(|export| export.make_setter_clamp(field_name));
+                let validate = field
+                    .var
+                    .as_ref()
+                    .and_then(|var| var.validate.as_ref())
+                    .map(|validate| {
+                        let property_name = field_name.to_string();
+                        quote! {
+                            if !self.#validate(&#field_name) {
+                                ::godot::register::private::warn_rejected_property_value::<Self, _>(#property_name, &#field_name);
+                                return;
+                            }
+                        }
+                    });
+
+                let set_property = quote! {
                     <#field_type as ::godot::register::property::Var>::set_property(&mut self.#field_name, #field_name);
                 };
+                let set_property = match &field.var {
+                    Some(var) => var.make_change_handlers(field, set_property),
+                    None => set_property,
+                };
+
+                function_body = quote! {
+                    #clamp
+                    #validate
+                    #set_property
+                };
+            }
+        }
+
+        Self::from_generated_body(class_name, function_name, signature, function_body)
+    }
+
+    /// Generates the getter of an `#[export_tool_button]` property, which returns a `Callable` bound to the given method.
+    pub(super) fn from_tool_button(class_name: &Ident, field: &Field, method: &Ident) -> Self {
+        let field_name = &field.name;
+        let function_name = format_ident!("{}{field_name}", GetSet::Get.prefix());
+
+        // Refer to the method through its funcs collection constant, which respects #[func(rename)] and fails if there is no such #[func].
+        let funcs_collection = util::format_funcs_collection_struct(class_name);
+        let method_constant = util::format_funcs_collection_constant(class_name, method);
+
+        let signature = quote! {
+            fn #function_name(&self) -> ::godot::builtin::Callable
+        };
+        let function_body = quote! {
+            ::godot::builtin::Callable::from_object_method(
+                &<Self as ::godot::obj::WithBaseField>::to_gd(self),
+                #funcs_collection::#method_constant,
+            )
+        };
+
+        Self::from_generated_body(class_name, function_name, signature, function_body)
+    }
+
+    /// Generates the getter or setter shared by the properties of an `#[export_nested]` field, which receives the property index.
+    pub(super) fn from_nested_group(class_name: &Ident, kind: GetSet, field: &Field) -> Self {
+        let Field {
+            name: field_name,
+            ty: field_type,
+            ..
+        } = field;
+
+        let function_name = format_ident!("{}{field_name}", kind.prefix());
+
+        let signature;
+        let function_body;
+
+        match kind {
+            GetSet::Get => {
+                signature = quote! {
+                    fn #function_name(&self, index: i64) -> ::godot::builtin::Variant
+                };
+                function_body = quote! {
+                    <#field_type as ::godot::register::property::ExportGroup>::__get_group_property(&self.#field_name, index)
+                };
+            }
+            GetSet::Set => {
+                signature = quote! {
+                    fn #function_name(&mut self, index: i64, value: ::godot::builtin::Variant)
+                };
+                function_body = quote! {
+                    <#field_type as ::godot::register::property::ExportGroup>::__set_group_property(&mut self.#field_name, index, &value);
+                };
             }
         }
 
+        Self::from_generated_body(class_name, function_name, signature, function_body)
+    }
+
+    fn from_generated_body(
+        class_name: &Ident,
+        function_name: Ident,
+        signature: TokenStream,
+        function_body: TokenStream,
+    ) -> Self {
         let function_impl = quote! {
             pub #signature {
                 #function_body
@@ -7,18 +7,20 @@
 
 // Note: group membership for properties in Godot is based on the order of their registration.
 // All the properties belong to group or subgroup registered beforehand, identically as in GDScript.
-// Initial implementation providing clap-like API with an explicit sorting
-// & groups/subgroups declared for each field (`#[export(group = ..., subgroup = ...)]`
-// can be found at: https://github.com/godot-rust/gdext/pull/1214.
+// Fields can alternatively declare their group explicitly with `#[export(group = ..., subgroup = ..., order = ...)]`; in that case,
+// registration is sorted accordingly (see `order_fields_by_placement()`). This API is based on the initial implementation at:
+// https://github.com/godot-rust/gdext/pull/1214.
 
-use proc_macro2::Literal;
+use proc_macro2::{Ident, Literal, Span};
 
+use crate::class::Field;
 use crate::util::{bail, KvParser};
 use crate::ParseResult;
 
 /// Specifies group or subgroup which starts with a given field.
 /// Group membership for properties in Godot is based on the order of their registration –
 /// i.e. given field belongs to group declared beforehand (for example with some previous field).
+#[derive(Clone)]
 pub struct FieldGroup {
     pub(crate) name: Literal,
     pub(crate) prefix: Literal,
@@ -36,4 +38,177 @@ impl FieldGroup {
 
         Ok(Self { name, prefix })
     }
+
+    /// Parses `#[export_nested(name = ..., prefix = ...)]`, which registers the fields of a nested struct inside their own group.
+    ///
+    /// Unlike other groups, both keys are optional: the name defaults to the field name, and the prefix to the field name followed by `_`.
+    /// The prefix is also prepended to the names of the nested properties.
+    pub(crate) fn new_nested_from_kv(
+        parser: &mut KvParser,
+        field_name: &Ident,
+    ) -> ParseResult<Self> {
+        let name = parser
+            .handle_literal("name", "String")?
+            .unwrap_or_else(|| Literal::string(&field_name.to_string()));
+
+        let prefix = parser
+            .handle_literal("prefix", "String")?
+            .unwrap_or_else(|| Literal::string(&format!("{field_name}_")));
+
+        Ok(Self { name, prefix })
+    }
+}
+
+/// Specifies a category which starts with a given field.
+///
+/// Like groups, category membership is based on the order of registration. Categories have no prefix.
+pub struct FieldCategory {
+    pub(crate) name: Literal,
+}
+
+impl FieldCategory {
+    pub(crate) fn new_from_kv(parser: &mut KvParser) -> ParseResult<Self> {
+        let Some(name) = parser.handle_literal("name", "String")? else {
+            return bail!(parser.span(), "missing required argument: `name = \"...\".");
+        };
+
+        Ok(Self { name })
+    }
+}
+
+/// Explicit placement of an exported field in the inspector, from `#[export(group = "...", subgroup = "...", order = N)]`.
+///
+/// Unlike `#[export_group]`, this does not depend on the position of the field in the struct.
+pub struct FieldPlacement {
+    pub(crate) group: Option<Literal>,
+    pub(crate) subgroup: Option<Literal>,
+    /// Position relative to other fields; fields without explicit `order` have order 0.
+    pub(crate) order: usize,
+    pub(crate) span: Span,
+}
+
+impl FieldPlacement {
+    /// Parses the `group`, `subgroup` and `order` keys of `#[export]`. Returns `None` if none of them are present.
+    pub(crate) fn new_from_kv(parser: &mut KvParser) -> ParseResult<Option<Self>> {
+        let group = parser.handle_literal("group", "String")?;
+        let subgroup = parser.handle_literal("subgroup", "String")?;
+        let order = parser.handle_usize("order")?;
+
+        if group.is_none() && subgroup.is_none() && order.is_none() {
+            return Ok(None);
+        }
+
+        if group.is_none() && subgroup.is_some() {
+            return bail!(
+                parser.span(),
+                "#[export(subgroup = ...)] requires key `group = \"...\"`"
+            );
+        }
+
+        Ok(Some(Self {
+            group,
+            subgroup,
+            order: order.unwrap_or(0),
+            span: parser.span(),
+        }))
+    }
+}
+
+/// Orders fields for property registration, according to their [`FieldPlacement`].
+///
+/// If no field has an explicit placement, the declaration order is kept. Otherwise, fields without group come first, so they are not
+/// absorbed by a group. They are followed by the groups, each starting with the fields outside a subgroup, followed by its subgroups.
+/// Groups and subgroups are ordered by their first member; fields are ordered by `order`, then by declaration.
+///
+/// Returns each field together with the group and subgroup that must be registered right before it, if any.
+pub(crate) fn order_fields_by_placement(fields: &[Field]) -> Vec<PlacedField<'_>> {
+    if !fields.iter().any(|field| placement_of(field).is_some()) {
+        return fields
+            .iter()
+            .map(|field| PlacedField {
+                field,
+                group: None,
+                subgroup: None,
+            })
+            .collect();
+    }
+
+    // Compare literals by their source representation, which is equal for equal string literals.
+    let name_of = |lit: &Option<Literal>| lit.as_ref().map(|lit| lit.to_string());
+
+    let mut sorted: Vec<(usize, &Field)> = fields.iter().enumerate().collect();
+    sorted.sort_by_key(|(index, field)| (placement_of(field).map_or(0, |p| p.order), *index));
+
+    // Groups and subgroups are ordered by their first member, so collect them in sorted order (ungrouped fields use `None`).
+    type Subgroups<'f> = Vec<(Option<String>, Vec<&'f Field>)>;
+    let mut groups: Vec<(Option<String>, Subgroups)> = Vec::new();
+    for (_, field) in sorted {
+        let (group, subgroup) = match placement_of(field) {
+            Some(placement) => (name_of(&placement.group), name_of(&placement.subgroup)),
+            None => (None, None),
+        };
+
+        let subgroups = match groups.iter_mut().find(|(name, _)| *name == group) {
+            Some((_, subgroups)) => subgroups,
+            None => {
+                groups.push((group, Vec::new()));
+                &mut groups.last_mut().unwrap().1
+            }
+        };
+
+        match subgroups.iter_mut().find(|(name, _)| *name == subgroup) {
+            Some((_, members)) => members.push(field),
+            None => subgroups.push((subgroup, vec![field])),
+        }
+    }
+
+    // Ungrouped fields first; within each group, fields without subgroup first.
+    groups.sort_by_key(|(name, _)| name.is_some());
+
+    let mut result = Vec::new();
+    for (_, mut subgroups) in groups {
+        subgroups.sort_by_key(|(name, _)| name.is_some());
+
+        for (subgroup_index, (_, members)) in subgroups.into_iter().enumerate() {
+            for (member_index, field) in members.into_iter().enumerate() {
+                let placement = placement_of(field);
+
+                let group = match placement {
+                    Some(p) if subgroup_index == 0 && member_index == 0 => p.group.clone(),
+                    _ => None,
+                };
+                let subgroup = match placement {
+                    Some(p) if member_index == 0 => p.subgroup.clone(),
+                    _ => None,
+                };
+
+                result.push(PlacedField {
+                    field,
+                    group: group.map(|name| FieldGroup {
+                        name,
+                        prefix: Literal::string(""),
+                    }),
+                    subgroup: subgroup.map(|name| FieldGroup {
+                        name,
+                        prefix: Literal::string(""),
+                    }),
+                });
+            }
+        }
+    }
+
+    result
+}
+
+fn placement_of(field: &Field) -> Option<&FieldPlacement> {
+    field.export.as_ref()?.placement.as_ref()
+}
+
+/// A field in registration order, see [`order_fields_by_placement()`].
+pub(crate) struct PlacedField<'a> {
+    pub field: &'a Field,
+    /// Group to register before the field, according to the explicit placements.
+    pub group: Option<FieldGroup>,
+    /// Subgroup to register before the field, according to the explicit placements.
+    pub subgroup: Option<FieldGroup>,
 }
@@ -25,7 +25,7 @@ use crate::{handle_mutually_exclusive_keys, util, ParseResult};
 enum ItemAttrType {
     Func(FuncAttr, Option<RpcAttr>),
     Signal(SignalAttr, venial::AttributeValue),
-    Const(#[allow(dead_code)] venial::AttributeValue),
+    Const(ConstAttr),
 }
 
 struct ItemAttr {
@@ -38,7 +38,7 @@ enum AttrParseResult {
     Rpc(RpcAttr),
     FuncRpc(FuncAttr, RpcAttr),
     Signal(SignalAttr, venial::AttributeValue),
-    Constant(#[allow(dead_code)] venial::AttributeValue),
+    Constant(ConstAttr),
 }
 
 impl AttrParseResult {
@@ -54,6 +54,11 @@ impl AttrParseResult {
     }
 }
 
+struct ConstAttr {
+    /// Register as static method instead of integer constant.
+    pub as_method: bool,
+}
+
 #[derive(Default)]
 struct FuncAttr {
     pub rename: Option<String>,
@@ -88,8 +93,9 @@ pub fn transform_inherent_impl(
     let prv = quote! { ::godot::private };
 
     // Can add extra functions to the end of the impl block.
-    let (funcs, signals) = process_godot_fns(&class_name, &mut impl_block, meta.secondary)?;
-    let consts = process_godot_constants(&mut impl_block)?;
+    let (mut funcs, signals) = process_godot_fns(&class_name, &mut impl_block, meta.secondary)?;
+    let (consts, const_getters) = process_godot_constants(&class_name, &mut impl_block)?;
+    funcs.extend(const_getters);
 
     #[cfg(all(feature = "register-docs", since_api = "4.3"))]
     let docs = crate::docs::document_inherent_impl(&funcs, &consts, &signals);
@@ -351,11 +357,25 @@ fn process_godot_fns(
                 removed_indexes.push(index);
             }
 
+            // Static getter for values that cannot be computed in a `const` context, e.g. `StringName`.
             ItemAttrType::Const(_) => {
-                return bail!(
-                    function,
-                    "#[constant] can only be used on associated constant",
-                )
+                if !function.params.is_empty() || function.return_ty.is_none() {
+                    return bail!(
+                        function,
+                        "#[constant] can only be used on associated constants, or on functions without parameters that return a value",
+                    );
+                }
+
+                let external_attributes = function.attributes.clone();
+                let signature = util::reduce_to_signature(function);
+
+                func_definitions.push(FuncDefinition {
+                    signature_info: into_signature_info(signature, class_name, false),
+                    external_attributes,
+                    registered_name: None,
+                    is_script_virtual: false,
+                    rpc_info: None,
+                });
             }
         }
     }
@@ -375,8 +395,14 @@ fn process_godot_fns(
     Ok((func_definitions, signal_definitions))
 }
 
-fn process_godot_constants(decl: &mut venial::Impl) -> ParseResult<Vec<ConstDefinition>> {
+/// Returns integer constants, as well as the static getters of other constants.
+fn process_godot_constants(
+    class_name: &Ident,
+    decl: &mut venial::Impl,
+) -> ParseResult<(Vec<ConstDefinition>, Vec<FuncDefinition>)> {
     let mut constant_signatures = vec![];
+    let mut getter_definitions = vec![];
+    let mut getters = vec![];
 
     for item in decl.body_items.iter_mut() {
         let venial::ImplMember::AssocConstant(constant) = item else {
@@ -391,7 +417,7 @@ fn process_godot_constants(decl: &mut venial::Impl) -> ParseResult<Vec<ConstDefi
                 ItemAttrType::Signal(_, _) => {
                     return bail!(constant, "#[signal] can only be used on functions")
                 }
-                ItemAttrType::Const(_) => {
+                ItemAttrType::Const(const_attr) => {
                     if constant.initializer.is_none() {
                         return bail!(constant, "exported constant must have initializer");
                     }
@@ -400,13 +426,26 @@ fn process_godot_constants(decl: &mut venial::Impl) -> ParseResult<Vec<ConstDefi
                         raw_constant: constant.clone(),
                     };
 
-                    constant_signatures.push(definition);
+                    if const_attr.as_method {
+                        let (getter, getter_definition) = definition.make_static_getter(class_name);
+
+                        getters.push(getter);
+                        getter_definitions.push(getter_definition);
+                    } else {
+                        constant_signatures.push(definition);
+                    }
                 }
             }
         }
     }
 
-    Ok(constant_signatures)
+    // Add static getters at the end of same impl block (subject to same attributes).
+    for getter in getters.into_iter() {
+        decl.body_items
+            .push(venial::ImplMember::AssocFunction(getter));
+    }
+
+    Ok((constant_signatures, getter_definitions))
 }
 
 /// Replaces the body of `function` with custom code that performs virtual dispatch.
@@ -527,7 +566,7 @@ fn parse_attributes_inner(
             name if name == "func" => parse_func_attr(attributes)?,
             name if name == "rpc" => parse_rpc_attr(attributes)?,
             name if name == "signal" => parse_signal_attr(attributes, attr)?,
-            name if name == "constant" => parse_constant_attr(attributes, attr)?,
+            name if name == "constant" => parse_constant_attr(attributes)?,
 
             // Ignore unknown attributes.
             _ => continue,
@@ -661,15 +700,16 @@ fn parse_signal_attr(
 }
 
 /// `#[constant]` attribute.
-fn parse_constant_attr(
-    attributes: &[venial::Attribute],
-    attr: &venial::Attribute,
-) -> ParseResult<AttrParseResult> {
-    // Ensure no keys are present.
-    let parser = KvParser::parse(attributes, "constant")?.unwrap();
+fn parse_constant_attr(attributes: &[venial::Attribute]) -> ParseResult<AttrParseResult> {
+    // Safe unwrap, since #[constant] must be present if we got to this point.
+    let mut parser = KvParser::parse(attributes, "constant")?.unwrap();
+
+    // #[constant(as_method)]
+    let as_method = parser.handle_alone("as_method")?;
+
     parser.finish()?;
 
-    Ok(AttrParseResult::Constant(attr.value.clone()))
+    Ok(AttrParseResult::Constant(ConstAttr { as_method }))
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
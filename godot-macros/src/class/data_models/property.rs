@@ -11,8 +11,10 @@ use proc_macro2::{Ident, TokenStream};
 use quote::quote;
 
 use crate::class::data_models::fields::Fields;
-use crate::class::data_models::group_export::FieldGroup;
-use crate::class::{Field, FieldVar, GetSet, GetterSetterImpl, UsageFlags};
+use crate::class::data_models::group_export::{
+    order_fields_by_placement, FieldCategory, FieldGroup, PlacedField,
+};
+use crate::class::{Field, FieldVar, GetSet, GetterSetter, GetterSetterImpl, UsageFlags};
 use crate::util::{format_funcs_collection_constant, format_funcs_collection_struct, ident};
 
 #[derive(Default, Clone, Debug)]
@@ -40,28 +42,115 @@ impl FieldHint {
     }
 }
 
-pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
+pub fn make_property_impl(
+    class_name: &Ident,
+    fields: &Fields,
+    revert_arms: &[TokenStream],
+) -> TokenStream {
     let mut getter_setter_impls = Vec::new();
     let mut phantom_var_dummy_uses = Vec::new();
     let mut func_name_consts = Vec::new();
     let mut export_tokens = Vec::new();
 
-    for field in &fields.all_fields {
+    for placed in order_fields_by_placement(&fields.all_fields) {
+        let PlacedField {
+            field,
+            group: placed_group,
+            subgroup: placed_subgroup,
+        } = placed;
+
+        // Explicit placements cannot be combined with #[export_group] and #[export_subgroup], so at most one of them is set.
+        let group = &placed_group.or_else(|| field.group.clone());
+        let subgroup = &placed_subgroup.or_else(|| field.subgroup.clone());
+
         let Field {
             name: field_ident,
             ty: field_type,
             var,
             export,
-            group,
-            subgroup,
+            category,
+            nested,
+            tool_button,
             ..
         } = field;
 
+        if let Some(tool_button) = tool_button {
+            make_groups_registrations(category, group, subgroup, &mut export_tokens, class_name);
+
+            let field_name = field_ident.to_string();
+            let getter_tokens = make_getter_setter(
+                Some(GetterSetterImpl::from_tool_button(
+                    class_name,
+                    field,
+                    &tool_button.method,
+                )),
+                &mut getter_setter_impls,
+                &mut func_name_consts,
+                &mut export_tokens,
+                class_name,
+            );
+            let hint = tool_button.to_export_hint();
+
+            phantom_var_dummy_uses.push(quote! {
+                let _ = &self.#field_ident;
+            });
+
+            // Like in GDScript, the button is only shown in the editor and not stored.
+            export_tokens.push(quote! {
+                ::godot::register::private::register_var::<#class_name, ::godot::builtin::Callable>(
+                    #field_name,
+                    #getter_tokens,
+                    "",
+                    #hint,
+                    ::godot::global::PropertyUsageFlags::EDITOR,
+                );
+            });
+            continue;
+        }
+
+        if let Some(FieldGroup { name, prefix }) = nested {
+            make_groups_registrations(category, group, subgroup, &mut export_tokens, class_name);
+
+            let getter_tokens = make_getter_setter(
+                Some(GetterSetterImpl::from_nested_group(
+                    class_name,
+                    GetSet::Get,
+                    field,
+                )),
+                &mut getter_setter_impls,
+                &mut func_name_consts,
+                &mut export_tokens,
+                class_name,
+            );
+            let setter_tokens = make_getter_setter(
+                Some(GetterSetterImpl::from_nested_group(
+                    class_name,
+                    GetSet::Set,
+                    field,
+                )),
+                &mut getter_setter_impls,
+                &mut func_name_consts,
+                &mut export_tokens,
+                class_name,
+            );
+
+            // The prefix determines group membership in the editor, so properties after the nested ones are no longer part of the group.
+            export_tokens.push(quote! {
+                ::godot::register::private::register_group::<#class_name>(#name, #prefix);
+                <#field_type as ::godot::register::property::ExportGroup>::__register_group_properties::<#class_name>(
+                    #prefix,
+                    #getter_tokens,
+                    #setter_tokens,
+                );
+            });
+            continue;
+        }
+
         // Ensure we add a var if the user only provided a `#[export]`.
         let var = match (export, var) {
             (Some(export), None) => {
                 let usage_flags = if let Some(usage) = export.to_export_usage() {
-                    UsageFlags::Custom(vec![usage])
+                    UsageFlags::Custom(usage)
                 } else {
                     UsageFlags::InferredExport
                 };
@@ -75,7 +164,7 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
             _ => continue,
         };
 
-        make_groups_registrations(group, subgroup, &mut export_tokens, class_name);
+        make_groups_registrations(category, group, subgroup, &mut export_tokens, class_name);
 
         let field_name = field_ident.to_string();
 
@@ -92,7 +181,10 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
 
         if let Some(export) = export {
             if usage_flags.is_inferred() {
-                usage_flags = UsageFlags::InferredExport;
+                usage_flags = match export.to_export_usage() {
+                    Some(usage) => UsageFlags::Custom(usage),
+                    None => UsageFlags::InferredExport,
+                };
             }
 
             export_hint = export.to_export_hint();
@@ -200,6 +292,19 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
         }
     };
 
+    let default_property_revert_fn = if revert_arms.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            fn __default_property_revert(property: &::godot::builtin::StringName) -> Option<::godot::builtin::Variant> {
+                match property.to_string().as_str() {
+                    #( #revert_arms )*
+                    _ => None,
+                }
+            }
+        }
+    };
+
     // For each generated #[func], add a const declaration.
     // This is the name of the container struct, which is declared by #[derive(GodotClass)].
     let class_functions_name = format_funcs_collection_struct(class_name);
@@ -222,10 +327,53 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
                     }
                 )*
             }
+
+            #default_property_revert_fn
         }
     }
 }
 
+/// Generates match arms mapping the name of each exported property to the value it is reverted to in the editor.
+///
+/// The revert value is the initial value from `#[init(val = ...)]`, or `Default::default()`. Only applicable if the constructor is
+/// generated by `#[class(init)]`. Skipped for properties with custom getters, since those may not reflect the field's value.
+///
+/// The generated code is evaluated at most once per class and property, see `property_revert_value()`.
+pub fn make_property_revert_arms(fields: &Fields) -> Vec<TokenStream> {
+    fields
+        .all_fields
+        .iter()
+        .filter(|field| {
+            let Some(export) = &field.export else {
+                return false;
+            };
+
+            let has_generated_getter = field
+                .var
+                .as_ref()
+                .is_none_or(|var| var.getter == GetterSetter::Generated);
+
+            !export.no_revert && !field.is_phantomvar && has_generated_getter
+        })
+        .map(|field| {
+            let field_name = field.name.to_string();
+            let field_type = &field.ty;
+            let value_expr = field
+                .default_val
+                .as_ref()
+                .map(|default| default.default_val.clone())
+                .unwrap_or_else(|| quote! { ::std::default::Default::default() });
+
+            quote! {
+                #field_name => {
+                    type FieldType = #field_type;
+                    ::godot::register::private::property_revert_value(|| -> FieldType { #value_expr })
+                }
+            }
+        })
+        .collect()
+}
+
 fn make_getter_setter(
     getter_setter_impl: Option<GetterSetterImpl>,
     getter_setter_impls: &mut Vec<TokenStream>,
@@ -249,15 +397,22 @@ fn make_getter_setter(
     quote! { #funcs_collection::#constant }
 }
 
-/// Generates registrations for declared group and subgroup and pushes them to export tokens.
+/// Generates registrations for declared category, group and subgroup and pushes them to export tokens.
 ///
-/// Groups must be registered before subgroups (otherwise the ordering is broken).
+/// Categories must be registered before groups, and groups before subgroups (otherwise the ordering is broken).
 fn make_groups_registrations(
+    category: &Option<FieldCategory>,
     group: &Option<FieldGroup>,
     subgroup: &Option<FieldGroup>,
     export_tokens: &mut Vec<TokenStream>,
     class_name: &Ident,
 ) {
+    if let Some(FieldCategory { name }) = category {
+        export_tokens.push(quote! {
+            ::godot::register::private::register_category::<#class_name>(#name);
+        });
+    }
+
     export_tokens.push(make_group_registration(
         group,
         ident("register_group"),
@@ -0,0 +1,58 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+
+use crate::util::{bail, require_api_version, KvParser};
+use crate::ParseResult;
+
+/// Store info from `#[export_tool_button]` attribute.
+///
+/// ### GDScript annotations
+/// - `@export_tool_button`
+///
+/// ### Property hints
+/// - `TOOL_BUTTON`
+pub struct FieldToolButton {
+    /// The `#[func]` invoked when the button is pressed.
+    pub method: Ident,
+    pub text: TokenStream,
+    pub icon: Option<TokenStream>,
+    pub span: Span,
+}
+
+impl FieldToolButton {
+    /// Parse an `#[export_tool_button(fn = method, text = "...", icon = "...")]` attribute.
+    pub(crate) fn new_from_kv(parser: &mut KvParser) -> ParseResult<Self> {
+        let span = parser.span();
+        require_api_version!("4.4", span, "#[export_tool_button]")?;
+
+        let method = parser.handle_ident_required("fn")?;
+        let text = parser.handle_expr_required("text")?;
+        let icon = parser.handle_expr("icon")?;
+
+        Ok(Self {
+            method,
+            text,
+            icon,
+            span,
+        })
+    }
+
+    pub fn to_export_hint(&self) -> TokenStream {
+        let text = &self.text;
+        let icon = match &self.icon {
+            Some(icon) => quote! { Some(#icon) },
+            None => quote! { None },
+        };
+
+        quote! {
+            ::godot::register::property::export_info_functions::export_tool_button(#text, #icon)
+        }
+    }
+}
@@ -10,10 +10,11 @@ use quote::{format_ident, quote, quote_spanned};
 use venial::Error;
 
 use crate::class::data_models::fields::{named_fields, Fields};
-use crate::class::data_models::group_export::FieldGroup;
+use crate::class::data_models::group_export::{FieldCategory, FieldGroup};
+use crate::class::data_models::tool_button_export::FieldToolButton;
 use crate::class::{
-    make_property_impl, make_virtual_callback, BeforeKind, Field, FieldCond, FieldDefault,
-    FieldExport, FieldVar, GetterSetter, SignatureInfo,
+    make_property_impl, make_property_revert_arms, make_virtual_callback, BeforeKind, Field,
+    FieldCond, FieldDefault, FieldExport, FieldVar, GetterSetter, SignatureInfo,
 };
 use crate::util::{
     bail, error, format_funcs_collection_struct, ident, path_ends_with_complex, KvParser,
@@ -40,6 +41,18 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
     let mut struct_cfg = parse_struct_attributes(class)?;
     let mut fields = parse_fields(named_fields, struct_cfg.init_strategy)?;
 
+    // Tool buttons invoke methods inside the editor, which is only possible for tool classes.
+    if !struct_cfg.is_tool {
+        for field in &fields.all_fields {
+            if let Some(tool_button) = &field.tool_button {
+                fields.errors.push(error!(
+                    tool_button.span,
+                    "#[export_tool_button] requires struct attribute #[class(tool)]"
+                ));
+            }
+        }
+    }
+
     if struct_cfg.is_editor_plugin() {
         modifiers.push(quote! { with_editor_plugin })
     }
@@ -81,7 +94,17 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
     let inherits_macro_ident = format_ident!("inherit_from_{}__ensure_class_exists", base_ty);
 
     let prv = quote! { ::godot::private };
-    let godot_exports_impl = make_property_impl(class_name, &fields);
+
+    // Initial values of exported properties are only known if the constructor is generated.
+    let revert_arms = match struct_cfg.init_strategy {
+        InitStrategy::Generated => make_property_revert_arms(&fields),
+        InitStrategy::UserDefined | InitStrategy::Absent => vec![],
+    };
+    if !revert_arms.is_empty() {
+        modifiers.push(quote! { with_default_property_revert::<#class_name> });
+    }
+
+    let godot_exports_impl = make_property_impl(class_name, &fields, &revert_arms);
 
     let godot_withbase_impl = if let Some(Field { name, ty, .. }) = &fields.base_field {
         // Apply the span of the field's type so that errors show up on the field's type.
@@ -147,6 +170,22 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
         modifiers.push(quote! { with_tool })
     }
 
+    let abstract_const = if struct_cfg.is_abstract {
+        modifiers.push(quote! { with_virtual });
+        quote! { const __IS_ABSTRACT: bool = true; }
+    } else {
+        TokenStream::new()
+    };
+
+    let (threadsafe_const, threadsafe_impl) = if let Some(threadsafe_key) = &struct_cfg.threadsafe {
+        (
+            quote! { const __IS_THREADSAFE: bool = true; },
+            make_threadsafe_impl(class_name, threadsafe_key, &fields.all_fields),
+        )
+    } else {
+        (TokenStream::new(), TokenStream::new())
+    };
+
     // Declares a "funcs collection" struct that, for holds a constant for each #[func].
     // That constant maps the Rust name (constant ident) to the Godot registered name (string value).
     let funcs_collection_struct_name = format_funcs_collection_struct(class_name);
@@ -175,6 +214,9 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
                 let name: &'static ClassName = CLASS_NAME.get_or_init(|| #class_name_allocation);
                 *name
             }
+
+            #abstract_const
+            #threadsafe_const
         }
 
         unsafe impl ::godot::obj::Bounds for #class_name {
@@ -189,6 +231,7 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
         #godot_withbase_impl
         #godot_exports_impl
         #user_class_impl
+        #threadsafe_impl
         #init_expecter
         #visibility_macro
         #base_field_macro
@@ -302,6 +345,9 @@ struct ClassAttributes {
     init_strategy: InitStrategy,
     is_tool: bool,
     is_internal: bool,
+    is_abstract: bool,
+    /// Key of `#[class(threadsafe)]`, if present.
+    threadsafe: Option<Ident>,
     rename: Option<Ident>,
     deprecations: Vec<TokenStream>,
 }
@@ -343,6 +389,33 @@ fn make_godot_init_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
     }
 }
 
+fn make_threadsafe_impl(
+    class_name: &Ident,
+    threadsafe_key: &Ident,
+    all_fields: &[Field],
+) -> TokenStream {
+    // The base field is excluded: Base<T> panics when accessed outside the main thread, see __IS_THREADSAFE.
+    let field_checks = all_fields.iter().map(|Field { ty, .. }| {
+        quote_spanned! { ty.span()=>
+            ::godot::private::is_threadsafe_field::<#ty>();
+        }
+    });
+
+    // Compile error without the `experimental-threads` feature, which godot-macros has no knowledge of.
+    let feature_check = quote_spanned! { threadsafe_key.span()=>
+        ::godot::private::require_experimental_threads();
+    };
+
+    quote! {
+        const _: () = {
+            #feature_check
+            #( #field_checks )*
+        };
+
+        unsafe impl ::godot::obj::ThreadSafeClass for #class_name {}
+    }
+}
+
 fn make_onready_init(all_fields: &[Field]) -> TokenStream {
     let onready_fields = all_fields
         .iter()
@@ -502,6 +575,8 @@ fn parse_struct_attributes(class: &venial::Struct) -> ParseResult<ClassAttribute
     let mut init_strategy = InitStrategy::UserDefined;
     let mut is_tool = false;
     let mut is_internal = false;
+    let mut is_abstract = false;
+    let mut threadsafe = None;
     let mut rename: Option<Ident> = None;
     let mut deprecations = vec![];
 
@@ -524,6 +599,20 @@ fn parse_struct_attributes(class: &venial::Struct) -> ParseResult<ClassAttribute
             is_tool = true;
         }
 
+        // #[class(abstract)]
+        if let Some(key) = parser.handle_alone_with_span("abstract")? {
+            if matches!(init_strategy, InitStrategy::Absent) {
+                return bail!(
+                    key,
+                    "#[class(abstract)] requires a constructor, so that subclasses can be instantiated; remove `no_init`",
+                );
+            }
+            is_abstract = true;
+        }
+
+        // #[class(threadsafe)]
+        threadsafe = parser.handle_alone_with_span("threadsafe")?;
+
         // Deprecated #[class(editor_plugin)]
         if let Some(_attr_key) = parser.handle_alone_with_span("editor_plugin")? {
             deprecations.push(quote_spanned! { _attr_key.span()=>
@@ -568,6 +657,8 @@ fn parse_struct_attributes(class: &venial::Struct) -> ParseResult<ClassAttribute
         init_strategy,
         is_tool,
         is_internal,
+        is_abstract,
+        threadsafe,
         rename,
         deprecations,
     })
@@ -683,6 +774,13 @@ fn parse_fields(
             parser.finish()?;
         }
 
+        // #[export_category(name = ...)]
+        if let Some(mut parser) = KvParser::parse(&named_field.attributes, "export_category")? {
+            let category = FieldCategory::new_from_kv(&mut parser)?;
+            field.category = Some(category);
+            parser.finish()?;
+        }
+
         // #[export_group(name = ..., prefix = ...)]
         if let Some(mut parser) = KvParser::parse(&named_field.attributes, "export_group")? {
             let group = FieldGroup::new_from_kv(&mut parser)?;
@@ -697,6 +795,20 @@ fn parse_fields(
             parser.finish()?;
         }
 
+        // #[export_nested(name = ..., prefix = ...)]
+        if let Some(mut parser) = KvParser::parse(&named_field.attributes, "export_nested")? {
+            let nested = FieldGroup::new_nested_from_kv(&mut parser, &field.name)?;
+            field.nested = Some(nested);
+            parser.finish()?;
+        }
+
+        // #[export_tool_button(fn = ..., text = ..., icon = ...)]
+        if let Some(mut parser) = KvParser::parse(&named_field.attributes, "export_tool_button")? {
+            let tool_button = FieldToolButton::new_from_kv(&mut parser)?;
+            field.tool_button = Some(tool_button);
+            parser.finish()?;
+        }
+
         // #[var]
         if let Some(mut parser) = KvParser::parse(&named_field.attributes, "var")? {
             let mut var = FieldVar::new_from_kv(&mut parser)?;
@@ -735,14 +847,22 @@ fn parse_fields(
                 ));
             }
         } else {
-            if field.is_phantomvar {
+            if field.tool_button.is_some() {
+                validate_tool_button_field(&field, &mut errors);
+            } else if field.nested.is_some() {
+                validate_nested_field(&field, &mut errors);
+            } else if field.is_phantomvar {
                 validate_phantomvar_field(&field, &mut errors);
             }
 
+            validate_setter_hooks(&field, &mut errors);
+
             all_fields.push(field);
         }
     }
 
+    validate_field_placements(&all_fields, &mut errors);
+
     Ok(Fields {
         all_fields,
         base_field,
@@ -773,6 +893,20 @@ fn validate_base_field(field: &Field, errors: &mut Vec<Error>) {
         ));
     }
 
+    if let Some(tool_button) = field.tool_button.as_ref() {
+        errors.push(error!(
+            tool_button.span,
+            "base field cannot have the attribute #[export_tool_button]"
+        ));
+    }
+
+    if field.nested.is_some() {
+        errors.push(error!(
+            field.span,
+            "base field cannot have the attribute #[export_nested]"
+        ));
+    }
+
     if let Some(default_val) = field.default_val.as_ref() {
         errors.push(error!(
             default_val.span,
@@ -781,6 +915,54 @@ fn validate_base_field(field: &Field, errors: &mut Vec<Error>) {
     }
 }
 
+/// Keys that are enforced by the generated setter cannot be combined with a custom or omitted one.
+fn validate_setter_hooks(field: &Field, errors: &mut Vec<Error>) {
+    let setter = field
+        .var
+        .as_ref()
+        .map_or(&GetterSetter::Generated, |var| &var.setter);
+
+    if *setter == GetterSetter::Generated {
+        return;
+    }
+
+    if let Some(clamp) = field
+        .export
+        .as_ref()
+        .and_then(|export| export.clamp.as_ref())
+    {
+        errors.push(error!(
+            clamp,
+            "`clamp` requires a generated setter; restrict the value in the custom setter instead"
+        ));
+    }
+
+    let Some(var) = &field.var else {
+        return;
+    };
+
+    if let Some(validate) = &var.validate {
+        errors.push(error!(
+            validate,
+            "`validate` requires a generated setter; validate the value in the custom setter instead"
+        ));
+    }
+
+    if let Some(notify) = &var.notify {
+        errors.push(error!(
+            notify,
+            "`notify` requires a generated setter; call the method from the custom setter instead"
+        ));
+    }
+
+    if let Some(emit) = &var.emit {
+        errors.push(error!(
+            emit,
+            "`emit` requires a generated setter; emit the signal from the custom setter instead"
+        ));
+    }
+}
+
 fn validate_phantomvar_field(field: &Field, errors: &mut Vec<Error>) {
     let Some(field_var) = &field.var else {
         errors.push(error!(
@@ -824,6 +1006,58 @@ fn validate_phantomvar_field(field: &Field, errors: &mut Vec<Error>) {
     }
 }
 
+fn validate_tool_button_field(field: &Field, errors: &mut Vec<Error>) {
+    let Some(tool_button) = &field.tool_button else {
+        return;
+    };
+
+    // The property is backed by a generated getter, so there is no data to store.
+    if !field.is_phantomvar {
+        errors.push(error!(
+            field.ty.clone(),
+            "#[export_tool_button] requires field type `PhantomVar<Callable>`"
+        ));
+    }
+
+    if field.var.is_some() || field.export.is_some() {
+        errors.push(error!(
+            tool_button.span,
+            "#[export_tool_button] cannot be combined with #[var] or #[export]"
+        ));
+    }
+}
+
+/// Explicit placements sort the registration, which would break the order-based group attributes.
+fn validate_field_placements(fields: &[Field], errors: &mut Vec<Error>) {
+    let Some(placement) = fields
+        .iter()
+        .find_map(|field| field.export.as_ref()?.placement.as_ref())
+    else {
+        return;
+    };
+
+    let has_ordered_groups = fields
+        .iter()
+        .any(|field| field.category.is_some() || field.group.is_some() || field.subgroup.is_some());
+
+    if has_ordered_groups {
+        errors.push(error!(
+            placement.span,
+            "#[export(group, subgroup, order)] cannot be combined with #[export_category], #[export_group] or #[export_subgroup] in the same class"
+        ));
+    }
+}
+
+fn validate_nested_field(field: &Field, errors: &mut Vec<Error>) {
+    // The nested fields are registered individually; the field itself is not a property.
+    if field.var.is_some() || field.export.is_some() {
+        errors.push(error!(
+            field.span,
+            "#[export_nested] cannot be combined with #[var] or #[export]"
+        ));
+    }
+}
+
 fn handle_opposite_keys(
     parser: &mut KvParser,
     key: &str,
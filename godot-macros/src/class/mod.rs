@@ -23,6 +23,7 @@ mod data_models {
     #[cfg_attr(not(feature = "codegen-full"), allow(dead_code))]
     pub mod rpc;
     pub mod signal;
+    pub mod tool_button_export;
 }
 
 pub(crate) use data_models::constant::*;
@@ -149,7 +149,7 @@ impl CStyleEnumerator {
             _ => {
                 return bail!(
                     &enum_variant.fields,
-                    "GodotConvert only supports C-style enums"
+                    "#[godot(via = ...)] only supports C-style enums; use #[godot(dictionary)] or #[godot(array)] for variants with fields"
                 )
             }
         }
@@ -0,0 +1,192 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use proc_macro2::{Ident, TokenStream, TokenTree};
+use quote::quote;
+
+use crate::util::{self, bail, KvParser};
+use crate::ParseResult;
+
+/// Stores info from a struct with named fields, converted to/from a `VarDictionary` via `#[godot(dictionary)]`.
+pub struct DictionaryStruct {
+    /// All fields in declaration order, including skipped ones.
+    pub fields: Vec<ConvertField>,
+}
+
+impl DictionaryStruct {
+    /// Parses a struct with named fields, along with the per-field `#[godot(...)]` attributes.
+    pub fn parse_struct(struct_: &venial::Struct) -> ParseResult<Self> {
+        let venial::Fields::Named(named_fields) = &struct_.fields else {
+            return bail!(
+                &struct_.fields,
+                "#[godot(dictionary)] requires a struct with named fields"
+            );
+        };
+
+        let fields = named_fields
+            .fields
+            .iter()
+            .map(|(field, _punct)| ConvertField::parse_named(field))
+            .collect::<ParseResult<Vec<_>>>()?;
+
+        let mut seen_keys = std::collections::HashSet::new();
+        for field in fields.iter().filter(|f| !f.is_skipped) {
+            if !seen_keys.insert(field.key.as_str()) {
+                return bail!(
+                    &field.name,
+                    "duplicate dictionary key \"{}\" in #[godot(dictionary)] struct",
+                    field.key
+                );
+            }
+        }
+
+        Ok(Self { fields })
+    }
+
+    /// Fields that are stored in the dictionary (i.e. not `#[godot(skip)]`).
+    pub fn stored_fields(&self) -> impl Iterator<Item = &ConvertField> {
+        self.fields.iter().filter(|f| !f.is_skipped)
+    }
+}
+
+/// A single field of a [`DictionaryStruct`] or of a variant in a [`TaggedEnum`][super::TaggedEnum].
+pub struct ConvertField {
+    /// The Rust name of the field, or a synthesized binding name for tuple fields.
+    pub name: Ident,
+
+    /// The type of the field.
+    pub ty: venial::TypeExpr,
+
+    /// The dictionary key under which the field is stored. Defaults to the field name (or index for tuple fields), can be
+    /// changed with `#[godot(rename = ...)]`.
+    pub key: String,
+
+    /// Value used when the key is absent, from `#[godot(default)]` or `#[godot(default = expr)]`.
+    pub default: Option<FieldDefault>,
+
+    /// Whether the field is excluded from the dictionary, via `#[godot(skip)]`.
+    pub is_skipped: bool,
+}
+
+/// How a missing dictionary entry is filled in.
+pub enum FieldDefault {
+    /// `#[godot(default)]` -- uses `Default::default()`.
+    Trait,
+    /// `#[godot(default = expr)]` -- uses the given expression.
+    Expr(TokenStream),
+}
+
+impl ConvertField {
+    /// Parses a named field, stored under its name unless `#[godot(rename = ...)]` is given.
+    pub fn parse_named(field: &venial::NamedField) -> ParseResult<Self> {
+        // Raw identifiers such as `r#type` are stored under their plain name.
+        let key = field.name.to_string().trim_start_matches("r#").to_string();
+
+        Self::parse(&field.attributes, field.name.clone(), &field.ty, key, true)
+    }
+
+    /// Parses a tuple field, which is identified by its position and bound to `__field{index}` in generated code.
+    pub fn parse_positional(index: usize, field: &venial::TupleField) -> ParseResult<Self> {
+        let binding = util::ident(&format!("__field{index}"));
+
+        Self::parse(
+            &field.attributes,
+            binding,
+            &field.ty,
+            index.to_string(),
+            false,
+        )
+    }
+
+    fn parse(
+        attributes: &[venial::Attribute],
+        name: Ident,
+        ty: &venial::TypeExpr,
+        mut key: String,
+        allow_rename: bool,
+    ) -> ParseResult<Self> {
+        let mut default = None;
+        let mut is_skipped = false;
+
+        if let Some(mut parser) = KvParser::parse(attributes, "godot")? {
+            let is_renamed = match parser.handle_expr_with_key("rename")? {
+                Some((rename_key, _)) if !allow_rename => {
+                    return bail!(rename_key, "`rename` is only supported on named fields");
+                }
+                Some((_, rename)) => {
+                    key = parse_key(rename)?;
+                    true
+                }
+                None => false,
+            };
+
+            default = match parser.handle_any("default") {
+                None => None,
+                Some(None) => Some(FieldDefault::Trait),
+                Some(Some(value)) => Some(FieldDefault::Expr(value.expr()?)),
+            };
+
+            if let Some(skip) = parser.handle_alone_with_span("skip")? {
+                if is_renamed {
+                    return bail!(skip, "#[godot(skip)] cannot be combined with `rename`");
+                }
+
+                is_skipped = true;
+            }
+
+            parser.finish()?;
+        }
+
+        Ok(Self {
+            name,
+            ty: ty.clone(),
+            key,
+            default,
+            is_skipped,
+        })
+    }
+
+    /// Expression producing the value if the key is absent (or the field is skipped).
+    ///
+    /// Skipped fields without explicit default fall back to `Default::default()`.
+    pub fn default_expr(&self) -> Option<TokenStream> {
+        match &self.default {
+            Some(FieldDefault::Expr(expr)) => Some(expr.clone()),
+            Some(FieldDefault::Trait) => Some(quote! { ::std::default::Default::default() }),
+            None if self.is_skipped => Some(quote! { ::std::default::Default::default() }),
+            None => None,
+        }
+    }
+}
+
+/// Accepts both `key = ident` and `key = "string literal"`.
+pub(crate) fn parse_key(value: TokenStream) -> ParseResult<String> {
+    let mut tokens = value.into_iter();
+    let key = match tokens.next() {
+        Some(TokenTree::Ident(ident)) => ident.to_string(),
+        Some(TokenTree::Literal(lit)) => {
+            let lit_str = lit.to_string();
+            let Some(key) = lit_str
+                .strip_prefix('"')
+                .and_then(|s| s.strip_suffix('"'))
+                .filter(|s| !s.contains('\\'))
+            else {
+                return bail!(lit, "expected an identifier or a plain string literal");
+            };
+
+            key.to_string()
+        }
+        Some(other) => return bail!(other, "expected an identifier or a string literal"),
+        None => unreachable!("KvParser guarantees non-empty expression"),
+    };
+
+    if let Some(surplus) = tokens.next() {
+        return bail!(surplus, "expected a single identifier or string literal");
+    }
+
+    Ok(key)
+}
@@ -8,6 +8,7 @@
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::ToTokens;
 
+use super::dictionary_struct::parse_key;
 use crate::util::{bail, KvParser};
 use crate::ParseResult;
 
@@ -15,6 +16,10 @@ use crate::ParseResult;
 pub enum GodotAttribute {
     /// `#[godot(transparent)]`
     Transparent { span: Span },
+    /// `#[godot(dictionary)]` or `#[godot(dictionary, tag = key)]`
+    Dictionary { span: Span, tag_key: Option<String> },
+    /// `#[godot(array)]`
+    Array { span: Span },
     /// `#[godot(via = via_type)]`
     Via { span: Span, via_type: ViaType },
 }
@@ -35,6 +40,19 @@ impl GodotAttribute {
             return Ok(Self::Transparent { span });
         }
 
+        if parser.handle_alone("dictionary")? {
+            let tag_key = match parser.handle_expr("tag")? {
+                Some(tag) => Some(parse_key(tag)?),
+                None => None,
+            };
+
+            return Ok(Self::Dictionary { span, tag_key });
+        }
+
+        if parser.handle_alone("array")? {
+            return Ok(Self::Array { span });
+        }
+
         if let Some(via_type) = parser.handle_ident("via")? {
             return Ok(Self::Via {
                 span,
@@ -44,7 +62,7 @@ impl GodotAttribute {
 
         bail!(
             span,
-            "expected either `#[godot(transparent)]` or `#[godot(via = <via_type>)]`"
+            "expected one of `#[godot(transparent)]`, `#[godot(dictionary)]`, `#[godot(array)]` or `#[godot(via = <via_type>)]`"
         )
     }
 
@@ -54,6 +72,8 @@ impl GodotAttribute {
     pub fn span(&self) -> Span {
         match self {
             GodotAttribute::Transparent { span } => *span,
+            GodotAttribute::Dictionary { span, .. } => *span,
+            GodotAttribute::Array { span } => *span,
             GodotAttribute::Via { span, .. } => *span,
         }
     }
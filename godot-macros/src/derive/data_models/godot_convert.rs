@@ -6,11 +6,13 @@
  */
 
 use proc_macro2::{Ident, TokenStream};
-use quote::ToTokens;
+use quote::{quote, ToTokens};
 
 use super::c_style_enum::CStyleEnum;
+use super::dictionary_struct::DictionaryStruct;
 use super::godot_attribute::{GodotAttribute, ViaType};
 use super::newtype::NewtypeStruct;
+use super::tagged_enum::{TaggedEnum, TaggedRepr};
 use crate::util::bail;
 use crate::ParseResult;
 
@@ -72,8 +74,12 @@ impl GodotConvert {
 pub enum ConvertType {
     /// Deriving for a newtype struct.
     NewType { field: NewtypeStruct },
-    /// Deriving for an enum.
+    /// Deriving for a struct with named fields, mapped to a `VarDictionary`.
+    Dictionary { fields: DictionaryStruct },
+    /// Deriving for a C-style enum.
     Enum { variants: CStyleEnum, via: ViaType },
+    /// Deriving for an enum (possibly with data-carrying variants), identified by a tag.
+    TaggedEnum { enum_: TaggedEnum },
 }
 
 impl ConvertType {
@@ -81,28 +87,42 @@ impl ConvertType {
         let attribute = GodotAttribute::parse_attribute(&item)?;
 
         match &item {
-            venial::Item::Struct(struct_) => {
-                let GodotAttribute::Transparent { .. } = attribute else {
-                    return bail!(attribute.span(), "#[derive(GodotConvert)] on structs currently only works with #[godot(transparent)]");
-                };
-
-                Ok(Self::NewType {
+            venial::Item::Struct(struct_) => match attribute {
+                GodotAttribute::Transparent { .. } => Ok(Self::NewType {
                     field: NewtypeStruct::parse_struct(struct_)?,
-                })
-            }
-            venial::Item::Enum(enum_) => {
-                let GodotAttribute::Via { via_type, .. } = attribute else {
-                    return bail!(
-                        attribute.span(),
-                        "#[derive(GodotConvert)] on enums requires #[godot(via = ...)]"
-                    );
-                };
-
-                Ok(Self::Enum {
+                }),
+                GodotAttribute::Dictionary { tag_key: None, .. } => Ok(Self::Dictionary {
+                    fields: DictionaryStruct::parse_struct(struct_)?,
+                }),
+                GodotAttribute::Dictionary { tag_key: Some(_), .. } => bail!(
+                    attribute.span(),
+                    "`tag` is only supported for #[godot(dictionary)] on enums"
+                ),
+                GodotAttribute::Via { .. } | GodotAttribute::Array { .. } => bail!(
+                    attribute.span(),
+                    "#[derive(GodotConvert)] on structs requires #[godot(transparent)] or #[godot(dictionary)]"
+                ),
+            },
+            venial::Item::Enum(enum_) => match attribute {
+                GodotAttribute::Via { via_type, .. } => Ok(Self::Enum {
                     variants: CStyleEnum::parse_enum(enum_)?,
                     via: via_type,
-                })
-            }
+                }),
+                GodotAttribute::Dictionary { tag_key, .. } => {
+                    let tag_key = tag_key.unwrap_or_else(|| TaggedRepr::DEFAULT_TAG_KEY.to_string());
+
+                    Ok(Self::TaggedEnum {
+                        enum_: TaggedEnum::parse_enum(enum_, TaggedRepr::Dictionary { tag_key })?,
+                    })
+                }
+                GodotAttribute::Array { .. } => Ok(Self::TaggedEnum {
+                    enum_: TaggedEnum::parse_enum(enum_, TaggedRepr::Array)?,
+                }),
+                GodotAttribute::Transparent { span } => bail!(
+                    span,
+                    "#[derive(GodotConvert)] on enums requires #[godot(via = ...)], #[godot(dictionary)] or #[godot(array)]"
+                ),
+            },
             _ => unreachable!(), // already checked outside.
         }
     }
@@ -111,7 +131,12 @@ impl ConvertType {
     pub fn via_type(&self) -> TokenStream {
         match self {
             ConvertType::NewType { field } => field.ty.to_token_stream(),
+            ConvertType::Dictionary { .. } => quote! { ::godot::builtin::VarDictionary },
             ConvertType::Enum { via, .. } => via.to_token_stream(),
+            ConvertType::TaggedEnum { enum_ } => match enum_.repr {
+                TaggedRepr::Dictionary { .. } => quote! { ::godot::builtin::VarDictionary },
+                TaggedRepr::Array => quote! { ::godot::builtin::VariantArray },
+            },
         }
     }
 }
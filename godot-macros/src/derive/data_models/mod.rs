@@ -6,11 +6,15 @@
  */
 
 mod c_style_enum;
+mod dictionary_struct;
 mod godot_attribute;
 mod godot_convert;
 mod newtype;
+mod tagged_enum;
 
 pub use c_style_enum::*;
+pub use dictionary_struct::*;
 pub use godot_attribute::*;
 pub use godot_convert::*;
 pub use newtype::*;
+pub use tagged_enum::*;
@@ -0,0 +1,165 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use proc_macro2::Ident;
+
+use super::dictionary_struct::{parse_key, ConvertField};
+use crate::util::{bail, KvParser};
+use crate::ParseResult;
+
+/// Stores info from enums whose variants may carry data, converted via a tag identifying the variant.
+pub struct TaggedEnum {
+    /// How the tag and payload are laid out on the Godot side.
+    pub repr: TaggedRepr,
+
+    /// All variants, in order of declaration.
+    pub variants: Vec<TaggedVariant>,
+}
+
+/// Godot representation of a [`TaggedEnum`].
+pub enum TaggedRepr {
+    /// `#[godot(dictionary)]` -- `{ tag_key: "Variant", field: value, ... }`.
+    Dictionary { tag_key: String },
+
+    /// `#[godot(array)]` -- `["Variant", value, ...]`.
+    Array,
+}
+
+impl TaggedRepr {
+    /// Default key for the tag in `#[godot(dictionary)]` representation.
+    pub const DEFAULT_TAG_KEY: &'static str = "type";
+}
+
+/// Single variant of a [`TaggedEnum`].
+pub struct TaggedVariant {
+    /// The Rust name of the variant.
+    pub name: Ident,
+
+    /// The tag identifying the variant on the Godot side. Defaults to the variant name, can be changed with `#[godot(rename = ...)]`.
+    pub tag: String,
+
+    /// Shape and fields of the variant.
+    pub fields: TaggedFields,
+}
+
+/// Fields of a [`TaggedVariant`].
+pub enum TaggedFields {
+    Unit,
+    Named(Vec<ConvertField>),
+    Tuple(Vec<ConvertField>),
+}
+
+impl TaggedFields {
+    /// All fields, in order of declaration.
+    pub fn all(&self) -> &[ConvertField] {
+        match self {
+            TaggedFields::Unit => &[],
+            TaggedFields::Named(fields) | TaggedFields::Tuple(fields) => fields,
+        }
+    }
+}
+
+impl TaggedEnum {
+    /// Parses the enum together with the `#[godot(...)]` attributes on its variants and fields.
+    pub fn parse_enum(enum_: &venial::Enum, repr: TaggedRepr) -> ParseResult<Self> {
+        let variants = enum_
+            .variants
+            .items()
+            .map(|variant| TaggedVariant::parse_variant(variant, &repr))
+            .collect::<ParseResult<Vec<_>>>()?;
+
+        let mut seen_tags = std::collections::HashSet::new();
+        for variant in variants.iter() {
+            if !seen_tags.insert(variant.tag.as_str()) {
+                return bail!(
+                    &variant.name,
+                    "duplicate tag \"{}\" in #[derive(GodotConvert)] enum",
+                    variant.tag
+                );
+            }
+        }
+
+        Ok(Self { repr, variants })
+    }
+}
+
+impl TaggedVariant {
+    fn parse_variant(variant: &venial::EnumVariant, repr: &TaggedRepr) -> ParseResult<Self> {
+        if let Some(value) = &variant.value {
+            return bail!(
+                &value.value,
+                "explicit discriminants are only supported with #[godot(via = ...)]"
+            );
+        }
+
+        let mut tag = variant.name.to_string();
+        if let Some(mut parser) = KvParser::parse(&variant.attributes, "godot")? {
+            if let Some(rename) = parser.handle_expr("rename")? {
+                tag = parse_key(rename)?;
+            }
+
+            parser.finish()?;
+        }
+
+        let fields = match &variant.fields {
+            venial::Fields::Unit => TaggedFields::Unit,
+            venial::Fields::Named(named) => {
+                let fields = named
+                    .fields
+                    .iter()
+                    .map(|(field, _punct)| ConvertField::parse_named(field))
+                    .collect::<ParseResult<Vec<_>>>()?;
+
+                if let TaggedRepr::Dictionary { tag_key } = repr {
+                    Self::validate_dictionary_keys(&fields, tag_key)?;
+                }
+
+                TaggedFields::Named(fields)
+            }
+            venial::Fields::Tuple(tuple) => {
+                if let TaggedRepr::Dictionary { .. } = repr {
+                    return bail!(
+                        &variant.fields,
+                        "#[godot(dictionary)] requires unit or named-field variants; use #[godot(array)] for tuple variants"
+                    );
+                }
+
+                let fields = tuple
+                    .fields
+                    .iter()
+                    .enumerate()
+                    .map(|(index, (field, _punct))| ConvertField::parse_positional(index, field))
+                    .collect::<ParseResult<Vec<_>>>()?;
+
+                TaggedFields::Tuple(fields)
+            }
+        };
+
+        Ok(Self {
+            name: variant.name.clone(),
+            tag,
+            fields,
+        })
+    }
+
+    fn validate_dictionary_keys(fields: &[ConvertField], tag_key: &str) -> ParseResult<()> {
+        let mut seen_keys = std::collections::HashSet::new();
+        seen_keys.insert(tag_key);
+
+        for field in fields.iter().filter(|f| !f.is_skipped) {
+            if !seen_keys.insert(field.key.as_str()) {
+                return bail!(
+                    &field.name,
+                    "dictionary key \"{}\" is used more than once (the tag key is \"{tag_key}\")",
+                    field.key
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
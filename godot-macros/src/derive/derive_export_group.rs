@@ -0,0 +1,143 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::class::FieldExport;
+use crate::util::{bail, KvParser};
+use crate::ParseResult;
+
+/// Derives `ExportGroup` for a struct with named fields.
+///
+/// Each field annotated with `#[export]` is registered as an indexed property of the enclosing class; the index is the position among
+/// the exported fields.
+pub fn derive_export_group(item: venial::Item) -> ParseResult<TokenStream> {
+    let venial::Item::Struct(struct_) = item else {
+        return bail!(item, "#[derive(ExportGroup)] only supports structs");
+    };
+
+    if let Some(generic_params) = &struct_.generic_params {
+        return bail!(
+            generic_params,
+            "#[derive(ExportGroup)] does not support lifetimes or generic parameters"
+        );
+    }
+
+    let venial::Fields::Named(named_fields) = &struct_.fields else {
+        return bail!(
+            &struct_.fields,
+            "#[derive(ExportGroup)] requires a struct with named fields"
+        );
+    };
+
+    let mut registrations = Vec::new();
+    let mut get_arms = Vec::new();
+    let mut set_arms = Vec::new();
+
+    for (field, _punct) in named_fields.fields.iter() {
+        let Some(mut parser) = KvParser::parse(&field.attributes, "export")? else {
+            continue;
+        };
+
+        let export = FieldExport::new_from_kv(&mut parser)?;
+        parser.finish()?;
+
+        if let Some(placement) = &export.placement {
+            return bail!(
+                placement.span,
+                "#[derive(ExportGroup)] does not support keys `group`, `subgroup` and `order`"
+            );
+        }
+
+        if let Some(clamp) = &export.clamp {
+            return bail!(clamp, "#[derive(ExportGroup)] does not support key `clamp`");
+        }
+
+        if export.no_revert {
+            return bail!(
+                export.span,
+                "#[derive(ExportGroup)] does not support key `no_revert`"
+            );
+        }
+
+        let index = registrations.len() as i64;
+        let field_ident = &field.name;
+        let field_type = &field.ty;
+        let field_name = field_ident.to_string();
+
+        let hint = export.to_export_hint().unwrap_or_else(|| {
+            quote! { <FieldType as ::godot::register::property::Export>::export_hint() }
+        });
+
+        let usage_flags = match export.to_export_usage() {
+            Some(flags) => quote! {
+                #( ::godot::global::PropertyUsageFlags::#flags )|*
+            },
+            None => quote! { ::godot::global::PropertyUsageFlags::DEFAULT },
+        };
+
+        registrations.push(quote! {
+            {
+                // This type may be reused in #hint, in case of generic functions.
+                type FieldType = #field_type;
+                ::godot::register::private::register_export_indexed::<C, FieldType>(
+                    &format!("{prefix}{}", #field_name),
+                    getter_name,
+                    setter_name,
+                    #index,
+                    #hint,
+                    #usage_flags,
+                );
+            }
+        });
+
+        get_arms.push(quote! {
+            #index => ::godot::register::private::get_group_field(&self.#field_ident),
+        });
+
+        set_arms.push(quote! {
+            #index => ::godot::register::private::set_group_field(&mut self.#field_ident, #field_name, value),
+        });
+    }
+
+    if registrations.is_empty() {
+        return bail!(
+            &struct_.name,
+            "#[derive(ExportGroup)] requires at least one field with #[export]"
+        );
+    }
+
+    let name = &struct_.name;
+    let name_str = name.to_string();
+
+    Ok(quote! {
+        impl ::godot::register::property::ExportGroup for #name {
+            fn __register_group_properties<C: ::godot::obj::GodotClass>(
+                prefix: &str,
+                getter_name: &str,
+                setter_name: &str,
+            ) {
+                #( #registrations )*
+            }
+
+            fn __get_group_property(&self, index: i64) -> ::godot::builtin::Variant {
+                match index {
+                    #( #get_arms )*
+                    _ => unreachable!("invalid property index {index} for ExportGroup `{}`", #name_str),
+                }
+            }
+
+            fn __set_group_property(&mut self, index: i64, value: &::godot::builtin::Variant) {
+                match index {
+                    #( #set_arms )*
+                    _ => unreachable!("invalid property index {index} for ExportGroup `{}`", #name_str),
+                }
+            }
+        }
+    })
+}
@@ -8,7 +8,10 @@
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
 
-use crate::derive::data_models::{CStyleEnum, ConvertType, GodotConvert, NewtypeStruct, ViaType};
+use crate::derive::data_models::{
+    CStyleEnum, ConvertField, ConvertType, DictionaryStruct, GodotConvert, NewtypeStruct,
+    TaggedEnum, TaggedFields, TaggedRepr, ViaType,
+};
 use crate::derive::derive_godot_convert::EnumeratorExprCache;
 use crate::util;
 
@@ -24,6 +27,10 @@ pub fn make_fromgodot(convert: &GodotConvert, cache: &mut EnumeratorExprCache) -
     match data {
         ConvertType::NewType { field } => make_fromgodot_for_newtype_struct(name, field),
 
+        ConvertType::Dictionary { fields } => make_fromgodot_for_dictionary_struct(name, fields),
+
+        ConvertType::TaggedEnum { enum_ } => make_fromgodot_for_tagged_enum(name, enum_),
+
         ConvertType::Enum {
             variants,
             via: ViaType::GString { .. },
@@ -52,6 +59,134 @@ fn make_fromgodot_for_newtype_struct(name: &Ident, field: &NewtypeStruct) -> Tok
     }
 }
 
+/// Derives `FromGodot` for structs with named fields, read from dictionary entries.
+fn make_fromgodot_for_dictionary_struct(name: &Ident, struct_: &DictionaryStruct) -> TokenStream {
+    let field_inits = struct_.fields.iter().map(|field| {
+        let field_name = &field.name;
+        let value = make_field_value(field, FieldSource::DictKey);
+
+        quote! { #field_name: #value }
+    });
+
+    quote! {
+        impl ::godot::meta::FromGodot for #name {
+            fn try_from_godot(via: ::godot::builtin::VarDictionary) -> ::std::result::Result<Self, ::godot::meta::error::ConvertError> {
+                Ok(Self {
+                    #( #field_inits, )*
+                })
+            }
+        }
+    }
+}
+
+/// Derives `FromGodot` for enums identified by a tag, with fields read from dictionary entries or array elements.
+fn make_fromgodot_for_tagged_enum(name: &Ident, enum_: &TaggedEnum) -> TokenStream {
+    let (via_type, read_tag) = match &enum_.repr {
+        TaggedRepr::Dictionary { tag_key } => (
+            quote! { ::godot::builtin::VarDictionary },
+            quote! { ::godot::private::dict_field_required::<::godot::builtin::GString>(&via, #tag_key)? },
+        ),
+        TaggedRepr::Array => (
+            quote! { ::godot::builtin::VariantArray },
+            quote! { ::godot::private::array_elem_required::<::godot::builtin::GString>(&via, 0)? },
+        ),
+    };
+
+    let arms = enum_.variants.iter().map(|variant| {
+        let variant_name = &variant.name;
+        let tag = &variant.tag;
+
+        // Array payload starts after the tag; skipped fields don't occupy an element.
+        let mut next_index = 1;
+        let values: Vec<TokenStream> = variant
+            .fields
+            .all()
+            .iter()
+            .map(|field| {
+                let source = match &enum_.repr {
+                    TaggedRepr::Dictionary { .. } => FieldSource::DictKey,
+                    TaggedRepr::Array => {
+                        let index = next_index;
+                        if !field.is_skipped {
+                            next_index += 1;
+                        }
+                        FieldSource::ArrayIndex(index)
+                    }
+                };
+
+                make_field_value(field, source)
+            })
+            .collect();
+
+        let construct = match &variant.fields {
+            TaggedFields::Unit => quote! { Self::#variant_name },
+            TaggedFields::Named(fields) => {
+                let field_names = fields.iter().map(|f| &f.name);
+                quote! { Self::#variant_name { #( #field_names: #values, )* } }
+            }
+            TaggedFields::Tuple(_) => quote! { Self::#variant_name( #( #values, )* ) },
+        };
+
+        quote! { #tag => Ok(#construct), }
+    });
+
+    let enum_name_str = name.to_string();
+
+    quote! {
+        impl ::godot::meta::FromGodot for #name {
+            fn try_from_godot(via: #via_type) -> ::std::result::Result<Self, ::godot::meta::error::ConvertError> {
+                let tag = #read_tag;
+
+                match tag.to_string().as_str() {
+                    #( #arms )*
+                    _ => Err(::godot::private::enum_tag_error(#enum_name_str, tag)),
+                }
+            }
+        }
+    }
+}
+
+/// Where a field of a dictionary struct or tagged enum is read from.
+#[derive(Copy, Clone)]
+enum FieldSource {
+    /// Dictionary entry under the field's key.
+    DictKey,
+    /// Array element at the given index.
+    ArrayIndex(usize),
+}
+
+/// Expression converting a single field, respecting `#[godot(default)]` and `#[godot(skip)]`.
+fn make_field_value(field: &ConvertField, source: FieldSource) -> TokenStream {
+    let field_ty = &field.ty;
+
+    let (required_fn, or_else_fn, key) = match source {
+        FieldSource::DictKey => {
+            let key = &field.key;
+            (
+                quote! { dict_field_required },
+                quote! { dict_field_or_else },
+                quote! { #key },
+            )
+        }
+        FieldSource::ArrayIndex(index) => (
+            quote! { array_elem_required },
+            quote! { array_elem_or_else },
+            quote! { #index },
+        ),
+    };
+
+    match (field.is_skipped, field.default_expr()) {
+        (true, Some(default)) => default,
+        (false, Some(default)) => quote! {
+            ::godot::private::#or_else_fn::<#field_ty>(&via, #key, || #default)?
+        },
+        (false, None) => quote! {
+            ::godot::private::#required_fn::<#field_ty>(&via, #key)?
+        },
+        (true, None) => unreachable!("skipped fields always have a default"),
+    }
+}
+
 /// Derives `FromGodot` for enums with a via type of integers.
 fn make_fromgodot_for_int_enum(
     name: &Ident,
@@ -8,7 +8,10 @@
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
 
-use crate::derive::data_models::{CStyleEnum, ConvertType, GodotConvert, NewtypeStruct, ViaType};
+use crate::derive::data_models::{
+    CStyleEnum, ConvertType, DictionaryStruct, GodotConvert, NewtypeStruct, TaggedEnum,
+    TaggedFields, TaggedRepr, ViaType,
+};
 use crate::derive::derive_godot_convert::EnumeratorExprCache;
 
 /// Creates a `ToGodot` impl for the given `GodotConvert`.
@@ -23,6 +26,10 @@ pub fn make_togodot(convert: &GodotConvert, cache: &mut EnumeratorExprCache) ->
     match data {
         ConvertType::NewType { field } => make_togodot_for_newtype_struct(name, field),
 
+        ConvertType::Dictionary { fields } => make_togodot_for_dictionary_struct(name, fields),
+
+        ConvertType::TaggedEnum { enum_ } => make_togodot_for_tagged_enum(name, enum_),
+
         ConvertType::Enum {
             variants,
             via: ViaType::GString { .. },
@@ -51,6 +58,100 @@ fn make_togodot_for_newtype_struct(name: &Ident, field: &NewtypeStruct) -> Token
     }
 }
 
+/// Derives `ToGodot` for structs with named fields, stored as dictionary entries.
+fn make_togodot_for_dictionary_struct(name: &Ident, struct_: &DictionaryStruct) -> TokenStream {
+    let (keys, field_names): (Vec<_>, Vec<_>) = struct_
+        .stored_fields()
+        .map(|field| (&field.key, &field.name))
+        .unzip();
+
+    quote! {
+        impl ::godot::meta::ToGodot for #name {
+            type Pass = ::godot::meta::ByValue;
+
+            fn to_godot(&self) -> Self::Via {
+                let mut dict = ::godot::builtin::VarDictionary::new();
+                #(
+                    dict.set(#keys, ::godot::meta::ToGodot::to_variant(&self.#field_names));
+                )*
+                dict
+            }
+        }
+    }
+}
+
+/// Derives `ToGodot` for enums identified by a tag, with fields stored as dictionary entries or array elements.
+fn make_togodot_for_tagged_enum(name: &Ident, enum_: &TaggedEnum) -> TokenStream {
+    let arms = enum_.variants.iter().map(|variant| {
+        let variant_name = &variant.name;
+        let tag = &variant.tag;
+        let stored_fields = variant.fields.all().iter().filter(|f| !f.is_skipped);
+
+        let pattern = match &variant.fields {
+            TaggedFields::Unit => quote! { Self::#variant_name },
+            TaggedFields::Named(fields) => {
+                let bindings = fields.iter().filter(|f| !f.is_skipped).map(|f| &f.name);
+                quote! { Self::#variant_name { #( #bindings, )* .. } }
+            }
+            TaggedFields::Tuple(fields) => {
+                let bindings = fields.iter().map(|f| {
+                    if f.is_skipped {
+                        quote! { _ }
+                    } else {
+                        let binding = &f.name;
+                        quote! { #binding }
+                    }
+                });
+                quote! { Self::#variant_name( #( #bindings, )* ) }
+            }
+        };
+
+        let body = match &enum_.repr {
+            TaggedRepr::Dictionary { tag_key } => {
+                let (keys, bindings): (Vec<_>, Vec<_>) =
+                    stored_fields.map(|f| (&f.key, &f.name)).unzip();
+
+                quote! {
+                    let mut dict = ::godot::builtin::VarDictionary::new();
+                    dict.set(#tag_key, #tag);
+                    #(
+                        dict.set(#keys, ::godot::meta::ToGodot::to_variant(#bindings));
+                    )*
+                    dict
+                }
+            }
+            TaggedRepr::Array => {
+                let bindings = stored_fields.map(|f| &f.name);
+
+                quote! {
+                    let mut array = ::godot::builtin::VariantArray::new();
+                    array.push(&::godot::meta::ToGodot::to_variant(&#tag));
+                    #(
+                        array.push(&::godot::meta::ToGodot::to_variant(#bindings));
+                    )*
+                    array
+                }
+            }
+        };
+
+        quote! {
+            #pattern => { #body }
+        }
+    });
+
+    quote! {
+        impl ::godot::meta::ToGodot for #name {
+            type Pass = ::godot::meta::ByValue;
+
+            fn to_godot(&self) -> Self::Via {
+                match self {
+                    #( #arms )*
+                }
+            }
+        }
+    }
+}
+
 /// Derives `ToGodot` for enums with a via type of integers.
 fn make_togodot_for_int_enum(
     name: &Ident,
@@ -40,7 +40,8 @@ pub fn derive_var(item: venial::Item) -> ParseResult<TokenStream> {
 
 /// Make an appropriate property hint implementation.
 ///
-/// For newtype structs we just defer to the wrapped type. For enums we use `PropertyHint::ENUM` with an appropriate hint string.
+/// For newtype structs we just defer to the wrapped type, dictionary structs and tagged enums use the hint of their `Via` type.
+/// For C-style enums we use `PropertyHint::ENUM` with an appropriate hint string.
 fn create_property_hint_impl(convert: &GodotConvert) -> TokenStream {
     use super::data_models::{ConvertType, ViaType};
 
@@ -51,6 +52,17 @@ fn create_property_hint_impl(convert: &GodotConvert) -> TokenStream {
                 <#ty as ::godot::register::property::Var>::var_hint()
             }
         }
+        ConvertType::Dictionary { .. } => {
+            quote! {
+                <::godot::builtin::VarDictionary as ::godot::register::property::Var>::var_hint()
+            }
+        }
+        ConvertType::TaggedEnum { .. } => {
+            let via_type = convert.convert_type.via_type();
+            quote! {
+                <#via_type as ::godot::register::property::Var>::var_hint()
+            }
+        }
         ConvertType::Enum { variants, via } => {
             let hint_string = match via {
                 ViaType::GString { .. } => variants.to_string_hint(),
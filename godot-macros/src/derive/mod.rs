@@ -9,12 +9,14 @@
 
 mod data_models;
 mod derive_export;
+mod derive_export_group;
 mod derive_from_godot;
 mod derive_godot_convert;
 mod derive_to_godot;
 mod derive_var;
 
 pub(crate) use derive_export::*;
+pub(crate) use derive_export_group::*;
 pub(crate) use derive_from_godot::*;
 pub(crate) use derive_godot_convert::*;
 pub(crate) use derive_to_godot::*;
@@ -39,7 +39,9 @@ pub fn document_struct(
 
     let members = fields
         .iter()
-        .filter(|field| field.var.is_some() || field.export.is_some())
+        .filter(|field| {
+            field.var.is_some() || field.export.is_some() || field.tool_button.is_some()
+        })
         .filter_map(format_member_xml)
         .collect::<String>();
 
@@ -243,6 +243,7 @@ use crate::util::{bail, ident, KvParser};
 ///
 /// ```
 /// # use godot::prelude::*;
+/// # use godot::classes::{Button, TouchScreenButton};
 /// #[derive(GodotClass)]
 /// # #[class(init)]
 /// struct MyStruct {
@@ -281,10 +282,35 @@ use crate::util::{bail, ident, KvParser};
 ///     // @export_flags("A:1", "B:2", "AB:3")
 ///     #[export(flags = (A = 1, B = 2, AB = 3))]
 ///     flags: u32,
+///
+///     // @export_flags_avoidance
+///     #[export(flags_avoidance)]
+///     avoidance_layers: u32,
+///
+///     // @export_node_path("Button", "TouchScreenButton")
+///     #[export(node_path = (Button, TouchScreenButton))]
+///     button_path: NodePath,
+///
+///     // @export_custom(PROPERTY_HINT_ENUM_SUGGESTION, "Fast,Slow", PROPERTY_USAGE_DEFAULT | PROPERTY_USAGE_READ_ONLY)
+///     #[export(custom = (hint = ENUM_SUGGESTION, hint_string = "Fast,Slow", usage_flags = [DEFAULT, READ_ONLY]))]
+///     speed: GString,
 /// }
 ///
 /// ```
 ///
+/// The classes in `node_path` are Rust types, so they must be in scope. For `@export_custom`, the `hint` is a constant of the
+/// [`PropertyHint`] enum, and `usage_flags` are constants of [`PropertyUsageFlags`] (`DEFAULT` if omitted). `hint_string` is passed
+/// verbatim, which can be used for custom hint strings of typed arrays, e.g. `hint = TYPE_STRING, hint_string = "2/1:0,100"`.
+///
+/// Some property hints have no dedicated GDScript annotation, but are still available as `#[export]` keys:
+///
+/// | Rust attribute                                       | Property hint   | Field types                 |
+/// |------------------------------------------------------|-----------------|-----------------------------|
+/// | `#[export(password)]`                                | `PASSWORD`      | `GString`                   |
+/// | `#[export(expression)]`                              | `EXPRESSION`    | `GString`                   |
+/// | `#[export(locale_id)]`                               | `LOCALE_ID`     | `GString`                   |
+/// | `#[export(input_name)]`<br>`#[export(input_name = (show_builtin, loose_mode))]` | `INPUT_NAME` | `StringName`, `GString` |
+///
 /// Most values in syntax such as `key = value` can be arbitrary expressions. For example, you can use constants, function calls or
 /// other Rust expressions that are valid in that context.
 ///
@@ -303,13 +329,64 @@ use crate::util::{bail, ident, KvParser};
 /// }
 /// ```
 ///
+/// Hints on typed arrays and packed arrays can be applied to each element instead of the field itself, by adding the `elem` key. This
+/// corresponds to GDScript annotations on typed arrays, e.g. `@export_range(0, 100) var values: Array[float]`. It works with all hints
+/// that are available for the element type, and requires Godot 4.3 or later. Hints such as `file` or `node_path` already apply to array
+/// elements automatically and don't need `elem`.
+///
+/// ```
+/// # #[cfg(since_api = "4.3")]
+/// # mod conditional {
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// # #[class(init)]
+/// struct MyStruct {
+///     // @export_range(0, 100) var percentages: Array[float]
+///     #[export(range = (0.0, 100.0), elem)]
+///     percentages: Array<f32>,
+///
+///     // @export_enum("Small", "Medium", "Large") var sizes: PackedInt32Array
+///     #[export(enum = (Small, Medium, Large), elem)]
+///     sizes: PackedInt32Array,
+/// }
+/// # }
+/// ```
+///
 /// It is possible to group your exported properties inside the Inspector with the `#[export_group(name = "...", prefix =  "...")]` attribute.
 /// Every exported property after this attribute will be added to the group. Start a new group or use `#[export_group(name = "")]` (with an empty name) to break out.
 ///
-/// Groups cannot be nested but subgroups can be declared with an `#[export_subgroup]` attribute.
+/// Groups cannot be nested but subgroups can be declared with an `#[export_subgroup]` attribute. Above groups, properties can be
+/// organized in categories using `#[export_category(name = "...")]`, like GDScript's `@export_category`.
 ///
 /// GDExtension groups and subgroups follow the same rules as the gdscript ones.
 ///
+/// Alternatively, the group can be declared on each field with `#[export(group = "...", subgroup = "...")]`. In this case, the properties
+/// are registered in the order given by the groups and not by the struct layout: properties without a group come first, followed by each
+/// group (in the order of their first field), with the properties outside of a subgroup before the subgroups. The key `order = N` moves a
+/// property after the ones with a lower `order` (default 0) within its group or subgroup. This cannot be combined with
+/// `#[export_category]`, `#[export_group]` or `#[export_subgroup]` in the same class.
+///
+/// ```
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// # #[class(init)]
+/// struct Enemy {
+///     #[export(group = "Movement", order = 1)]
+///     speed: f32,
+///
+///     #[export(group = "Combat")]
+///     damage: i32,
+///
+///     // Shown before `speed`, despite being declared after it.
+///     #[export(group = "Movement")]
+///     jump_height: f32,
+/// }
+/// ```
+///
+/// Fields of plain Rust structs can be exported as a group, too. Derive [`ExportGroup`](derive.ExportGroup.html) for the struct and
+/// annotate the field with `#[export_nested]`. Each `#[export]` field of the struct becomes a property named `{field}_{nested_field}`,
+/// inside a group named after the field. Name and prefix can be customized with `#[export_nested(name = "...", prefix = "...")]`.
+///
 /// <div class="warning">
 /// Nesting subgroups with the slash separator `/` <strong>outside</strong> the group is not supported and might crash the editor.
 /// </div>
@@ -359,6 +436,137 @@ use crate::util::{bail, ident, KvParser};
 /// }
 ///```
 ///
+/// ## Reverting to initial values
+///
+/// In classes with `#[class(init)]`, the Inspector shows a "revert" button next to each exported property whose value differs from
+/// the initial one, i.e. from `#[init(val = ...)]` or `Default::default()`. Pressing it resets the property to this value.
+///
+/// The initial value is evaluated once per class and property, when the editor first queries it, and is then cached. Object properties
+/// (e.g. `Gd<Resource>` or `Option<Gd<Node>>`) are not reverted, since all instances would share the same object. Use
+/// `#[export(no_revert)]` to opt out of reverting for other fields, e.g. if the initial value is not constant. Properties with a custom
+/// getter `#[var(get = ...)]` are not reverted either. An `I*::property_get_revert()` override takes precedence over the initial values;
+/// if it returns `None`, the initial value is used.
+///
+/// ```
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init)]
+/// struct Enemy {
+///     // Can be reverted to 100.
+///     #[export]
+///     #[init(val = 100)]
+///     health: i32,
+///
+///     // Can be reverted to "".
+///     #[export]
+///     name: GString,
+///
+///     // Cannot be reverted.
+///     #[export(no_revert)]
+///     seed: i64,
+/// }
+/// ```
+///
+/// ## Validating values
+///
+/// Generated setters can enforce constraints on the assigned values. They apply to all writes through the setter, i.e. from the
+/// Inspector, from GDScript and from Rust code calling `set_*()`. Both keys require a generated setter.
+///
+/// - `#[export(range = ..., clamp)]` restricts values to the range; values outside are replaced with the nearest bound. With `or_greater`
+///   or `or_less`, the respective bound is not enforced. NaN is replaced with the lower bound. Only numeric types are supported.
+/// - `#[var(validate = method)]` calls `fn method(&self, value: &T) -> bool` before assigning a value. If it returns `false`, the value
+///   is rejected with a warning (`godot_warn!`) and the previous value is kept. `T` is the type passed to the setter, i.e.
+///   [`GodotConvert::Via`](../meta/trait.GodotConvert.html#associatedtype.Via), which is the field type itself for most types.
+///
+/// ```
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init)]
+/// struct Player {
+///     // Assigning 1.5 stores 1.0.
+///     #[export(range = (0.0, 1.0), clamp)]
+///     volume: f32,
+///
+///     // Assigning an empty name keeps the previous one.
+///     #[export]
+///     #[var(validate = is_valid_name)]
+///     name: GString,
+/// }
+///
+/// impl Player {
+///     fn is_valid_name(&self, name: &GString) -> bool {
+///         !name.is_empty()
+///     }
+/// }
+/// ```
+///
+/// ## Change notifications
+///
+/// Generated setters can react to changes of the value, which is useful to update UI or dependent state:
+///
+/// - `#[var(notify = method)]` calls `fn method(&mut self, old: &T, new: &T)`.
+/// - `#[var(emit = signal)]` emits the `#[signal]` with parameters `(old: T, new: T)`. This requires a `Base<T>` field.
+///
+/// Both are only invoked if the setter actually changes the value, i.e. if the old and new value are not equal (`T: PartialEq`); the new
+/// value is read back after assignment, so it accounts for `clamp`. Like validators, they require a generated setter, and `T` is the type
+/// passed to the setter.
+///
+/// ```
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Player {
+///     #[export]
+///     #[var(notify = on_health_changed, emit = health_changed)]
+///     health: i32,
+///
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl Player {
+///     #[signal]
+///     fn health_changed(old: i32, new: i32);
+/// }
+///
+/// impl Player {
+///     fn on_health_changed(&mut self, old: &i32, new: &i32) {
+///         godot_print!("Health changed from {old} to {new}");
+///     }
+/// }
+/// ```
+///
+/// ## Tool buttons -- `#[export_tool_button]`
+///
+/// Since Godot 4.4, a button can be shown in the Inspector, which invokes a method when clicked. This is the counterpart to GDScript's
+/// `@export_tool_button` annotation. The field must have type [`PhantomVar<Callable>`](../register/property/struct.PhantomVar.html),
+/// and the class must be a `#[class(tool)]`. The `fn` key names a `#[func]` of the class, `text` is the button label, and the optional
+/// `icon` is the name of an editor theme icon.
+///
+/// ```no_run
+/// # #[cfg(since_api = "4.4")]
+/// # mod conditional {
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, tool, base=Node)]
+/// struct LevelTools {
+///     // @export_tool_button("Rebuild", "Reload") var rebuild_button = rebuild
+///     #[export_tool_button(fn = rebuild, text = "Rebuild", icon = "Reload")]
+///     rebuild_button: PhantomVar<Callable>,
+///
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl LevelTools {
+///     #[func]
+///     fn rebuild(&mut self) {
+///         godot_print!("Rebuilding level...");
+///     }
+/// }
+/// # }
+/// ```
+///
 ///
 /// ## Low-level property hints and usage
 ///
@@ -466,6 +674,71 @@ use crate::util::{bail, ident, KvParser};
 /// Even though this class is a `Node` and it has an init function, it still won't show up in the editor as a node you can add to a scene
 /// because we have added a `hidden` key to the class. This will also prevent it from showing up in documentation.
 ///
+/// ## Abstract classes
+///
+/// A class marked with `#[class(abstract)]` is registered as an abstract base class (called "virtual" in Godot). It is still listed in
+/// the editor's "Create New Node" dialog, but greyed out, and can be extended by GDScript classes. Methods and signals declared in
+/// `#[godot_api]` blocks are available to those subclasses.
+///
+/// Abstract classes cannot be default-constructed from Rust: `Gd::new_alloc()`, `Gd::new_gd()` and `Gd::default()` fail to compile.
+/// A constructor (`init`) is still needed, because Godot runs it when instantiating a subclass.
+///
+/// Note that this error is only raised once the constructor call is monomorphized, i.e. during `cargo build`, not `cargo check`.
+/// Abstract classes still implement `NewAlloc` resp. `NewGd`, so generic code bounded by these traits accepts them, and the error points
+/// to the concrete instantiation.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(base=Node, init, abstract)]
+/// pub struct Enemy {
+///     #[var]
+///     health: i32,
+/// }
+///
+/// #[godot_api]
+/// impl Enemy {
+///     #[signal]
+///     fn died();
+///
+///     #[func]
+///     fn take_damage(&mut self, amount: i32) {
+///         self.health -= amount;
+///     }
+/// }
+/// ```
+///
+/// Constructing an abstract class from Rust does not compile:
+/// ```compile_fail
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(base=Node, init, abstract)]
+/// pub struct Enemy {}
+///
+/// let enemy: Gd<Enemy> = Enemy::new_alloc();
+/// # enemy.free();
+/// ```
+///
+/// ## Thread-safe classes
+///
+/// With the `experimental-threads` feature, a class marked with `#[class(threadsafe)]` can be wrapped in a
+/// [`ThreadSafeGd<T>`](../obj/struct.ThreadSafeGd.html), which is `Send + Sync`. The following is checked at compile time:
+/// - The `experimental-threads` feature is enabled.
+/// - The class is ref-counted, i.e. inherits `RefCounted`.
+/// - All fields except the `Base<T>` field are `Send + Sync`.
+///
+/// The base object is only accessible on the main thread: `base()`, `base_mut()`, `to_gd()` and any other use of the `Base<T>` field
+/// panic on other threads. If the last reference is dropped on another thread, the object is destroyed there; see `ThreadSafeGd` docs.
+///
+/// ```ignore
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, threadsafe, base=RefCounted)]
+/// pub struct PathCache {
+///     paths: std::collections::HashMap<String, Vec<Vector2>>,
+/// }
+/// ```
+///
 /// # Further field customization
 ///
 /// ## Fine-grained inference hints
@@ -543,11 +816,25 @@ use crate::util::{bail, ident, KvParser};
     alias = "export",
     alias = "tool",
     alias = "rename",
-    alias = "internal"
+    alias = "internal",
+    alias = "abstract",
+    alias = "threadsafe"
 )]
 #[proc_macro_derive(
     GodotClass,
-    attributes(class, base, hint, var, export, export_group, export_subgroup, init)
+    attributes(
+        class,
+        base,
+        hint,
+        var,
+        export,
+        export_category,
+        export_group,
+        export_subgroup,
+        export_nested,
+        export_tool_button,
+        init
+    )
 )]
 pub fn derive_godot_class(input: TokenStream) -> TokenStream {
     translate(input, class::derive_godot_class)
@@ -909,6 +1196,36 @@ pub fn derive_godot_class(input: TokenStream) -> TokenStream {
 ///
 /// Please refer to [the book](https://godot-rust.github.io/book/register/constants.html).
 ///
+/// Godot classes can only have integer constants. `#[constant]` on an associated constant registers such a constant, accessible as
+/// `MyClass.CONSTANT` in GDScript; its value must be convertible to `i64`. Constants of other types, e.g. `Vector2` or `Color`, can be
+/// registered as a static method with the constant's name using `#[constant(as_method)]`, which GDScript calls as `MyClass.CONSTANT()`.
+/// For values that cannot be computed in a `const` context, such as `StringName`, `#[constant]` can also be placed on a function
+/// without parameters.
+///
+/// ```
+/// # use godot::prelude::*;
+/// # #[derive(GodotClass)]
+/// # #[class(init)]
+/// # struct Level {}
+/// #[godot_api]
+/// impl Level {
+///     // Level.MAX_PLAYERS
+///     #[constant]
+///     const MAX_PLAYERS: i32 = 4;
+///
+///     // Level.SPAWN_POINT()
+///     #[constant(as_method)]
+///     const SPAWN_POINT: Vector2 = Vector2::new(10.0, 20.0);
+///
+///     // Level.DEFAULT_MAP()
+///     #[constant]
+///     #[allow(non_snake_case)]
+///     fn DEFAULT_MAP() -> StringName {
+///         StringName::from("forest")
+///     }
+/// }
+/// ```
+///
 /// # Multiple inherent `impl` blocks
 ///
 /// Just like with regular structs, you can have multiple inherent `impl` blocks. This can be useful for code organization or when you want to generate code from a proc-macro.
@@ -1027,14 +1344,14 @@ pub fn godot_dyn(_meta: TokenStream, input: TokenStream) -> TokenStream {
     translate(input, class::attribute_godot_dyn)
 }
 
-/// Derive macro for [`GodotConvert`](../meta/trait.GodotConvert.html) on structs.
+/// Derive macro for [`GodotConvert`](../meta/trait.GodotConvert.html) on structs and enums.
 ///
 /// This derive macro also derives [`ToGodot`](../meta/trait.ToGodot.html) and [`FromGodot`](../meta/trait.FromGodot.html).
 ///
 /// # Choosing a Via type
 ///
 /// To specify the `Via` type that your type should be converted to, you must use the `godot` attribute.
-/// There are currently two modes supported.
+/// The following modes are supported.
 ///
 /// ## `transparent`
 ///
@@ -1094,6 +1411,53 @@ pub fn godot_dyn(_meta: TokenStream, input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// ## `dictionary`
+///
+/// If you specify `#[godot(dictionary)]` on a struct with named fields, the struct is converted to a [`VarDictionary`][dict] with one
+/// entry per field. Keys are the field names, values are converted using each field's own `ToGodot`/`FromGodot` implementation.
+///
+/// Fields accept the following `#[godot(...)]` attributes:
+/// - `rename = key` or `rename = "key"`: use a different dictionary key than the field name.
+/// - `default`: if the key is absent when converting from Godot, use `Default::default()`.
+/// - `default = expr`: if the key is absent when converting from Godot, use `expr`.
+/// - `skip`: do not store the field in the dictionary; it is initialized with its default (`Default::default()` unless
+///   `default = expr` is specified).
+///
+/// Converting from Godot fails with a [`ConvertError`][error] naming the key, if a required key is missing or if a value cannot be
+/// converted to the field type. Extra keys in the dictionary are ignored.
+///
+/// ### Example
+///
+/// ```no_run
+/// use godot::prelude::*;
+///
+/// #[derive(GodotConvert)]
+/// #[godot(dictionary)]
+/// struct PlayerRecord {
+///     name: GString,
+///     #[godot(rename = "hit_points")]
+///     hp: i32,
+///     #[godot(default = 1)]
+///     level: i64,
+///     #[godot(skip)]
+///     cached_rank: Option<u32>,
+/// }
+///
+/// let record = PlayerRecord {
+///     name: "Godette".into(),
+///     hp: 100,
+///     level: 3,
+///     cached_rank: None,
+/// };
+///
+/// let dict = record.to_godot();
+/// assert_eq!(dict.at("hit_points"), 100.to_variant());
+/// assert!(!dict.contains_key("cached_rank"));
+/// ```
+///
+/// [dict]: ../builtin/type.VarDictionary.html
+/// [error]: ../meta/error/struct.ConvertError.html
+///
 /// ## `via = <type>`
 ///
 /// For c-style enums, that is enums where all the variants are unit-like, you can use `via = <type>` to convert the enum into that
@@ -1154,6 +1518,50 @@ pub fn godot_dyn(_meta: TokenStream, input: TokenStream) -> TokenStream {
 /// assert_eq!(MyEnum::B.to_godot(), 10);
 /// assert_eq!(MyEnum::C.to_godot(), 11);
 /// ```
+///
+/// ## Enums with data: `dictionary` and `array`
+///
+/// Enums whose variants carry fields are converted using a _tag_ -- a string identifying the variant (by default its name, can be
+/// changed per variant with `#[godot(rename = ...)]`). Two representations are available:
+///
+/// - `#[godot(dictionary)]` converts to a [`VarDictionary`][dict] of the form `{ "type": "Variant", "field": value, ... }`.
+///   The tag key can be changed with `#[godot(dictionary, tag = "kind")]`. Variants must be unit-like or have named fields.
+/// - `#[godot(array)]` converts to a [`VariantArray`][varray] of the form `["Variant", value, ...]`, with fields in declaration order.
+///   Works for unit, tuple and named-field variants.
+///
+/// Fields support the same `default` and `skip` attributes as structs using `#[godot(dictionary)]`; with `array`, `default` applies
+/// if the array is too short. Converting from Godot fails with a [`ConvertError`][error] if the tag does not match any variant.
+///
+/// ### Example
+///
+/// ```no_run
+/// use godot::prelude::*;
+///
+/// #[derive(GodotConvert)]
+/// #[godot(dictionary)]
+/// enum GameEvent {
+///     Hit { damage: i32, source: Gd<Node> },
+///     #[godot(rename = "heal")]
+///     Healed { amount: i32 },
+///     Died,
+/// }
+///
+/// #[derive(GodotConvert)]
+/// #[godot(array)]
+/// enum Command {
+///     Move(Vector2),
+///     Wait { seconds: f64 },
+///     Stop,
+/// }
+///
+/// let dict = GameEvent::Healed { amount: 5 }.to_godot();
+/// assert_eq!(dict, vdict! { "type": "heal", "amount": 5 });
+///
+/// let array = Command::Move(Vector2::new(1.0, 2.0)).to_godot();
+/// assert_eq!(array, varray!["Move", Vector2::new(1.0, 2.0)]);
+/// ```
+///
+/// [varray]: ../builtin/type.VariantArray.html
 #[proc_macro_derive(GodotConvert, attributes(godot))]
 pub fn derive_godot_convert(input: TokenStream) -> TokenStream {
     translate(input, derive::derive_godot_convert)
@@ -1176,6 +1584,18 @@ pub fn derive_export(input: TokenStream) -> TokenStream {
     translate(input, derive::derive_export)
 }
 
+/// Derive macro for [`ExportGroup`](../register/property/trait.ExportGroup.html) on structs with named fields.
+///
+/// Fields annotated with `#[export]` are registered as properties of the class that contains the struct in a field annotated with
+/// `#[export_nested]`. All keys of [`#[export]`](derive.GodotClass.html#export-properties--export) are supported. Fields without
+/// `#[export]` are not registered.
+///
+/// See the [`ExportGroup`](../register/property/trait.ExportGroup.html) trait for an example.
+#[proc_macro_derive(ExportGroup, attributes(export))]
+pub fn derive_export_group(input: TokenStream) -> TokenStream {
+    translate(input, derive::derive_export_group)
+}
+
 /// Similar to `#[test]`, but runs an integration test with Godot.
 ///
 /// Transforms the `fn` into one returning `bool` (success of the test), which must be called explicitly.
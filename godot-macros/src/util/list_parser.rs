@@ -230,6 +230,11 @@ impl ListParser {
         }
     }
 
+    /// The span of the end of the list, usually its closing parenthesis. Useful to report missing elements.
+    pub(crate) fn span_close(&self) -> Span {
+        self.span_close
+    }
+
     /// Ensure all values have been consumed.
     pub fn finish(&mut self) -> ParseResult<()> {
         if let Some(kv) = self.pop_next() {
@@ -99,11 +99,20 @@
 //!   truly available. Function calls may thus panic only at runtime, possibly in deeply nested code paths.
 //!   This feature is not yet thread-safe and can thus not be combined with `experimental-threads`.<br><br>
 //!
+//! * **`debug-borrows`**
+//!
+//!   Records the source location of every outstanding `bind()`/`bind_mut()` guard. When a bind fails because the instance is already
+//!   bound, the panic message (or [`BorrowError`][crate::meta::error::BorrowError]) lists where the conflicting guards were created.
+//!   This adds a small overhead to every bind, so it is intended for debugging re-entrancy problems.<br><br>
+//!
 //! * **`experimental-threads`**
 //!
 //!   Experimental threading support. This adds synchronization to access the user instance in `Gd<T>` and disables several single-thread checks.
 //!   The safety aspects are not ironed out yet; there is a high risk of unsoundness at the moment.
-//!   As this evolves, it is very likely that the API becomes stricter.<br><br>
+//!   As this evolves, it is very likely that the API becomes stricter.
+//!
+//!   Enables APIs for multithreaded code: `task::run_on_main_thread()` and `task::run_on_main_thread_async()` to send work from other
+//!   threads to the main thread, as well as `#[class(threadsafe)]` and `obj::ThreadSafeGd<T>`.<br><br>
 //!
 //! * **`experimental-wasm`**
 //!
@@ -140,7 +149,9 @@
 //!
 //! * **`serde`**
 //!
-//!   Implement the [serde](https://serde.rs/) traits `Serialize` and `Deserialize` traits for certain built-in types.
+//!   Implement the [serde](https://serde.rs/) traits `Serialize` and `Deserialize` traits for built-in value types, including
+//!   `Variant`, `Array`, `Dictionary` and packed arrays. `Variant` is stored in a self-describing tagged form; variants holding objects,
+//!   callables, signals or RIDs cannot be serialized.
 //!   The serialized representation underlies **no stability guarantees** and may change at any time, even without a SemVer-breaking change.
 //!
 
@@ -195,7 +206,9 @@ pub mod register {
     pub use godot_core::registry::signal::re_export::*;
     #[cfg(feature = "__codegen-full")]
     pub use godot_core::registry::RpcConfig;
-    pub use godot_macros::{godot_api, godot_dyn, Export, GodotClass, GodotConvert, Var};
+    pub use godot_macros::{
+        godot_api, godot_dyn, Export, ExportGroup, GodotClass, GodotConvert, Var,
+    };
 
     /// Re-exports used by proc-macro API.
     #[doc(hidden)]
@@ -18,11 +18,13 @@ pub use super::meta::error::{ConvertError, IoError};
 pub use super::meta::{FromGodot, GodotConvert, ToGodot};
 pub use super::obj::{
     AsDyn, Base, DynGd, DynGdMut, DynGdRef, Gd, GdMut, GdRef, GodotClass, Inherits, InstanceId,
-    OnEditor, OnReady,
+    OnEditor, OnReady, WeakGd,
 };
-pub use super::register::property::{Export, PhantomVar, Var};
+pub use super::register::property::{Export, ExportGroup, PhantomVar, Var};
 // Re-export macros.
-pub use super::register::{godot_api, godot_dyn, Export, GodotClass, GodotConvert, Var};
+pub use super::register::{
+    godot_api, godot_dyn, Export, ExportGroup, GodotClass, GodotConvert, Var,
+};
 pub use super::tools::{load, save, try_load, try_save, GFile};
 
 // Make trait methods available.
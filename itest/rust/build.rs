@@ -192,7 +192,7 @@ fn collect_inputs() -> Vec<Input> {
         [-7, "godot", false, Vector2i(-77, 88)],
         varray![-7, "godot", false, Vector2i::new(-77, 88)]);
 
-    pushs!(inputs; Dictionary, Dictionary,
+    pushs!(inputs; Dictionary, VarDictionary,
         r#"{"key": 83, -3: Vector2(1, 2), 0.03: true}"#,
         vdict! { "key": 83, (-3): Vector2::new(1.0, 2.0), 0.03: true },
         true, true, None
@@ -475,6 +475,36 @@ fn generate_property_template(inputs: &[Input]) -> PropertyTests {
         quote! {
             #[export(storage)]
             export_storage: GString,
+            #[export(storage)]
+            export_storage_resource_array: Array<Gd<Resource>>,
+
+            #[export(node_path = (godot::classes::Button))]
+            export_node_path_button_array: Array<NodePath>,
+
+            // Hints without dedicated GDScript annotation, compared against @export_custom.
+            #[export(password)]
+            export_password: GString,
+            #[export(expression)]
+            export_expression: GString,
+            #[export(locale_id)]
+            export_locale_id: GString,
+            #[export(input_name = (show_builtin))]
+            export_input_name_show_builtin: StringName,
+
+            #[export(custom = (hint = ENUM_SUGGESTION, hint_string = "Fast,Slow"))]
+            export_custom_enum_suggestion: GString,
+            #[export(custom = (hint = TYPE_STRING, hint_string = "2/1:0,100", usage_flags = [DEFAULT, READ_ONLY]))]
+            export_custom_int_range_array: Array<i64>,
+
+            // Per-element hints on typed and packed arrays.
+            #[export(range = (0.0, 100.0), elem)]
+            export_range_elem_array: Array<f64>,
+            #[export(range = (0.0, 10.0), elem)]
+            export_range_elem_parray: PackedInt32Array,
+            #[export(enum = (A, B = 3), elem)]
+            export_enum_elem_array: Array<i64>,
+            #[export(multiline, elem)]
+            export_multiline_elem_array: Array<GString>,
 
             #[export(file)]
             export_file_array: Array<GString>,
@@ -541,8 +571,8 @@ fn generate_property_template(inputs: &[Input]) -> PropertyTests {
             export_exp_easing: f64,
             #[export(color_no_alpha)]
             export_color_no_alpha: Color,
-            // Not implemented
-            //  #[export(node_path = ("Button", "TouchScreenButton"))] export_node_path_button_touch_screen_button: NodePath,
+            #[export(node_path = (godot::classes::Button, godot::classes::TouchScreenButton))]
+            export_node_path_button_touch_screen_button: NodePath,
             #[export(flags = (Fire, Water, Earth, Wind))]
             export_flags_fire_water_earth_wind: i64,
             #[export(flags = (Self = 4, Allies = 8, Foes = 16))]
@@ -559,6 +589,8 @@ fn generate_property_template(inputs: &[Input]) -> PropertyTests {
             export_flags_3d_render: i64,
             #[export(flags_3d_navigation)]
             export_flags_3d_navigation: i64,
+            #[export(flags_avoidance)]
+            export_flags_avoidance: i64,
             #[export(enum = (Warrior, Magician, Thief))]
             export_enum_int_warrior_magician_thief: i64,
             #[export(enum = (Slow = 30, Average = 60, VeryFast = 200))]
@@ -594,6 +626,7 @@ fn generate_property_template(inputs: &[Input]) -> PropertyTests {
 @export_flags_3d_physics var export_flags_3d_physics: int
 @export_flags_3d_render var export_flags_3d_render: int
 @export_flags_3d_navigation var export_flags_3d_navigation: int
+@export_flags_avoidance var export_flags_avoidance: int
 @export_enum("Warrior", "Magician", "Thief") var export_enum_int_warrior_magician_thief: int
 @export_enum("Slow:30", "Average:60", "VeryFast:200") var export_enum_int_slow_30_average_60_very_fast_200: int
 @export_enum("Rebecca", "Mary", "Leah") var export_enum_string_rebecca_mary_leah: String
@@ -602,6 +635,18 @@ fn generate_property_template(inputs: &[Input]) -> PropertyTests {
     // Only available in Godot 4.3+.
     let advanced_exports_4_3 = r#"
 @export_storage var export_storage: String
+@export_storage var export_storage_resource_array: Array[Resource]
+@export_node_path("Button") var export_node_path_button_array: Array[NodePath]
+@export_custom(PROPERTY_HINT_PASSWORD, "") var export_password: String
+@export_custom(PROPERTY_HINT_EXPRESSION, "") var export_expression: String
+@export_custom(PROPERTY_HINT_LOCALE_ID, "") var export_locale_id: String
+@export_custom(PROPERTY_HINT_INPUT_NAME, "show_builtin") var export_input_name_show_builtin: StringName
+@export_custom(PROPERTY_HINT_ENUM_SUGGESTION, "Fast,Slow") var export_custom_enum_suggestion: String
+@export_custom(PROPERTY_HINT_TYPE_STRING, "2/1:0,100", PROPERTY_USAGE_DEFAULT | PROPERTY_USAGE_READ_ONLY) var export_custom_int_range_array: Array[int]
+@export_range(0, 100) var export_range_elem_array: Array[float]
+@export_range(0, 10) var export_range_elem_parray: PackedInt32Array
+@export_enum("A", "B:3") var export_enum_elem_array: Array[int]
+@export_multiline var export_multiline_elem_array: Array[String]
 @export_file var export_file_array: Array[String]
 @export_file var export_file_parray: PackedStringArray
 @export_file("*.txt") var export_file_wildcard_array: Array[String]
@@ -402,7 +402,7 @@ fn untyped_array_return_from_godot_func() {
 
 // Conditional, so we don't need Texture2DArray > ImageTextureLayered > TextureLayered > Texture in minimal codegen.
 // Potential alternatives (search for "typedarray::" in extension_api.json):
-// - ClassDB::class_get_signal_list() -> Array<Dictionary>
+// - ClassDB::class_get_signal_list() -> Array<VarDictionary>
 // - Compositor::set_compositor_effects( Array<Gd<Compositor>> )
 #[cfg(feature = "codegen-full-experimental")]
 #[itest]
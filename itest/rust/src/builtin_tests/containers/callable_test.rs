@@ -373,7 +373,7 @@ pub mod custom_callable {
     use std::hash::Hash;
     use std::sync::{Arc, Mutex};
 
-    use godot::builtin::{Dictionary, RustCallable};
+    use godot::builtin::{RustCallable, VarDictionary};
     use godot::prelude::Signal;
     use godot::sys;
     use godot::sys::GdextBuild;
@@ -564,7 +564,7 @@ pub mod custom_callable {
         let a = Callable::from_custom(Adder::new_tracked(3, at.clone()));
         let b = Callable::from_custom(Adder::new_tracked(3, bt.clone()));
 
-        let mut dict = Dictionary::new();
+        let mut dict = VarDictionary::new();
 
         dict.set(a, "hello");
         assert_eq!(hash_count(&at), 1, "hash needed for a dict key");
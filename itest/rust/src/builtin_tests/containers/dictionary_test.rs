@@ -7,47 +7,58 @@
 
 use std::collections::{HashMap, HashSet};
 
-use godot::builtin::{varray, vdict, Dictionary, Variant, VariantType};
+use godot::builtin::{
+    varray, vdict, Color, Dictionary, GString, VarDictionary, Variant, VariantType,
+};
 use godot::classes::RefCounted;
 use godot::meta::{ElementType, FromGodot, ToGodot};
-use godot::obj::NewGd;
+use godot::obj::{Gd, NewGd};
 
 use crate::framework::{assert_match, create_gdscript, expect_panic, itest};
 
 #[itest]
 fn dictionary_default() {
-    assert_eq!(Dictionary::default().len(), 0);
+    assert_eq!(VarDictionary::default().len(), 0);
 }
 
 #[itest]
 fn dictionary_new() {
-    assert_eq!(Dictionary::new().len(), 0);
+    assert_eq!(VarDictionary::new().len(), 0);
 }
 
 #[itest]
 fn dictionary_from_iterator() {
-    let dictionary = Dictionary::from_iter([("foo", 1), ("bar", 2)]);
+    let dictionary = Dictionary::<GString, i64>::from_iter([("foo".into(), 1), ("bar".into(), 2)]);
 
     assert_eq!(dictionary.len(), 2);
-    assert_eq!(dictionary.get("foo"), Some(1.to_variant()), "key = \"foo\"");
-    assert_eq!(dictionary.get("bar"), Some(2.to_variant()), "key = \"bar\"");
+    assert_eq!(dictionary.get("foo"), Some(1), "key = \"foo\"");
+    assert_eq!(dictionary.get("bar"), Some(2), "key = \"bar\"");
 
-    let dictionary = Dictionary::from_iter([(1, "foo"), (2, "bar")]);
+    let dictionary = Dictionary::<i64, GString>::from_iter([(1, "foo".into()), (2, "bar".into())]);
 
     assert_eq!(dictionary.len(), 2);
-    assert_eq!(dictionary.get(1), Some("foo".to_variant()), "key = 1");
+    assert_eq!(dictionary.get(1), Some("foo".into()), "key = 1");
+    assert_eq!(dictionary.get(2), Some("bar".into()), "key = 2");
+
+    let dictionary = VarDictionary::from_iter([
+        ("foo".to_variant(), 1.to_variant()),
+        (2.to_variant(), "bar".to_variant()),
+    ]);
+
+    assert_eq!(dictionary.len(), 2);
+    assert_eq!(dictionary.get("foo"), Some(1.to_variant()), "key = \"foo\"");
     assert_eq!(dictionary.get(2), Some("bar".to_variant()), "key = 2");
 }
 
 #[itest]
 fn dictionary_from() {
-    let dictionary = Dictionary::from(&HashMap::from([("foo", 1), ("bar", 2)]));
+    let dictionary = VarDictionary::from(&HashMap::from([("foo", 1), ("bar", 2)]));
 
     assert_eq!(dictionary.len(), 2);
     assert_eq!(dictionary.get("foo"), Some(1.to_variant()), "key = \"foo\"");
     assert_eq!(dictionary.get("bar"), Some(2.to_variant()), "key = \"bar\"");
 
-    let dictionary = Dictionary::from(&HashMap::from([(1, "foo"), (2, "bar")]));
+    let dictionary = VarDictionary::from(&HashMap::from([(1, "foo"), (2, "bar")]));
 
     assert_eq!(dictionary.len(), 2);
     assert_eq!(dictionary.get(1), Some("foo".to_variant()), "key = \"foo\"");
@@ -102,7 +113,7 @@ fn dictionary_clone() {
 
     #[allow(clippy::redundant_clone)]
     let clone = dictionary.clone();
-    Dictionary::from_variant(&clone.get("bar").unwrap()).set("final", 4);
+    VarDictionary::from_variant(&clone.get("bar").unwrap()).set("final", 4);
     assert_eq!(subdictionary.get("final"), Some(4.to_variant()));
 }
 
@@ -148,7 +159,7 @@ fn dictionary_duplicate_deep() {
         "bar": subdictionary.clone()
     };
     let clone = dictionary.duplicate_deep();
-    Dictionary::from_variant(&clone.get("bar").unwrap()).set("baz", 4);
+    VarDictionary::from_variant(&clone.get("bar").unwrap()).set("baz", 4);
     assert_eq!(
         subdictionary.get("baz"),
         Some(true.to_variant()),
@@ -168,7 +179,7 @@ fn dictionary_duplicate_shallow() {
     };
 
     let mut clone = dictionary.duplicate_shallow();
-    Dictionary::from_variant(&clone.get("bar").unwrap()).set("baz", 4);
+    VarDictionary::from_variant(&clone.get("bar").unwrap()).set("baz", 4);
     assert_eq!(
         subdictionary.get("baz"),
         Some(4.to_variant()),
@@ -455,7 +466,7 @@ fn dictionary_iter() {
 #[itest]
 fn dictionary_iter_size_hint() {
     // Test a completely empty dict.
-    let dictionary = Dictionary::new();
+    let dictionary = VarDictionary::new();
     let iter = dictionary.iter_shared();
     assert_eq!(iter.size_hint(), (0, Some(0)));
 
@@ -493,11 +504,11 @@ fn dictionary_iter_size_hint() {
 
 #[itest]
 fn dictionary_iter_equals_big() {
-    let dictionary: Dictionary = (0..1000).zip(0..1000).collect();
+    let dictionary: Dictionary<i64, i64> = (0..1000).zip(0..1000).collect();
     let map: HashMap<i64, i64> = (0..1000).zip(0..1000).collect();
-    let collected_map: HashMap<i64, i64> = dictionary.iter_shared().typed::<i64, i64>().collect();
+    let collected_map: HashMap<i64, i64> = dictionary.iter_shared().collect();
     assert_eq!(map, collected_map);
-    let collected_dictionary: Dictionary = collected_map.into_iter().collect();
+    let collected_dictionary: Dictionary<i64, i64> = collected_map.into_iter().collect();
     assert_eq!(dictionary, collected_dictionary);
 }
 
@@ -550,7 +561,7 @@ fn dictionary_iter_insert_after_completion() {
 
 #[itest]
 fn dictionary_iter_big() {
-    let dictionary: Dictionary = (0..256).zip(0..256).collect();
+    let dictionary: Dictionary<i64, i64> = (0..256).zip(0..256).collect();
     let mut dictionary2 = dictionary.clone();
     let mut iter = dictionary.iter_shared();
 
@@ -559,7 +570,7 @@ fn dictionary_iter_big() {
             for _ in 0..16 {
                 iter.next();
             }
-            dictionary2.set("a", "b");
+            dictionary2.set(-1, -2);
         }
         dictionary2.clear();
         dictionary2.extend((0..64).zip(0..64));
@@ -620,7 +631,7 @@ fn dictionary_iter_panics() {
     expect_panic(
         "Dictionary containing integer keys should not be convertible to a HashSet<String>",
         || {
-            let dictionary: Dictionary = (0..10).zip(0..).collect();
+            let dictionary: Dictionary<i64, i64> = (0..10).zip(0..).collect();
             let _set: HashSet<String> = dictionary.keys_shared().typed::<String>().collect();
         },
     );
@@ -628,7 +639,7 @@ fn dictionary_iter_panics() {
     expect_panic(
         "Dictionary containing integer entries should not be convertible to a HashMap<String,String>",
         || {
-            let dictionary: Dictionary = (0..10).zip(0..).collect();
+            let dictionary: Dictionary<i64, i64> = (0..10).zip(0..).collect();
             let _set: HashMap<String,String> = dictionary.iter_shared().typed::<String,String>().collect();
         },
     );
@@ -728,7 +739,7 @@ fn dictionary_iter_erase() {
 
 #[itest]
 fn dictionary_should_format_with_display() {
-    let d = Dictionary::new();
+    let d = VarDictionary::new();
     assert_eq!(format!("{d}"), "{  }");
 
     let d = vdict! {
@@ -745,7 +756,7 @@ fn dictionary_element_type() {
     use godot::meta::ElementType;
 
     // Test untyped dictionary
-    let untyped = Dictionary::new();
+    let untyped = VarDictionary::new();
     assert!(
         matches!(untyped.key_element_type(), ElementType::Untyped),
         "expected untyped key for Dictionary"
@@ -754,6 +765,73 @@ fn dictionary_element_type() {
         matches!(untyped.value_element_type(), ElementType::Untyped),
         "expected untyped value for Dictionary"
     );
+
+    // Test typed dictionary
+    let typed = Dictionary::<GString, i64>::new();
+    assert_match!(
+        typed.key_element_type(),
+        ElementType::Builtin(VariantType::STRING)
+    );
+    assert_match!(
+        typed.value_element_type(),
+        ElementType::Builtin(VariantType::INT)
+    );
+
+    // Element types survive a round-trip through Variant.
+    let variant = typed.to_variant();
+    let untyped_view = variant.try_to::<VarDictionary>();
+    assert!(
+        untyped_view.is_err(),
+        "typed dictionary must not convert to VarDictionary"
+    );
+
+    let roundtrip = variant.to::<Dictionary<GString, i64>>();
+    assert_match!(
+        roundtrip.key_element_type(),
+        ElementType::Builtin(VariantType::STRING)
+    );
+}
+
+#[itest]
+fn dictionary_typed_access() {
+    let mut dict = Dictionary::<GString, i64>::new();
+    dict.set("apples", 12);
+    dict.set("pears", 7);
+
+    assert_eq!(dict.len(), 2);
+    assert_eq!(dict.at("apples"), 12);
+    assert_eq!(dict.get("pears"), Some(7));
+    assert_eq!(dict.get("plums"), None);
+    assert!(dict.contains_key("apples"));
+    assert_eq!(dict.find_key_by_value(7), Some("pears".into()));
+
+    assert_eq!(dict.insert("pears", 9), Some(7));
+    assert_eq!(dict.insert("plums", 3), None);
+    assert_eq!(dict.remove("apples"), Some(12));
+    assert_eq!(dict.remove("apples"), None);
+
+    let entries: HashMap<GString, i64> = dict.iter_shared().collect();
+    let expected = HashMap::from([("pears".into(), 9), ("plums".into(), 3)]);
+    assert_eq!(entries, expected);
+
+    let keys: HashSet<GString> = dict.keys_shared().collect();
+    assert_eq!(keys, HashSet::from(["pears".into(), "plums".into()]));
+}
+
+#[itest]
+fn dictionary_typed_from_godot() {
+    let typed = Dictionary::<GString, i64>::from_iter([("one".into(), 1)]);
+    let back = typed.to_variant().to::<Dictionary<GString, i64>>();
+    assert_eq!(back, typed);
+
+    // Mismatched entries are rejected, both with engine-side (4.4+) and Rust-side typing.
+    let result = vdict! { 1: "one" }
+        .to_variant()
+        .try_to::<Dictionary<GString, i64>>();
+    assert!(
+        result.is_err(),
+        "{{ 1: \"one\" }} -> Dictionary<GString, i64>"
+    );
 }
 
 #[itest]
@@ -783,15 +861,28 @@ func variant_script_dict() -> Dictionary[Variant, CustomScriptForDictionaries]:
     let mut object = RefCounted::new_gd();
     object.set_script(&gdscript.to_variant());
 
+    // Typed dictionaries cannot be converted to untyped ones.
+    let result = object
+        .call("builtin_variant_dict", &[])
+        .try_to::<VarDictionary>();
+    assert!(
+        result.is_err(),
+        "Dictionary[String, Variant] -> VarDictionary"
+    );
+
     // Test all 4 ElementType variants in alternating key/value pattern.
 
     // 1) Dictionary.
-    let dict = object.call("variant_variant_dict", &[]).to::<Dictionary>();
+    let dict = object
+        .call("variant_variant_dict", &[])
+        .to::<VarDictionary>();
     assert_match!(dict.key_element_type(), ElementType::Untyped);
     assert_match!(dict.value_element_type(), ElementType::Untyped);
 
     // 2) Dictionary[String, Variant].
-    let dict = object.call("builtin_variant_dict", &[]).to::<Dictionary>();
+    let dict = object
+        .call("builtin_variant_dict", &[])
+        .to::<Dictionary<GString, Variant>>();
     assert_match!(
         dict.key_element_type(),
         ElementType::Builtin(VariantType::STRING)
@@ -799,7 +890,9 @@ func variant_script_dict() -> Dictionary[Variant, CustomScriptForDictionaries]:
     assert_match!(dict.value_element_type(), ElementType::Untyped);
 
     // 3) Dictionary[Color, RefCounted].
-    let dict = object.call("builtin_class_dict", &[]).to::<Dictionary>();
+    let dict = object
+        .call("builtin_class_dict", &[])
+        .to::<Dictionary<Color, Gd<RefCounted>>>();
     assert_match!(
         dict.key_element_type(),
         ElementType::Builtin(VariantType::COLOR)
@@ -808,7 +901,9 @@ func variant_script_dict() -> Dictionary[Variant, CustomScriptForDictionaries]:
     assert_eq!(class_name.to_string(), "RefCounted");
 
     // 4) Dictionary[Variant, CustomScriptForDictionaries].
-    let dict = object.call("variant_script_dict", &[]).to::<Dictionary>();
+    let dict = object
+        .call("variant_script_dict", &[])
+        .to::<Dictionary<Variant, Gd<RefCounted>>>();
     assert_match!(dict.key_element_type(), ElementType::Untyped);
     assert_match!(dict.value_element_type(), ElementType::ScriptClass(script));
     let script = script.script().expect("script object should be alive");
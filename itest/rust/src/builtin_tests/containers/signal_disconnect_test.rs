@@ -8,7 +8,7 @@
 use godot::builtin::{Callable, Signal};
 use godot::classes::Object;
 use godot::obj::{Base, Gd, NewAlloc};
-use godot::register::{godot_api, ConnectHandle, GodotClass};
+use godot::register::{godot_api, ConnectHandle, ConnectionSet, GodotClass};
 
 use crate::framework::{expect_debug_panic_or_release_ok, expect_panic, itest};
 
@@ -159,6 +159,120 @@ fn test_handle_after_freeing_receiver() {
     test_freed_nodes_handles(false);
 }
 
+#[itest]
+fn guard_disconnects_on_drop() {
+    let obj = SignalDisc::new_alloc();
+
+    let guard = obj
+        .signals()
+        .my_signal()
+        .connect_self(SignalDisc::increment_self)
+        .into_guard();
+
+    assert!(guard.is_connected());
+    obj.signals().my_signal().emit();
+    assert_eq!(obj.bind().counter, 1);
+
+    drop(guard);
+    assert!(!has_connections(&obj));
+
+    obj.signals().my_signal().emit();
+    assert_eq!(obj.bind().counter, 1);
+
+    obj.free();
+}
+
+#[itest]
+fn guard_into_handle_keeps_connection() {
+    let obj = SignalDisc::new_alloc();
+
+    let guard = obj
+        .signals()
+        .my_signal()
+        .connect_self(SignalDisc::increment_self)
+        .into_guard();
+
+    let handle = guard.into_handle();
+    assert!(has_connections(&obj));
+
+    handle.disconnect();
+    obj.free();
+}
+
+#[itest]
+fn guard_after_freeing_broadcaster() {
+    let broadcaster = SignalDisc::new_alloc();
+    let receiver = SignalDisc::new_alloc();
+
+    let guard = broadcaster
+        .signals()
+        .my_signal()
+        .connect_other(&receiver, SignalDisc::increment_self)
+        .into_guard();
+
+    broadcaster.free();
+    assert!(!guard.is_connected());
+
+    // Must not panic.
+    drop(guard);
+    receiver.free();
+}
+
+#[itest]
+fn connection_set_disconnect_all() {
+    let broadcaster = SignalDisc::new_alloc();
+    let receiver = SignalDisc::new_alloc();
+
+    let mut connections = ConnectionSet::new();
+    connections.add(
+        broadcaster
+            .signals()
+            .my_signal()
+            .connect_self(SignalDisc::increment_self),
+    );
+    connections.add(
+        broadcaster
+            .signals()
+            .my_signal()
+            .connect_other(&receiver, SignalDisc::increment_self),
+    );
+    assert_eq!(connections.len(), 2);
+
+    broadcaster.signals().my_signal().emit();
+    assert_eq!(broadcaster.bind().counter, 1);
+    assert_eq!(receiver.bind().counter, 1);
+
+    connections.disconnect_all();
+    assert!(connections.is_empty());
+    assert!(!has_connections(&broadcaster));
+
+    // Disconnecting again is a no-op.
+    connections.disconnect_all();
+
+    broadcaster.free();
+    receiver.free();
+}
+
+#[itest]
+fn connection_set_disconnects_on_drop() {
+    let obj = SignalDisc::new_alloc();
+
+    let connections: ConnectionSet = [
+        obj.signals()
+            .my_signal()
+            .connect_self(SignalDisc::increment_self),
+        obj.signals().my_signal().connect(|| {}),
+    ]
+    .into_iter()
+    .collect();
+
+    assert!(has_connections(&obj));
+    drop(connections);
+    assert!(!has_connections(&obj));
+
+    obj.free();
+}
+
 // Helper functions:
 
 fn test_disconnect(
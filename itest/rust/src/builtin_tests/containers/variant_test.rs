@@ -10,9 +10,9 @@ use std::fmt;
 use std::fmt::Display;
 
 use godot::builtin::{
-    array, varray, vdict, vslice, Array, Basis, Color, Dictionary, GString, NodePath,
-    PackedInt32Array, PackedStringArray, Projection, Quaternion, Signal, StringName, Transform2D,
-    Transform3D, Variant, VariantArray, VariantOperator, VariantType, Vector2, Vector2i, Vector3,
+    array, varray, vdict, vslice, Array, Basis, Color, GString, NodePath, PackedInt32Array,
+    PackedStringArray, Projection, Quaternion, Signal, StringName, Transform2D, Transform3D,
+    VarDictionary, Variant, VariantArray, VariantOperator, VariantType, Vector2, Vector2i, Vector3,
     Vector3i,
 };
 use godot::classes::{Node, Node2D, Resource};
@@ -125,7 +125,7 @@ fn variant_relaxed_conversions() {
     convert_relaxed_fail::<GString>(Variant::nil());
     convert_relaxed_fail::<Gd<Node>>(Variant::nil());
     convert_relaxed_fail::<VariantArray>(Variant::nil());
-    convert_relaxed_fail::<Dictionary>(Variant::nil());
+    convert_relaxed_fail::<VarDictionary>(Variant::nil());
 
     // anything -> Variant
     convert_relaxed_to(123, Variant::from(123));
@@ -244,7 +244,7 @@ fn variant_bad_conversions() {
     //);
     Variant::nil()
         .to_variant()
-        .try_to::<Dictionary>()
+        .try_to::<VarDictionary>()
         .expect_err("`nil` should not convert to `Dictionary`");
 }
 
@@ -691,7 +691,7 @@ fn variant_booleanize() {
     assert!(varray![""].to_variant().booleanize());
     assert!(vdict! { "Key": 50 }.to_variant().booleanize());
 
-    assert!(!Dictionary::new().to_variant().booleanize());
+    assert!(!VarDictionary::new().to_variant().booleanize());
     assert!(!varray![].to_variant().booleanize());
     assert!(!0.to_variant().booleanize());
     assert!(!Variant::nil().booleanize());
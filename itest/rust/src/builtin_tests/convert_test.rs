@@ -6,8 +6,8 @@
  */
 
 use godot::builtin::{
-    array, vdict, Array, Dictionary, GString, NodePath, StringName, Variant, VariantArray, Vector2,
-    Vector2Axis,
+    array, vdict, Array, GString, NodePath, StringName, VarDictionary, Variant, VariantArray,
+    Vector2, Vector2Axis,
 };
 use godot::classes::{Node, Resource};
 use godot::meta;
@@ -104,7 +104,7 @@ impl ConvertedStruct {
 }
 
 impl GodotConvert for ConvertedStruct {
-    type Via = Dictionary;
+    type Via = VarDictionary;
 }
 
 impl ToGodot for ConvertedStruct {
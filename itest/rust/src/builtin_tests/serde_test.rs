@@ -5,7 +5,13 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use godot::builtin::{array, Array, Color, ColorHsv, GString, NodePath, StringName, Vector2i};
+use godot::builtin::{
+    array, varray, vdict, Array, Color, ColorHsv, Dictionary, GString, NodePath, PackedColorArray,
+    PackedInt32Array, PackedStringArray, StringName, Variant, Vector2, Vector2i,
+};
+use godot::classes::RefCounted;
+use godot::meta::ToGodot;
+use godot::obj::NewGd;
 use serde::{Deserialize, Serialize};
 
 use crate::framework::itest;
@@ -93,3 +99,82 @@ fn color_hsv_serde() {
     let expected_json = r#"{"h":0.0,"s":0.0,"v":0.0,"a":1.0}"#;
     serde_roundtrip(&color, expected_json);
 }
+
+#[itest]
+fn serde_dictionary_typed() {
+    let value: Dictionary<GString, i64> =
+        [("one".into(), 1), ("two".into(), 2)].into_iter().collect();
+    let expected_json = r#"{"one":1,"two":2}"#;
+
+    serde_roundtrip(&value, expected_json);
+}
+
+#[itest]
+fn serde_packed_arrays() {
+    let value = PackedInt32Array::from([1, -2, 3]);
+    serde_roundtrip(&value, "[1,-2,3]");
+
+    let value = PackedStringArray::from(["Godot".into(), "Rust".into()]);
+    serde_roundtrip(&value, r#"["Godot","Rust"]"#);
+
+    let value = PackedColorArray::from([Color::from_rgba(1.0, 0.5, 0.0, 1.0)]);
+    serde_roundtrip(&value, r#"[{"r":1.0,"g":0.5,"b":0.0,"a":1.0}]"#);
+}
+
+#[itest]
+fn serde_variant_scalars() {
+    serde_roundtrip(&Variant::nil(), r#""Nil""#);
+    serde_roundtrip(&true.to_variant(), r#"{"bool":true}"#);
+    serde_roundtrip(&(-42).to_variant(), r#"{"int":-42}"#);
+    serde_roundtrip(&1.5.to_variant(), r#"{"float":1.5}"#);
+    serde_roundtrip(&"hello".to_variant(), r#"{"String":"hello"}"#);
+    serde_roundtrip(
+        &StringName::from("name").to_variant(),
+        r#"{"StringName":"name"}"#,
+    );
+    serde_roundtrip(
+        &Vector2::new(1.0, 2.0).to_variant(),
+        r#"{"Vector2":{"x":1.0,"y":2.0}}"#,
+    );
+}
+
+#[itest]
+fn serde_variant_containers() {
+    let value = varray![1, "two", Variant::nil()].to_variant();
+    let expected_json = r#"{"Array":[{"int":1},{"String":"two"},"Nil"]}"#;
+    serde_roundtrip(&value, expected_json);
+
+    // Non-string keys are supported, since dictionaries are stored as key-value pairs.
+    let value = vdict! { 1: "one", "nested": varray![true] }.to_variant();
+    let expected_json = r#"{"Dictionary":[[{"int":1},{"String":"one"}],[{"String":"nested"},{"Array":[{"bool":true}]}]]}"#;
+    serde_roundtrip(&value, expected_json);
+
+    let value = PackedInt32Array::from([7, 8]).to_variant();
+    serde_roundtrip(&value, r#"{"PackedInt32Array":[7,8]}"#);
+}
+
+#[itest]
+fn serde_variant_object_rejected() {
+    let object = RefCounted::new_gd();
+
+    let err = serde_json::to_string(&object.to_variant()).expect_err("objects must be rejected");
+    assert!(
+        err.to_string().contains("OBJECT"),
+        "unexpected error: {err}"
+    );
+
+    // Also inside containers.
+    let err = serde_json::to_string(&varray![1, object].to_variant())
+        .expect_err("nested objects must be rejected");
+    assert!(
+        err.to_string().contains("OBJECT"),
+        "unexpected error: {err}"
+    );
+
+    let err = serde_json::from_str::<Variant>(r#"{"Object":null}"#)
+        .expect_err("objects must be rejected");
+    assert!(
+        err.to_string().contains("Object"),
+        "unexpected error: {err}"
+    );
+}
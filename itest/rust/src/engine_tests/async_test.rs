@@ -5,16 +5,23 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use std::future::Future;
 use std::ops::Deref;
+use std::pin::Pin;
+use std::task::{Context, Poll, Waker};
+use std::time::{Duration, Instant};
 
 use godot::builtin::{array, vslice, Array, Callable, Signal, Variant};
-use godot::classes::{Object, RefCounted};
+use godot::classes::{Engine, Object, RefCounted};
 use godot::obj::{Base, Gd, NewAlloc, NewGd};
 use godot::prelude::{godot_api, GodotClass};
 use godot::sys;
-use godot::task::{self, create_test_signal_future_resolver, SignalFuture, TaskHandle};
+use godot::task::{
+    self, create_test_signal_future_resolver, has_godot_task_owner_freed, has_godot_task_panicked,
+    OverflowPolicy, SignalFuture, TaskHandle,
+};
 
-use crate::framework::{expect_async_panic, itest, TestContext};
+use crate::framework::{expect_async_panic, expect_panic, itest, TestContext};
 
 #[derive(GodotClass)]
 #[class(init)]
@@ -203,6 +210,52 @@ fn signal_future_send_arg_no_panic() -> TaskHandle {
     handle
 }
 
+#[cfg(feature = "experimental-threads")]
+#[itest(async)]
+fn run_on_main_thread_from_worker() -> TaskHandle {
+    let main_thread = std::thread::current().id();
+
+    task::spawn(async move {
+        let future = std::thread::spawn(|| {
+            task::run_on_main_thread_async(|| {
+                // Engine APIs are available on the main thread.
+                let frames = Engine::singleton().get_process_frames();
+                (std::thread::current().id(), frames)
+            })
+        })
+        .join()
+        .expect("failed to join thread");
+
+        let (thread, _frames) = future.await;
+        assert_eq!(thread, main_thread);
+    })
+}
+
+#[cfg(feature = "experimental-threads")]
+#[itest(async)]
+fn run_on_main_thread_fire_and_forget() -> TaskHandle {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+
+    let ran = Arc::new(AtomicBool::new(false));
+    let ran_clone = ran.clone();
+
+    std::thread::spawn(move || {
+        task::run_on_main_thread(move || {
+            assert!(sys::is_main_thread());
+            ran_clone.store(true, Ordering::SeqCst);
+        });
+    })
+    .join()
+    .expect("failed to join thread");
+
+    task::spawn(async move {
+        // Deferred calls queued before this task's first poll run in the same flush, or earlier.
+        task::run_on_main_thread_async(|| ()).await;
+        assert!(ran.load(Ordering::SeqCst));
+    })
+}
+
 // Test that two callables created from the same future resolver (but cloned) are equal, while they are not equal to an unrelated
 // callable.
 #[itest]
@@ -253,3 +306,300 @@ fn async_typed_signal_with_array() -> TaskHandle {
 
     task_handle
 }
+
+#[itest(async)]
+fn async_sleep() -> TaskHandle {
+    let start = Instant::now();
+
+    task::spawn(async move {
+        task::sleep(Duration::from_millis(50)).await;
+
+        assert!(start.elapsed() >= Duration::from_millis(50));
+    })
+}
+
+#[itest(async)]
+fn async_next_frame() -> TaskHandle {
+    let start_frame = Engine::singleton().get_process_frames();
+
+    task::spawn(async move {
+        task::next_frame().await;
+
+        assert!(Engine::singleton().get_process_frames() > start_frame);
+    })
+}
+
+#[itest(async)]
+fn async_next_physics_frame() -> TaskHandle {
+    let start_frame = Engine::singleton().get_physics_frames();
+
+    task::spawn(async move {
+        task::next_physics_frame().await;
+
+        assert!(Engine::singleton().get_physics_frames() > start_frame);
+    })
+}
+
+#[itest(async)]
+fn async_timeout_elapsed() -> TaskHandle {
+    let mut object = RefCounted::new_gd();
+    object.add_user_signal("custom_signal");
+    let signal = Signal::from_object_signal(&object, "custom_signal");
+
+    task::spawn(async move {
+        // Signal is never emitted.
+        let result = task::timeout(Duration::from_millis(20), signal.to_future::<()>()).await;
+
+        assert_eq!(result, Err(task::TimeoutError));
+        drop(object);
+    })
+}
+
+#[itest(async)]
+fn async_timeout_completes() -> TaskHandle {
+    task::spawn(async move {
+        let result = task::timeout(Duration::from_secs(10), async {
+            task::next_frame().await;
+            42
+        })
+        .await;
+
+        assert_eq!(result, Ok(42));
+    })
+}
+
+#[itest]
+fn cancel_async_sleep() {
+    let handle = task::spawn(async move {
+        task::sleep(Duration::from_millis(10)).await;
+
+        unreachable!();
+    });
+
+    assert!(handle.is_pending());
+    handle.cancel();
+}
+
+#[itest(async)]
+fn async_join_handle() -> TaskHandle {
+    let child = task::spawn_joinable(async {
+        task::next_frame().await;
+        "done"
+    });
+
+    assert!(child.is_pending());
+
+    task::spawn(async move {
+        let value = child.await;
+
+        assert_eq!(value, "done");
+    })
+}
+
+#[itest(async)]
+fn async_join_all() -> TaskHandle {
+    let children: Vec<_> = (0..3)
+        .map(|i| {
+            task::spawn_joinable(async move {
+                for _ in 0..i {
+                    task::next_frame().await;
+                }
+                i * 10
+            })
+        })
+        .collect();
+
+    task::spawn(async move {
+        let values = task::join_all(children).await;
+
+        assert_eq!(values, [0, 10, 20]);
+    })
+}
+
+#[itest(async)]
+fn async_select() -> TaskHandle {
+    let slow = task::spawn_joinable(async {
+        task::sleep(Duration::from_secs(10)).await;
+        1
+    });
+    let fast = task::spawn_joinable(async {
+        task::next_frame().await;
+        2
+    });
+
+    task::spawn(async move {
+        let (index, value) = task::select([slow, fast]).await;
+
+        assert_eq!(index, 1);
+        assert_eq!(value, 2);
+    })
+}
+
+#[itest]
+fn select_empty_panics() {
+    expect_panic("select() without futures should panic", || {
+        let _ = task::select(std::iter::empty::<std::future::Ready<()>>());
+    });
+}
+
+#[itest]
+fn select_polled_after_completion_panics() {
+    let mut select = task::select([std::future::ready(7)]);
+    let mut cx = Context::from_waker(Waker::noop());
+
+    let result = Pin::new(&mut select).poll(&mut cx);
+    assert_eq!(result, Poll::Ready((0, 7)));
+
+    expect_panic("polling a completed select() should panic", || {
+        let _ = Pin::new(&mut select).poll(&mut cx);
+    });
+}
+
+#[itest(async)]
+fn async_join_handle_propagates_panic() -> TaskHandle {
+    let child = task::spawn_joinable(async {
+        task::next_frame().await;
+        panic!("child task panicked on purpose");
+    });
+
+    task::spawn(expect_async_panic(
+        "awaiting a panicked child task should panic",
+        async move {
+            let () = child.await;
+        },
+    ))
+}
+
+#[itest(async)]
+fn async_join_all_propagates_panic() -> TaskHandle {
+    let ok_child = task::spawn_joinable(async {});
+    let panicking_child = task::spawn_joinable(async {
+        task::next_frame().await;
+        panic!("child task panicked on purpose");
+    });
+
+    task::spawn(expect_async_panic(
+        "join_all() should propagate panic of child task",
+        async move {
+            task::join_all([ok_child, panicking_child]).await;
+        },
+    ))
+}
+
+#[itest(async)]
+fn async_spawn_bound_owner_freed() -> TaskHandle {
+    let owner = Object::new_alloc();
+
+    let bound = task::spawn_bound(&owner, async {
+        task::sleep(Duration::from_secs(10)).await;
+        unreachable!();
+    });
+
+    assert!(bound.is_pending());
+    owner.free();
+
+    task::spawn(async move {
+        task::next_frame().await;
+
+        assert!(!bound.is_pending());
+        assert!(has_godot_task_owner_freed(&bound));
+        assert!(!has_godot_task_panicked(&bound));
+    })
+}
+
+#[itest(async)]
+fn async_spawn_bound_completes() -> TaskHandle {
+    let owner = Object::new_alloc();
+
+    let bound = task::spawn_bound(&owner, async {
+        task::next_frame().await;
+    });
+
+    task::spawn(async move {
+        task::next_frame().await;
+        task::next_frame().await;
+
+        assert!(!bound.is_pending());
+        assert!(!has_godot_task_owner_freed(&bound));
+        assert!(!has_godot_task_panicked(&bound));
+
+        // Connection to the owner is removed once the task is done.
+        assert!(owner
+            .get_signal_connection_list("script_changed")
+            .is_empty());
+        owner.free();
+    })
+}
+
+#[itest(async)]
+fn async_signal_stream() -> TaskHandle {
+    let object = AsyncRefCounted::new_gd();
+    let mut stream = object.signals().custom_signal().to_stream();
+
+    let copy = object.clone();
+    let task_handle = task::spawn(async move {
+        // Values emitted before the first poll are buffered.
+        let mut values = vec![];
+        for _ in 0..3 {
+            let (value,) = stream.next().await.expect("stream is open");
+            values.push(value);
+        }
+        assert_eq!(values, [1, 2, 3]);
+        assert_eq!(stream.buffered_len(), 0);
+
+        // Buffer is empty, so the task is suspended here until the emitter task below runs.
+        assert_eq!(stream.next().await, Some((4,)));
+
+        // Freeing the emitter ends the stream.
+        drop(copy);
+        assert_eq!(stream.next().await, None);
+    });
+
+    let mut signal = object.signals().custom_signal();
+    signal.emit(1);
+    signal.emit(2);
+    signal.emit(3);
+
+    let emitter = object.clone();
+    task::spawn(async move {
+        task::next_frame().await;
+        emitter.signals().custom_signal().emit(4);
+    });
+
+    task_handle
+}
+
+#[itest(async)]
+fn async_signal_stream_overflow() -> TaskHandle {
+    let object = AsyncRefCounted::new_gd();
+    let mut drop_oldest = object
+        .signals()
+        .custom_signal()
+        .to_stream_with(2, OverflowPolicy::DropOldest);
+    let mut drop_newest = object
+        .signals()
+        .custom_signal()
+        .to_stream_with(2, OverflowPolicy::DropNewest);
+
+    for value in 1..=3 {
+        object.signals().custom_signal().emit(value);
+    }
+
+    assert_eq!(drop_oldest.buffered_len(), 2);
+    assert_eq!(drop_newest.buffered_len(), 2);
+
+    task::spawn(async move {
+        assert_eq!(drop_oldest.next().await, Some((2,)));
+        assert_eq!(drop_oldest.next().await, Some((3,)));
+
+        assert_eq!(drop_newest.next().await, Some((1,)));
+        assert_eq!(drop_newest.next().await, Some((2,)));
+
+        // Dropping the streams disconnects them.
+        drop(drop_oldest);
+        drop(drop_newest);
+        assert!(object
+            .get_signal_connection_list("custom_signal")
+            .is_empty());
+    })
+}
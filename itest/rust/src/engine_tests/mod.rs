@@ -14,7 +14,9 @@ mod match_class_test;
 mod native_st_niche_audio_test;
 mod native_st_niche_pointer_test;
 mod native_structures_test;
+mod node_pool_test;
 mod node_test;
 mod save_load_test;
 mod translate_test;
 mod utilities_test;
+mod wire_variant_test;
@@ -0,0 +1,166 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::prelude::*;
+use godot::tools::{NodePool, Poolable};
+
+use crate::framework::{expect_panic, itest, suppress_godot_print};
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct PooledBullet {
+    damage: i32,
+    base: Base<Node>,
+}
+
+impl Poolable for PooledBullet {
+    fn reset(&mut self) {
+        self.damage = 0;
+    }
+}
+
+#[itest]
+fn node_pool_preallocates() {
+    let pool = NodePool::<PooledBullet>::new(3);
+    assert_eq!(pool.idle_count(), 3);
+    assert_eq!(pool.total_count(), 3);
+
+    let bullet = pool.acquire();
+    assert_eq!(pool.idle_count(), 2);
+    assert!(bullet.get_parent().is_none());
+
+    pool.release(bullet);
+    assert_eq!(pool.idle_count(), 3);
+}
+
+#[itest]
+fn node_pool_grows_and_reuses() {
+    let pool = NodePool::<PooledBullet>::new(0);
+
+    let bullet = pool.acquire();
+    let id = bullet.instance_id();
+    assert_eq!(pool.total_count(), 1);
+
+    pool.release(bullet);
+
+    let bullet = pool.acquire();
+    assert_eq!(bullet.instance_id(), id, "released node is reused");
+    assert_eq!(pool.total_count(), 1);
+
+    pool.release(bullet);
+}
+
+#[itest]
+fn node_pool_acquire_child_and_reset() {
+    let pool = NodePool::from_init_fn(1, |base| PooledBullet { damage: 10, base });
+    let mut parent = Node::new_alloc();
+
+    let mut bullet = pool.acquire_child(&parent);
+    assert_eq!(bullet.get_parent(), Some(parent.clone()));
+    assert_eq!(bullet.bind().damage, 10);
+
+    bullet.bind_mut().damage = 25;
+    pool.release(bullet);
+
+    assert_eq!(
+        parent.get_child_count(),
+        0,
+        "release() detaches from parent"
+    );
+
+    let bullet = pool.acquire();
+    assert_eq!(bullet.bind().damage, 0, "reset() hook was called");
+
+    pool.release(bullet);
+    parent.free();
+}
+
+#[itest]
+fn node_pool_free_returns_to_pool() {
+    let pool = NodePool::<PooledBullet>::new(1);
+
+    let bullet = pool.acquire();
+    let id = bullet.instance_id();
+    bullet.free();
+
+    assert_eq!(pool.idle_count(), 1);
+    let bullet = pool.acquire();
+    assert_eq!(bullet.instance_id(), id);
+    assert!(bullet.is_instance_valid());
+
+    pool.release(bullet);
+}
+
+#[itest]
+fn node_pool_free_idle_is_noop() {
+    let pool = NodePool::<PooledBullet>::new(0);
+
+    let bullet = pool.acquire();
+    pool.release(bullet.clone());
+
+    // Only warns; the node stays in the pool.
+    suppress_godot_print(|| bullet.clone().free());
+
+    assert!(bullet.is_instance_valid());
+    assert_eq!(pool.idle_count(), 1);
+    assert_eq!(pool.total_count(), 1);
+}
+
+#[itest]
+fn node_pool_forgets_destroyed_nodes() {
+    let pool = NodePool::<PooledBullet>::new(0);
+    let parent = Node::new_alloc();
+
+    let in_tree = pool.acquire_child(&parent);
+    let detached = pool.acquire();
+    assert_eq!(pool.total_count(), 2);
+
+    // Freeing the parent destroys its children, bypassing Gd::free().
+    parent.free();
+    assert!(!in_tree.is_instance_valid());
+    assert_eq!(pool.total_count(), 1);
+
+    // Same for engine-side free(), as done by GDScript.
+    detached.clone().upcast::<Object>().call("free", &[]);
+    assert!(!detached.is_instance_valid());
+    assert_eq!(pool.total_count(), 0);
+    assert_eq!(pool.idle_count(), 0);
+
+    // Pool is still usable.
+    let bullet = pool.acquire();
+    assert_eq!(pool.total_count(), 1);
+    pool.release(bullet);
+}
+
+#[itest]
+fn node_pool_drop_frees_idle() {
+    let pool = NodePool::<PooledBullet>::new(1);
+
+    let in_use = pool.acquire();
+    let idle = pool.acquire();
+    pool.release(idle.clone());
+    drop(pool);
+
+    assert!(!idle.is_instance_valid(), "idle node freed with pool");
+
+    // Handed-out nodes are detached from the pool, free() destroys them.
+    assert!(in_use.is_instance_valid());
+    in_use.clone().free();
+    assert!(!in_use.is_instance_valid());
+}
+
+#[itest]
+fn node_pool_release_foreign_panics() {
+    let pool = NodePool::<PooledBullet>::new(0);
+    let foreign = PooledBullet::new_alloc();
+
+    expect_panic("release node not owned by pool", || {
+        pool.release(foreign.clone());
+    });
+
+    foreign.free();
+}
@@ -0,0 +1,134 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::*;
+use godot::classes::RefCounted;
+use godot::global::{bytes_to_var, var_to_bytes};
+use godot::meta::ToGodot;
+use godot::obj::NewGd;
+use godot::tools::WireVariant;
+
+use crate::framework::itest;
+
+/// Values covering all supported variant types, including edge cases of the format (64-bit numbers, padding, nesting).
+fn sample_values() -> Vec<Variant> {
+    let mut values = vec![
+        Variant::nil(),
+        true.to_variant(),
+        (-42).to_variant(),
+        i64::MAX.to_variant(),
+        0.5.to_variant(),
+        0.1.to_variant(),
+        GString::from("Grüße 🦀").to_variant(),
+        GString::new().to_variant(),
+        Vector2::new(1.5, -2.25).to_variant(),
+        Vector2i::new(-3, 4).to_variant(),
+        Rect2::from_components(1.0, 2.0, 3.0, 4.0).to_variant(),
+        Rect2i::from_components(1, 2, 3, 4).to_variant(),
+        Vector3::new(1.0, 2.0, 3.0).to_variant(),
+        Vector3i::new(1, -2, 3).to_variant(),
+        Transform2D::from_angle_origin(1.0, Vector2::new(5.0, 6.0)).to_variant(),
+        Vector4::new(1.0, 2.0, 3.0, 4.0).to_variant(),
+        Vector4i::new(1, 2, 3, -4).to_variant(),
+        Plane::new(Vector3::UP, 2.5).to_variant(),
+        Quaternion::new(0.0, 0.0, 0.6, 0.8).to_variant(),
+        Aabb::new(Vector3::ZERO, Vector3::ONE).to_variant(),
+        Basis::from_scale(Vector3::new(1.0, 2.0, 3.0)).to_variant(),
+        Transform3D::new(Basis::IDENTITY, Vector3::new(7.0, 8.0, 9.0)).to_variant(),
+        Projection::IDENTITY.to_variant(),
+        Color::from_rgba(0.25, 0.5, 0.75, 1.0).to_variant(),
+        StringName::from("name").to_variant(),
+        NodePath::from("/root/Node:position:x").to_variant(),
+        NodePath::from("../Sibling").to_variant(),
+        Rid::new(1234567890123).to_variant(),
+        vdict! { "key": 1, 2: varray![3.5, "four"] }.to_variant(),
+        varray![1, "two", varray![Vector2::ONE], VarDictionary::new()].to_variant(),
+        PackedByteArray::from(&[1, 2, 3, 4, 5]).to_variant(),
+        PackedInt32Array::from(&[1, -2, 3]).to_variant(),
+        PackedInt64Array::from(&[i64::MIN, 0, i64::MAX]).to_variant(),
+        PackedFloat32Array::from(&[1.5, -0.25]).to_variant(),
+        PackedFloat64Array::from(&[0.1, 1e300]).to_variant(),
+        PackedStringArray::from(&["a".into(), "bcdef".into(), GString::new()]).to_variant(),
+        PackedVector2Array::from(&[Vector2::ONE, Vector2::LEFT]).to_variant(),
+        PackedVector3Array::from(&[Vector3::ONE, Vector3::BACK]).to_variant(),
+        PackedColorArray::from(&[Color::RED, Color::TRANSPARENT_BLACK]).to_variant(),
+    ];
+
+    #[cfg(since_api = "4.3")]
+    values.push(PackedVector4Array::from(&[Vector4::ONE]).to_variant());
+
+    values
+}
+
+#[itest]
+fn wire_variant_encode_matches_engine() {
+    for variant in sample_values() {
+        let wire = WireVariant::from_variant(&variant).expect("supported type");
+
+        let expected = var_to_bytes(&variant);
+        let actual = wire.encode();
+
+        assert_eq!(
+            actual,
+            expected.as_slice(),
+            "encoding differs for {variant:?}"
+        );
+    }
+}
+
+#[itest]
+fn wire_variant_decode_matches_engine() {
+    for variant in sample_values() {
+        let bytes = var_to_bytes(&variant);
+        let wire = WireVariant::decode(bytes.as_slice()).expect("decodable");
+
+        assert_eq!(wire.to_variant(), variant, "decoding differs");
+        assert_eq!(wire, WireVariant::from_variant(&variant).unwrap());
+    }
+}
+
+#[itest]
+fn wire_variant_engine_decodes_rust_encoding() {
+    for variant in sample_values() {
+        let bytes = WireVariant::from_variant(&variant).unwrap().encode();
+        let decoded = bytes_to_var(&PackedByteArray::from(bytes.as_slice()));
+
+        assert_eq!(decoded, variant);
+    }
+}
+
+#[itest]
+fn wire_variant_typed_array() {
+    let array: Array<i64> = array![1, 2, 3];
+    let bytes = var_to_bytes(&array.to_variant());
+
+    // Element type is dropped.
+    let wire = WireVariant::decode(bytes.as_slice()).expect("decodable");
+    let expected = WireVariant::Array(vec![
+        WireVariant::Int(1),
+        WireVariant::Int(2),
+        WireVariant::Int(3),
+    ]);
+    assert_eq!(wire, expected);
+}
+
+#[itest]
+fn wire_variant_object_unsupported() {
+    let object = RefCounted::new_gd().to_variant();
+    assert!(WireVariant::from_variant(&object).is_err());
+
+    let callable = Callable::from_local_fn("test", |_| Ok(Variant::nil())).to_variant();
+    assert!(WireVariant::from_variant(&callable).is_err());
+
+    // Nested inside container.
+    let array = varray![1, object].to_variant();
+    assert!(WireVariant::from_variant(&array).is_err());
+
+    // Engine encodes objects without full_objects as an ID header, which is rejected on decoding.
+    let bytes = var_to_bytes(&RefCounted::new_gd().to_variant());
+    assert!(WireVariant::decode(bytes.as_slice()).is_err());
+}
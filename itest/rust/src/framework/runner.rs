@@ -464,7 +464,7 @@ fn check_async_test_task(
 
     if !task_handle.is_pending() {
         on_test_finished(TestOutcome::from_bool(!has_godot_task_panicked(
-            task_handle,
+            &task_handle,
         )));
 
         return;
@@ -0,0 +1,72 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::classes::ClassDb;
+use godot::prelude::*;
+
+use crate::framework::{create_gdscript, itest};
+
+#[derive(GodotClass)]
+#[class(init, abstract)]
+struct AbstractUnit {
+    #[var]
+    #[init(val = 10)]
+    health: i32,
+
+    base: Base<RefCounted>,
+}
+
+#[godot_api]
+impl AbstractUnit {
+    #[signal]
+    fn damaged(amount: i32);
+
+    #[func]
+    fn take_damage(&mut self, amount: i32) {
+        self.health -= amount;
+        self.signals().damaged().emit(amount);
+    }
+}
+
+#[itest]
+fn abstract_class_registers_api() {
+    let class_name = AbstractUnit::class_name().to_string_name();
+    let db = ClassDb::singleton();
+
+    assert!(db.class_exists(&class_name));
+    assert!(db.class_has_method(&class_name, "take_damage"));
+    assert!(db.class_has_signal(&class_name, "damaged"));
+}
+
+#[itest]
+fn abstract_class_extended_by_gdscript() {
+    let mut script = create_gdscript(
+        r#"
+extends AbstractUnit
+
+var last_damage := 0
+
+func _init():
+    damaged.connect(func(amount): last_damage = amount)
+
+func heavy_hit():
+    take_damage(2 * health / 5)
+"#,
+    );
+
+    let mut obj = script
+        .call("new", &[])
+        .try_to::<Gd<AbstractUnit>>()
+        .expect("GDScript subclass must be an AbstractUnit");
+
+    // Rust constructor ran as part of the subclass instantiation.
+    assert_eq!(obj.bind().health, 10);
+
+    obj.call("heavy_hit", &[]);
+    assert_eq!(obj.bind().health, 6);
+    assert_eq!(obj.get("last_damage"), 4.to_variant());
+}
@@ -5,6 +5,7 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+mod abstract_class_test;
 mod base_test;
 mod call_deferred_test;
 mod class_name_test;
@@ -26,11 +27,13 @@ mod property_template_test;
 mod property_test;
 mod reentrant_test;
 mod singleton_test;
+mod thread_safe_gd_test;
 // `validate_property` is only supported in Godot 4.2+.
 mod base_init_test;
 mod validate_property_test;
 mod virtual_methods_niche_test;
 mod virtual_methods_test;
+mod weak_gd_test;
 
 // Need to test this in the init level method.
 pub use init_level_test::*;
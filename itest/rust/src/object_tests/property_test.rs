@@ -5,17 +5,25 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use std::cell::RefCell;
+use std::rc::Rc;
+
 use godot::builtin::{
-    vdict, vslice, Color, Dictionary, GString, PackedInt32Array, Variant, VariantType,
+    vdict, vslice, Color, GString, PackedInt32Array, StringName, VarDictionary, Variant,
+    VariantType,
 };
 use godot::classes::{INode, IRefCounted, Node, Object, RefCounted, Resource, Texture};
 use godot::global::{PropertyHint, PropertyUsageFlags};
+#[cfg(since_api = "4.4")]
+use godot::meta::ElementType;
 use godot::meta::{GodotConvert, PropertyHintInfo, ToGodot};
 use godot::obj::{Base, EngineBitfield, EngineEnum, Gd, NewAlloc, NewGd, OnEditor};
 use godot::register::property::{Export, Var};
-use godot::register::{godot_api, Export, GodotClass, GodotConvert, Var};
+use godot::register::{godot_api, Export, ExportGroup, GodotClass, GodotConvert, Var};
 use godot::test::itest;
 
+use crate::framework::suppress_godot_print;
+
 // No tests currently, tests using these classes are in Godot scripts.
 
 #[derive(GodotClass)]
@@ -195,7 +203,7 @@ struct NotExportable {
 }
 
 impl GodotConvert for NotExportable {
-    type Via = Dictionary;
+    type Via = VarDictionary;
 }
 
 impl Var for NotExportable {
@@ -419,6 +427,57 @@ fn derive_export() {
     check_property(&property, "usage", PropertyUsageFlags::DEFAULT.ord());
 }
 
+#[cfg(since_api = "4.4")]
+use godot::builtin::Dictionary;
+
+#[cfg(since_api = "4.4")]
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+pub struct TypedDictionaryProperties {
+    #[var]
+    var_dict: Dictionary<GString, i64>,
+
+    #[export]
+    export_dict: Dictionary<GString, i64>,
+
+    #[export]
+    export_untyped_dict: VarDictionary,
+}
+
+#[itest]
+#[cfg(since_api = "4.4")]
+fn typed_dictionary_properties() {
+    let class = TypedDictionaryProperties::new_alloc();
+    let find_property = |name: &str| {
+        class
+            .get_property_list()
+            .iter_shared()
+            .find(|c| c.get_or_nil("name") == name.to_variant())
+            .unwrap()
+    };
+
+    let property = find_property("var_dict");
+    check_property(&property, "type", VariantType::DICTIONARY.ord());
+    check_property(&property, "hint", PropertyHint::DICTIONARY_TYPE.ord());
+    check_property(&property, "hint_string", "String;int");
+
+    let property = find_property("export_dict");
+    check_property(&property, "type", VariantType::DICTIONARY.ord());
+    check_property(&property, "hint", PropertyHint::TYPE_STRING.ord());
+    check_property(&property, "hint_string", "4:;2:");
+
+    let property = find_property("export_untyped_dict");
+    check_property(&property, "hint", PropertyHint::NONE.ord());
+    check_property(&property, "hint_string", "");
+
+    // Default-initialized typed dictionaries carry their element types to Godot.
+    let dict = class.get("var_dict").to::<Dictionary<GString, i64>>();
+    assert_eq!(dict.key_element_type(), ElementType::of::<GString>());
+    assert_eq!(dict.value_element_type(), ElementType::of::<i64>());
+
+    class.free();
+}
+
 #[derive(GodotClass)]
 #[class(init, base=Resource)]
 pub struct CustomResource {}
@@ -515,7 +574,108 @@ fn override_export() {
     check_property(&property, "usage", PropertyUsageFlags::GROUP.ord());
 }
 
-fn check_property(property: &Dictionary, key: &str, expected: impl ToGodot) {
+#[derive(GodotClass)]
+#[class(init)]
+struct ExportCategory {
+    #[export]
+    uncategorized: i32,
+
+    #[export_category(name = "Stats")]
+    #[export_group(name = "Health")]
+    #[export]
+    max_health: i32,
+}
+
+#[itest]
+fn export_category() {
+    let class = ExportCategory::new_gd();
+
+    let names = class
+        .get_property_list()
+        .iter_shared()
+        .map(|property| property.get_or_nil("name").to::<String>())
+        .collect::<Vec<_>>();
+
+    let category_index = names.iter().position(|name| name == "Stats").unwrap();
+    assert_eq!(names[category_index - 1], "uncategorized");
+    assert_eq!(names[category_index + 1], "Health");
+    assert_eq!(names[category_index + 2], "max_health");
+
+    let property = class
+        .get_property_list()
+        .iter_shared()
+        .find(|c| c.get_or_nil("name") == "Stats".to_variant())
+        .unwrap();
+    check_property(&property, "type", VariantType::NIL.ord());
+    check_property(&property, "usage", PropertyUsageFlags::CATEGORY.ord());
+}
+
+#[derive(ExportGroup, Default)]
+struct ExportedStats {
+    #[export(range = (0.0, 100.0))]
+    health: f64,
+
+    #[export]
+    name: GString,
+
+    // Not exported.
+    #[allow(dead_code)]
+    cache: i32,
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct ExportNested {
+    #[export_nested(name = "Stats")]
+    stats: ExportedStats,
+
+    #[export]
+    after: i32,
+}
+
+#[itest]
+fn export_nested() {
+    let mut class = ExportNested::new_alloc();
+
+    let names = class
+        .get_property_list()
+        .iter_shared()
+        .map(|property| property.get_or_nil("name").to::<String>())
+        .collect::<Vec<_>>();
+
+    let group_index = names.iter().position(|name| name == "Stats").unwrap();
+    assert_eq!(names[group_index + 1], "stats_health");
+    assert_eq!(names[group_index + 2], "stats_name");
+    assert_eq!(names[group_index + 3], "after");
+    assert!(!names.iter().any(|name| name == "stats_cache"));
+
+    let property = class
+        .get_property_list()
+        .iter_shared()
+        .find(|c| c.get_or_nil("name") == "stats_health".to_variant())
+        .unwrap();
+    check_property(&property, "type", VariantType::FLOAT.ord());
+    check_property(&property, "hint", PropertyHint::RANGE.ord());
+    check_property(&property, "hint_string", "0,100");
+
+    // Properties are routed to the nested fields.
+    class.set("stats_health", &42.5.to_variant());
+    class.set("stats_name", &"Goblin".to_variant());
+    assert_eq!(class.bind().stats.health, 42.5);
+    assert_eq!(class.bind().stats.name, GString::from("Goblin"));
+
+    class.bind_mut().stats.health = 7.0;
+    assert_eq!(class.get("stats_health"), 7.0.to_variant());
+    assert_eq!(class.get("stats_name"), "Goblin".to_variant());
+
+    // Values of the wrong type are rejected, keeping the previous value.
+    suppress_godot_print(|| class.set("stats_name", &42.to_variant()));
+    assert_eq!(class.bind().stats.name, GString::from("Goblin"));
+
+    class.free();
+}
+
+fn check_property(property: &VarDictionary, key: &str, expected: impl ToGodot) {
     assert_eq!(property.get_or_nil(key), expected.to_variant());
 }
 
@@ -579,6 +739,246 @@ fn guaranteed_ordering() {
     }
 }
 
+#[derive(GodotClass)]
+#[class(init)]
+struct ExportPlacements {
+    #[export(group = "Movement", order = 2)]
+    speed: f32,
+
+    #[export(group = "Combat", subgroup = "Defense")]
+    armor: i32,
+
+    #[var]
+    internal: i32,
+
+    #[export(group = "Combat", order = 1)]
+    damage: i32,
+
+    #[export(group = "Movement", order = 1)]
+    jump_height: f32,
+
+    #[export(order = 1)]
+    late: i32,
+
+    #[export]
+    early: i32,
+}
+
+// Explicit placements are independent of the struct layout; ungrouped properties come first.
+#[itest]
+fn export_placement_ordering() {
+    let expected_order = [
+        ("ExportPlacements", PropertyUsageFlags::CATEGORY),
+        ("internal", PropertyUsageFlags::NONE),
+        ("early", PropertyUsageFlags::DEFAULT),
+        ("late", PropertyUsageFlags::DEFAULT),
+        ("Combat", PropertyUsageFlags::GROUP),
+        ("damage", PropertyUsageFlags::DEFAULT),
+        ("Defense", PropertyUsageFlags::SUBGROUP),
+        ("armor", PropertyUsageFlags::DEFAULT),
+        ("Movement", PropertyUsageFlags::GROUP),
+        ("jump_height", PropertyUsageFlags::DEFAULT),
+        ("speed", PropertyUsageFlags::DEFAULT),
+    ];
+
+    let class = ExportPlacements::new_gd();
+    let properties = class
+        .get_property_list()
+        .iter_shared()
+        .map(|property| {
+            (
+                property.get_or_nil("name").to::<String>(),
+                property.get_or_nil("usage").to::<PropertyUsageFlags>(),
+            )
+        })
+        .take(expected_order.len())
+        .collect::<Vec<_>>();
+
+    let expected_order = expected_order
+        .iter()
+        .map(|(name, usage)| (name.to_string(), *usage))
+        .collect::<Vec<_>>();
+
+    assert_eq!(properties, expected_order);
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct ExportRevert {
+    #[export]
+    #[init(val = 100)]
+    health: i32,
+
+    #[export]
+    name: GString,
+
+    #[export(no_revert)]
+    #[init(val = 42)]
+    seed: i64,
+
+    #[export]
+    #[var(get = get_doubled, set)]
+    doubled: i32,
+
+    #[var]
+    #[init(val = 5)]
+    not_exported: i32,
+
+    #[export]
+    #[init(val = Some(Resource::new_gd()))]
+    resource: Option<Gd<Resource>>,
+}
+
+#[godot_api]
+impl ExportRevert {
+    #[func]
+    fn get_doubled(&self) -> i32 {
+        self.doubled * 2
+    }
+}
+
+#[itest]
+fn export_revert_to_init_value() {
+    let mut obj = ExportRevert::new_gd();
+
+    let health = StringName::from("health");
+    assert!(obj.property_can_revert(&health));
+    assert_eq!(obj.property_get_revert(&health), 100.to_variant());
+
+    // Revert value does not depend on the current value.
+    obj.set("health", &7.to_variant());
+    assert_eq!(obj.property_get_revert(&health), 100.to_variant());
+
+    let name = StringName::from("name");
+    assert!(obj.property_can_revert(&name));
+    assert_eq!(obj.property_get_revert(&name), GString::new().to_variant());
+
+    // Objects are not reverted, as the initial value would be shared between instances.
+    for property in ["seed", "doubled", "not_exported", "resource", "nonexistent"] {
+        let property = StringName::from(property);
+        assert!(!obj.property_can_revert(&property));
+        assert_eq!(obj.property_get_revert(&property), Variant::nil());
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct ExportValidation {
+    #[export(range = (0.0, 1.0), clamp)]
+    volume: f32,
+
+    #[export(range = (0.0, 10.0, or_greater), clamp)]
+    level: i32,
+
+    #[export]
+    #[var(validate = is_valid_name)]
+    #[init(val = GString::from("default"))]
+    name: GString,
+}
+
+impl ExportValidation {
+    fn is_valid_name(&self, name: &GString) -> bool {
+        !name.is_empty()
+    }
+}
+
+#[itest]
+fn export_clamp_and_validate() {
+    let mut obj = ExportValidation::new_gd();
+
+    obj.set("volume", &1.5.to_variant());
+    assert_eq!(obj.bind().volume, 1.0);
+    obj.set("volume", &(-0.5).to_variant());
+    assert_eq!(obj.bind().volume, 0.0);
+    obj.set("volume", &0.25.to_variant());
+    assert_eq!(obj.bind().volume, 0.25);
+
+    // NaN is mapped to the lower bound.
+    obj.set("volume", &f64::NAN.to_variant());
+    assert_eq!(obj.bind().volume, 0.0);
+
+    // Upper bound is not enforced with `or_greater`.
+    obj.set("level", &(-3).to_variant());
+    assert_eq!(obj.bind().level, 0);
+    obj.set("level", &20.to_variant());
+    assert_eq!(obj.bind().level, 20);
+
+    obj.set("name", &"Ada".to_variant());
+    assert_eq!(obj.bind().name, GString::from("Ada"));
+
+    // Rejected value keeps the previous one, also when setting from Rust.
+    obj.set("name", &"".to_variant());
+    assert_eq!(obj.bind().name, GString::from("Ada"));
+    obj.bind_mut().set_name(GString::new());
+    assert_eq!(obj.bind().name, GString::from("Ada"));
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct PropertyNotify {
+    #[var(notify = on_health_changed, emit = health_changed)]
+    health: i32,
+
+    #[export(range = (0.0, 1.0), clamp)]
+    #[var(notify = on_volume_changed)]
+    volume: f32,
+
+    changes: Vec<(f32, f32)>,
+    base: Base<Node>,
+}
+
+#[godot_api]
+impl PropertyNotify {
+    #[signal]
+    fn health_changed(old: i32, new: i32);
+}
+
+impl PropertyNotify {
+    fn on_health_changed(&mut self, old: &i32, new: &i32) {
+        self.changes.push((*old as f32, *new as f32));
+    }
+
+    fn on_volume_changed(&mut self, old: &f32, new: &f32) {
+        self.changes.push((*old, *new));
+    }
+}
+
+#[itest]
+fn var_notify_and_emit() {
+    let mut obj = PropertyNotify::new_alloc();
+
+    let emitted = Rc::new(RefCell::new(Vec::new()));
+    {
+        let emitted = emitted.clone();
+        obj.signals()
+            .health_changed()
+            .connect(move |old, new| emitted.borrow_mut().push((old, new)));
+    }
+
+    obj.set("health", &10.to_variant());
+    obj.bind_mut().set_health(25);
+
+    // Unchanged value neither notifies nor emits.
+    obj.set("health", &25.to_variant());
+
+    assert_eq!(obj.bind().changes, [(0.0, 10.0), (10.0, 25.0)]);
+    assert_eq!(*emitted.borrow(), [(0, 10), (10, 25)]);
+
+    // Notified with the clamped value; clamping to the current value is no change.
+    obj.bind_mut().changes.clear();
+    obj.set("volume", &2.0.to_variant());
+    obj.set("volume", &3.0.to_variant());
+    assert_eq!(obj.bind().changes, [(0.0, 1.0)]);
+
+    obj.free();
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
 #[derive(GodotClass)]
@@ -718,3 +1118,74 @@ fn test_duplicate_retains_properties() {
     duplicated.free();
     original.free();
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(since_api = "4.4")]
+use godot::builtin::{Callable, StringName};
+#[cfg(since_api = "4.4")]
+use godot::register::property::PhantomVar;
+
+#[cfg(since_api = "4.4")]
+#[derive(GodotClass)]
+#[class(init, tool, base=Node)]
+struct ToolButtons {
+    #[export_tool_button(fn = rebuild, text = "Rebuild", icon = "Reload")]
+    rebuild_button: PhantomVar<Callable>,
+
+    #[export_tool_button(fn = clear, text = "Clear")]
+    clear_button: PhantomVar<Callable>,
+
+    rebuild_count: i32,
+    base: Base<Node>,
+}
+
+#[cfg(since_api = "4.4")]
+#[godot_api]
+impl ToolButtons {
+    #[func]
+    fn rebuild(&mut self) {
+        self.rebuild_count += 1;
+    }
+
+    #[func(rename = clear_all)]
+    fn clear(&mut self) {
+        self.rebuild_count = 0;
+    }
+}
+
+#[itest]
+#[cfg(since_api = "4.4")]
+fn export_tool_button() {
+    let obj = ToolButtons::new_alloc();
+    let find_property = |name: &str| {
+        obj.get_property_list()
+            .iter_shared()
+            .find(|c| c.get_or_nil("name") == name.to_variant())
+            .unwrap()
+    };
+
+    let property = find_property("rebuild_button");
+    check_property(&property, "type", VariantType::CALLABLE.ord());
+    check_property(&property, "hint", PropertyHint::TOOL_BUTTON.ord());
+    check_property(&property, "hint_string", "Rebuild,Reload");
+    check_property(&property, "usage", PropertyUsageFlags::EDITOR.ord());
+
+    let property = find_property("clear_button");
+    check_property(&property, "hint_string", "Clear");
+
+    // The property value is a callable bound to the method.
+    let rebuild = obj.get("rebuild_button").to::<Callable>();
+    assert_eq!(rebuild.method_name().unwrap(), StringName::from("rebuild"));
+    rebuild.call(&[]);
+    rebuild.call(&[]);
+    assert_eq!(obj.bind().rebuild_count, 2);
+
+    // Renamed #[func] is resolved to its registered name.
+    let clear = obj.get("clear_button").to::<Callable>();
+    assert_eq!(clear.method_name().unwrap(), StringName::from("clear_all"));
+    clear.call(&[]);
+    assert_eq!(obj.bind().rebuild_count, 0);
+
+    obj.free();
+}
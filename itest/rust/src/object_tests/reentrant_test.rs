@@ -42,6 +42,12 @@ impl ReentrantClass {
     fn second(&mut self) {
         self.second_called = true;
     }
+
+    #[func]
+    fn first_try_binds(&mut self) -> bool {
+        // While `&mut self` is held, another bind through the same object must fail.
+        self.to_gd().try_bind().is_ok()
+    }
 }
 
 #[itest]
@@ -80,3 +86,38 @@ fn reentrant_emit_succeeds() {
 
     class.free()
 }
+
+#[itest]
+fn try_bind_conflicts() {
+    let mut class = ReentrantClass::new_alloc();
+    let mut other = class.clone();
+
+    {
+        let _guard = class.bind();
+        assert!(other.try_bind().is_ok(), "shared binds can coexist");
+
+        let err = other.try_bind_mut().expect_err("bind_mut() while bound");
+        assert!(err.is_mut());
+    }
+
+    {
+        let _guard = class.bind_mut();
+        let err = other.try_bind().expect_err("bind() while mutably bound");
+        assert!(!err.is_mut());
+    }
+
+    // All guards dropped.
+    assert!(other.try_bind_mut().is_ok());
+
+    class.free()
+}
+
+#[itest]
+fn try_bind_reentrant_fails() {
+    let mut class = ReentrantClass::new_alloc();
+
+    let could_bind = class.call("first_try_binds", &[]);
+    assert_eq!(could_bind, false.to_variant());
+
+    class.free()
+}
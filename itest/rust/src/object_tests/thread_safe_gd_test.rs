@@ -0,0 +1,146 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+// ThreadSafeGd<T> only exists with blocking storage.
+#![cfg(feature = "experimental-threads")]
+
+use std::sync::{Arc, Mutex};
+use std::thread::{self, ThreadId};
+
+use godot::obj::ThreadSafeGd;
+use godot::prelude::*;
+
+use crate::framework::itest;
+
+// Base<T> is not Send + Sync; it is excluded from the #[class(threadsafe)] field check.
+#[derive(GodotClass)]
+#[class(init, threadsafe, base=RefCounted)]
+struct SharedCounter {
+    count: u32,
+    base: Base<RefCounted>,
+}
+
+#[itest]
+fn thread_safe_gd_bind_from_threads() {
+    let counter = SharedCounter::new_gd().into_thread_safe();
+
+    thread::scope(|s| {
+        for _ in 0..4 {
+            s.spawn(|| {
+                for _ in 0..100 {
+                    counter.bind_mut().count += 1;
+                }
+            });
+        }
+    });
+
+    assert_eq!(counter.bind().count, 400);
+}
+
+#[itest]
+fn thread_safe_gd_send_and_back() {
+    let obj = SharedCounter::new_gd();
+    let id = obj.instance_id();
+
+    let safe = ThreadSafeGd::new(obj);
+    let safe = thread::spawn(move || {
+        safe.bind_mut().count = 7;
+        safe
+    })
+    .join()
+    .unwrap();
+
+    assert_eq!(safe.instance_id(), id);
+
+    let obj = safe.into_gd();
+    assert_eq!(obj.bind().count, 7);
+    assert_eq!(obj.get_reference_count(), 1);
+}
+
+#[itest]
+fn thread_safe_gd_to_gd_off_main_thread() {
+    let safe = SharedCounter::new_gd().into_thread_safe();
+
+    let result = thread::scope(|s| s.spawn(|| safe.to_gd().instance_id()).join());
+    assert!(result.is_err(), "ThreadSafeGd::to_gd() on worker thread");
+
+    // Main thread is fine.
+    assert_eq!(safe.to_gd().instance_id(), safe.instance_id());
+}
+
+#[itest]
+fn thread_safe_gd_base_off_main_thread() {
+    let safe = SharedCounter::new_gd().into_thread_safe();
+
+    let result = thread::scope(|s| s.spawn(|| safe.bind().base().get_reference_count()).join());
+    assert!(result.is_err(), "base() on worker thread");
+
+    let result = thread::scope(|s| s.spawn(|| safe.bind().to_gd().instance_id()).join());
+    assert!(result.is_err(), "to_gd() on worker thread");
+
+    // Direct access to the Base<T> field is confined as well, e.g. Display calls into the engine.
+    let result = thread::scope(|s| s.spawn(|| format!("{}", safe.bind().base)).join());
+    assert!(result.is_err(), "Display for Base on worker thread");
+
+    // Debug is used for logging, it doesn't panic but also doesn't call into the engine.
+    let debug = thread::scope(|s| s.spawn(|| format!("{:?}", safe.bind().base)).join());
+    let expected = format!(
+        "Base {{ id: {}, class: SharedCounter }}",
+        safe.instance_id()
+    );
+    assert_eq!(debug.expect("Debug for Base on worker thread"), expected);
+
+    // Main thread is fine, and the instance is still accessible after the panics.
+    assert_eq!(safe.bind().base().get_reference_count(), 1);
+    safe.bind_mut().count = 3;
+    assert_eq!(safe.bind().count, 3);
+}
+
+#[derive(GodotClass)]
+#[class(no_init, threadsafe, base=RefCounted)]
+struct DropTracker {
+    drop_thread: Arc<Mutex<Option<ThreadId>>>,
+}
+
+impl Drop for DropTracker {
+    fn drop(&mut self) {
+        *self.drop_thread.lock().unwrap() = Some(thread::current().id());
+    }
+}
+
+#[itest]
+fn thread_safe_gd_drop_last_on_worker() {
+    let drop_thread = Arc::new(Mutex::new(None));
+    let obj = Gd::from_init_fn(|_base| DropTracker {
+        drop_thread: drop_thread.clone(),
+    });
+    let id = obj.instance_id();
+
+    let safe = obj.into_thread_safe();
+    let worker = thread::spawn(move || {
+        let copy = safe.clone();
+        drop(safe);
+        drop(copy); // Last reference.
+        thread::current().id()
+    })
+    .join()
+    .expect("dropping last ThreadSafeGd on worker thread");
+
+    assert!(!id.lookup_validity(), "object destroyed");
+    assert_eq!(*drop_thread.lock().unwrap(), Some(worker));
+}
+
+#[itest]
+fn thread_safe_gd_try_bind_same_thread() {
+    let safe = SharedCounter::new_gd().into_thread_safe();
+
+    let _guard = safe.bind();
+    let err = safe
+        .try_bind_mut()
+        .expect_err("bind_mut() while bound on same thread");
+    assert!(err.is_mut());
+}
@@ -145,7 +145,7 @@ impl IPrimitiveMesh for VirtualReturnTest {
     fn surface_get_array_index_len(&self, _index: i32) -> i32 { unreachable!() }
     fn surface_get_arrays(&self, _index: i32) -> VariantArray { unreachable!() }
     fn surface_get_blend_shape_arrays(&self, _index: i32) -> godot::prelude::Array<VariantArray> { unreachable!() }
-    fn surface_get_lods(&self, _index: i32) -> godot::prelude::Dictionary { unreachable!() }
+    fn surface_get_lods(&self, _index: i32) -> godot::prelude::VarDictionary { unreachable!() }
     fn surface_get_format(&self, _index: i32) -> u32 { unreachable!() }
     fn surface_get_primitive_type(&self, _index: i32) -> u32 { unreachable!() }
     #[cfg(feature = "codegen-full")]
@@ -265,7 +265,11 @@ impl IRefCounted for SetTest {
 
 #[derive(GodotClass)]
 #[class(init)]
-struct RevertTest {}
+struct RevertTest {
+    #[export]
+    #[init(val = 7)]
+    exported: i32,
+}
 
 #[godot_api]
 impl IRefCounted for RevertTest {
@@ -642,6 +646,11 @@ fn test_revert() {
 
     assert_eq!(revert.property_get_revert(&changes), Variant::nil());
     assert_eq!(revert.property_get_revert(&changes), true.to_variant());
+
+    // Falls back to #[init] value of exported property.
+    let exported = StringName::from("exported");
+    assert!(revert.property_can_revert(&exported));
+    assert_eq!(revert.property_get_revert(&exported), 7.to_variant());
 }
 
 #[itest]
@@ -0,0 +1,81 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::classes::Node2D;
+use godot::prelude::*;
+
+use crate::framework::itest;
+
+#[itest]
+fn weak_gd_upgrade_manual() {
+    let node = Node::new_alloc();
+    let weak = node.downgrade();
+
+    assert!(weak.is_instance_valid());
+    assert_eq!(weak.instance_id(), Some(node.instance_id()));
+    assert_eq!(weak.upgrade(), Some(node.clone()));
+
+    node.free();
+    assert!(!weak.is_instance_valid());
+    assert_eq!(weak.upgrade(), None);
+}
+
+#[itest]
+fn weak_gd_does_not_keep_refcounted_alive() {
+    let obj = RefCounted::new_gd();
+    let weak = WeakGd::new(&obj);
+
+    let strong = weak.upgrade().expect("object alive");
+    assert_eq!(strong.get_reference_count(), 2);
+    drop(strong);
+    assert_eq!(obj.get_reference_count(), 1);
+
+    drop(obj);
+    assert_eq!(weak.upgrade(), None);
+}
+
+#[itest]
+fn weak_gd_null() {
+    let weak = WeakGd::<Node>::default();
+
+    assert_eq!(weak, WeakGd::null());
+    assert_eq!(weak.instance_id(), None);
+    assert!(!weak.is_instance_valid());
+    assert_eq!(weak.upgrade(), None);
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct WeakGdHolder {
+    #[var]
+    any_node: WeakGd<Node>,
+
+    #[export]
+    cached: WeakGd<Node2D>,
+}
+
+#[itest]
+fn weak_gd_as_property() {
+    let mut holder = WeakGdHolder::new_alloc();
+    let node = Node2D::new_alloc();
+
+    holder.set("cached", &node.to_variant());
+    holder.set("any_node", &node.to_variant());
+    assert_eq!(holder.bind().cached.upgrade(), Some(node.clone()));
+    assert_eq!(holder.get("cached"), node.to_variant());
+    assert_eq!(holder.get("any_node"), node.to_variant());
+
+    // Dead objects are read as null.
+    node.free();
+    assert_eq!(holder.get("cached"), Variant::nil());
+    assert_eq!(holder.bind().any_node.upgrade(), None);
+
+    holder.set("cached", &Variant::nil());
+    assert_eq!(holder.bind().cached, WeakGd::null());
+
+    holder.free();
+}
@@ -9,6 +9,7 @@
 #![allow(clippy::non_minimal_cfg)]
 
 use godot::classes::ClassDb;
+use godot::global::MethodFlags;
 use godot::prelude::*;
 use godot::sys::static_assert;
 
@@ -113,6 +114,76 @@ fn cfg_removes_or_keeps_constants() {
     ));
 }
 
+type Score = i32;
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct HasValueConstants {}
+
+#[godot_api]
+impl HasValueConstants {
+    // Integer constants, regardless of how the type is spelled.
+    #[constant]
+    const MAX_SCORE: Score = 100;
+
+    #[constant]
+    const LIVES: std::primitive::i32 = 3;
+
+    #[constant(as_method)]
+    const ORIGIN: Vector2 = Vector2::new(1.0, 2.0);
+
+    #[constant(as_method)]
+    const TINT: Color = Color::from_rgb(1.0, 0.5, 0.0);
+
+    #[constant]
+    #[allow(non_snake_case)]
+    fn GREETING() -> StringName {
+        StringName::from("hello")
+    }
+}
+
+/// Returns the flags of a method declared directly in the given class, if it exists.
+fn class_method_flags(class_name: &StringName, method: &str) -> Option<u64> {
+    ClassDb::singleton()
+        .class_get_method_list_ex(class_name)
+        .no_inheritance(true)
+        .done()
+        .iter_shared()
+        .find(|info| info.at("name") == method.to_variant())
+        .map(|info| info.at("flags").to::<u64>())
+}
+
+#[itest]
+fn constants_non_integer_as_static_methods() {
+    // Godot only knows integer constants; everything else is exposed through static methods.
+    assert!(class_has_integer_constant::<HasValueConstants>("MAX_SCORE"));
+    assert!(class_has_integer_constant::<HasValueConstants>("LIVES"));
+    assert!(!class_has_integer_constant::<HasValueConstants>("ORIGIN"));
+
+    // Registered as static methods, so GDScript can call them on the class: `HasValueConstants.ORIGIN()`.
+    let class_name = HasValueConstants::class_name().to_string_name();
+    for name in ["ORIGIN", "TINT", "GREETING"] {
+        let flags = class_method_flags(&class_name, name)
+            .unwrap_or_else(|| panic!("method {name} is registered"));
+        assert_ne!(
+            flags & MethodFlags::STATIC.ord(),
+            0,
+            "method {name} is static"
+        );
+    }
+
+    let mut obj = HasValueConstants::new_gd();
+    assert_eq!(
+        obj.call("ORIGIN", &[]),
+        HasValueConstants::ORIGIN.to_variant()
+    );
+    assert_eq!(obj.call("TINT", &[]), HasValueConstants::TINT.to_variant());
+    assert_eq!(
+        obj.call("GREETING", &[]),
+        StringName::from("hello").to_variant()
+    );
+}
+
 #[derive(GodotClass)]
 #[class(no_init)]
 struct HasOtherConstants {}
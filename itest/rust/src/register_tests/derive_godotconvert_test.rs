@@ -7,8 +7,10 @@
 
 use std::fmt::Debug;
 
-use godot::builtin::{GString, Vector2};
-use godot::meta::ToGodot;
+use godot::builtin::{varray, vdict, GString, VarDictionary, VariantArray, Vector2};
+use godot::classes::Node;
+use godot::meta::{FromGodot, ToGodot};
+use godot::obj::{Gd, NewAlloc};
 use godot::register::GodotConvert;
 
 use crate::common::roundtrip;
@@ -27,6 +29,66 @@ struct NamedNewtype {
     field1: Vector2,
 }
 
+#[derive(GodotConvert, PartialEq, Debug)]
+#[godot(dictionary)]
+struct DictStruct {
+    name: GString,
+    #[godot(rename = "hit_points")]
+    hp: i32,
+    #[godot(default)]
+    position: Vector2,
+    #[godot(default = 7)]
+    level: i64,
+    #[godot(skip)]
+    cached: Option<u32>,
+    r#type: GString,
+}
+
+impl DictStruct {
+    fn sample() -> Self {
+        Self {
+            name: "Godette".into(),
+            hp: 100,
+            position: Vector2::new(1.0, 2.0),
+            level: 3,
+            cached: None,
+            r#type: "mage".into(),
+        }
+    }
+}
+
+#[derive(GodotConvert, PartialEq, Debug)]
+#[godot(dictionary)]
+enum EventDict {
+    Hit {
+        damage: i32,
+        source: Gd<Node>,
+    },
+    #[godot(rename = "heal")]
+    Healed {
+        amount: i32,
+        #[godot(default = 1)]
+        ticks: i64,
+    },
+    Died,
+}
+
+#[derive(GodotConvert, PartialEq, Debug)]
+#[godot(dictionary, tag = "kind")]
+enum EventCustomTag {
+    Started { level: GString },
+    Stopped,
+}
+
+#[derive(GodotConvert, PartialEq, Debug)]
+#[godot(array)]
+enum CommandArray {
+    Move(Vector2),
+    Wait { seconds: f64 },
+    Pair(i32, #[godot(skip)] u8, GString),
+    Stop,
+}
+
 #[derive(GodotConvert, Clone, PartialEq, Debug)]
 #[godot(via = GString)]
 enum EnumStringy {
@@ -68,6 +130,164 @@ fn newtype_named_struct() {
     });
 }
 
+#[itest]
+fn dictionary_struct() {
+    roundtrip(DictStruct::sample());
+
+    let dict = DictStruct::sample().to_godot();
+    assert_eq!(
+        dict,
+        vdict! {
+            "name": "Godette",
+            "hit_points": 100,
+            "position": Vector2::new(1.0, 2.0),
+            "level": 3,
+            "type": "mage",
+        }
+    );
+}
+
+#[itest]
+fn dictionary_struct_defaults() {
+    let dict = vdict! {
+        "name": "Godette",
+        "hit_points": 100,
+        "type": "mage",
+        "unrelated": true, // Extra keys are ignored.
+    };
+
+    let value = DictStruct::from_godot(dict);
+    let expected = DictStruct {
+        position: Vector2::ZERO,
+        level: 7,
+        ..DictStruct::sample()
+    };
+    assert_eq!(value, expected);
+}
+
+#[itest]
+fn dictionary_struct_errors() {
+    let missing = DictStruct::try_from_godot(vdict! { "name": "Godette", "type": "mage" });
+    let err = missing.expect_err("missing key must fail");
+    assert!(
+        err.to_string().contains("\"hit_points\""),
+        "error should name missing key: {err}"
+    );
+    assert!(err.value().is_some());
+
+    let mistyped = DictStruct::try_from_godot(vdict! {
+        "name": "Godette",
+        "hit_points": "lots",
+        "type": "mage",
+    });
+    let err = mistyped.expect_err("mistyped value must fail");
+    assert!(
+        err.to_string().contains("\"hit_points\""),
+        "error should name mistyped key: {err}"
+    );
+    assert_eq!(err.value(), Some(&"lots".to_variant()));
+
+    let empty = DictStruct::try_from_godot(VarDictionary::new());
+    assert!(empty.is_err());
+
+    let not_a_dict = 42.to_variant();
+    assert!(DictStruct::try_from_variant(&not_a_dict).is_err());
+}
+
+#[itest]
+fn tagged_enum_dictionary() {
+    let node = Node::new_alloc();
+
+    roundtrip(EventDict::Hit {
+        damage: 12,
+        source: node.clone(),
+    });
+    roundtrip(EventDict::Healed {
+        amount: 5,
+        ticks: 3,
+    });
+    roundtrip(EventDict::Died);
+
+    let dict = EventDict::Hit {
+        damage: 12,
+        source: node.clone(),
+    }
+    .to_godot();
+    assert_eq!(
+        dict,
+        vdict! { "type": "Hit", "damage": 12, "source": node.clone() }
+    );
+
+    assert_eq!(EventDict::Died.to_godot(), vdict! { "type": "Died" });
+
+    let healed = EventDict::from_godot(vdict! { "type": "heal", "amount": 5 });
+    assert_eq!(
+        healed,
+        EventDict::Healed {
+            amount: 5,
+            ticks: 1
+        }
+    );
+
+    roundtrip(EventCustomTag::Started {
+        level: "forest".into(),
+    });
+    assert_eq!(
+        EventCustomTag::Stopped.to_godot(),
+        vdict! { "kind": "Stopped" }
+    );
+
+    node.free();
+}
+
+#[itest]
+fn tagged_enum_array() {
+    roundtrip(CommandArray::Move(Vector2::new(1.0, 2.0)));
+    roundtrip(CommandArray::Wait { seconds: 0.5 });
+    roundtrip(CommandArray::Pair(7, 0, "seven".into()));
+    roundtrip(CommandArray::Stop);
+
+    assert_eq!(
+        CommandArray::Move(Vector2::new(1.0, 2.0)).to_godot(),
+        varray!["Move", Vector2::new(1.0, 2.0)]
+    );
+
+    // Skipped fields are not stored and don't occupy an element.
+    assert_eq!(
+        CommandArray::Pair(7, 99, "seven".into()).to_godot(),
+        varray!["Pair", 7, "seven"]
+    );
+    assert_eq!(CommandArray::Stop.to_godot(), varray!["Stop"]);
+}
+
+#[itest]
+fn tagged_enum_errors() {
+    let err = EventDict::try_from_godot(vdict! { "type": "Exploded" })
+        .expect_err("unknown tag must fail");
+    assert!(
+        err.to_string().contains("EventDict"),
+        "error should name enum: {err}"
+    );
+    assert_eq!(err.value(), Some(&"Exploded".to_variant()));
+
+    let err = EventDict::try_from_godot(vdict! { "damage": 3 }).expect_err("missing tag must fail");
+    assert!(err.to_string().contains("\"type\""), "{err}");
+
+    let err =
+        EventDict::try_from_godot(vdict! { "type": "heal" }).expect_err("missing field must fail");
+    assert!(err.to_string().contains("\"amount\""), "{err}");
+
+    let err = CommandArray::try_from_godot(varray!["Wait"]).expect_err("missing element must fail");
+    assert!(err.to_string().contains("index 1"), "{err}");
+
+    let err = CommandArray::try_from_godot(varray!["Move", "left"])
+        .expect_err("mistyped element must fail");
+    assert!(err.to_string().contains("index 1"), "{err}");
+
+    let err = CommandArray::try_from_godot(VariantArray::new()).expect_err("empty array must fail");
+    assert!(err.to_string().contains("index 0"), "{err}");
+}
+
 #[itest]
 fn enum_stringy() {
     roundtrip(EnumStringy::A);